@@ -79,6 +79,9 @@ use crate::PeanoInt;
 #[doc(no_inline)]
 pub use typewit::const_marker::{Bool, BoolWit, BoolWitG};
 
+#[doc(no_inline)]
+pub use crate::Bool;
+
 use typewit::{HasTypeWitness, TypeEq};
 
 //////////////////////////////////////////////////////////////////////////////
@@ -89,6 +92,8 @@ pub mod type_fns;
 #[doc(no_inline)]
 pub use self::type_fns::*;
 
+pub mod proofs;
+
 //////////////////////////////////////////////////////////////////////////////
 
 /// Type alias form of [`Boolean::IfTruePI`]
@@ -112,6 +117,24 @@ pub type Or<L, R> = <L as Boolean>::Or<R>;
 /// Type alias form of [`Boolean::Xor`]
 pub type Xor<L, R> = <L as Boolean>::Xor<R>;
 
+/// Type alias form of [`Boolean::Implies`]
+pub type Implies<L, R> = <L as Boolean>::Implies<R>;
+
+/// Type alias form of [`Boolean::Nand`]
+pub type Nand<L, R> = <L as Boolean>::Nand<R>;
+
+/// Type alias form of [`Boolean::Nor`]
+pub type Nor<L, R> = <L as Boolean>::Nor<R>;
+
+/// Type alias form of [`Boolean::BoolEq`]
+pub type BoolEq<L, R> = <L as Boolean>::BoolEq<R>;
+
+/// Type alias form of [`BooleanList::All`]
+pub type All<List> = <List as BooleanList>::All;
+
+/// Type alias form of [`BooleanList::Any`]
+pub type Any<List> = <List as BooleanList>::Any;
+
 //////////////////////////////////////////////////////////////////////////////
 
 /// Trait for bounding [type-level bools].
@@ -184,6 +207,66 @@ pub trait Boolean:
     /// ```
     type Xor<Rhs: Boolean>: Boolean;
 
+    /// Logical implication, `Self` implies `Rhs`
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use nlist::boolean::{self, Bool};
+    /// 
+    /// let _: boolean::Implies<Bool<false>, Bool<false>> = Bool::<true>;
+    /// let _: boolean::Implies<Bool<false>, Bool<true>> = Bool::<true>;
+    /// let _: boolean::Implies<Bool<true>, Bool<false>> = Bool::<false>;
+    /// let _: boolean::Implies<Bool<true>, Bool<true>> = Bool::<true>;
+    /// 
+    /// ```
+    type Implies<Rhs: Boolean>: Boolean;
+
+    /// Negated logical and
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use nlist::boolean::{self, Bool};
+    /// 
+    /// let _: boolean::Nand<Bool<false>, Bool<false>> = Bool::<true>;
+    /// let _: boolean::Nand<Bool<false>, Bool<true>> = Bool::<true>;
+    /// let _: boolean::Nand<Bool<true>, Bool<false>> = Bool::<true>;
+    /// let _: boolean::Nand<Bool<true>, Bool<true>> = Bool::<false>;
+    /// 
+    /// ```
+    type Nand<Rhs: Boolean>: Boolean;
+
+    /// Negated logical or
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use nlist::boolean::{self, Bool};
+    /// 
+    /// let _: boolean::Nor<Bool<false>, Bool<false>> = Bool::<true>;
+    /// let _: boolean::Nor<Bool<false>, Bool<true>> = Bool::<false>;
+    /// let _: boolean::Nor<Bool<true>, Bool<false>> = Bool::<false>;
+    /// let _: boolean::Nor<Bool<true>, Bool<true>> = Bool::<false>;
+    /// 
+    /// ```
+    type Nor<Rhs: Boolean>: Boolean;
+
+    /// Equality, whether `Self` and `Rhs` are the same boolean
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use nlist::boolean::{self, Bool};
+    /// 
+    /// let _: boolean::BoolEq<Bool<false>, Bool<false>> = Bool::<true>;
+    /// let _: boolean::BoolEq<Bool<false>, Bool<true>> = Bool::<false>;
+    /// let _: boolean::BoolEq<Bool<true>, Bool<false>> = Bool::<false>;
+    /// let _: boolean::BoolEq<Bool<true>, Bool<true>> = Bool::<true>;
+    /// 
+    /// ```
+    type BoolEq<Rhs: Boolean>: Boolean;
+
     /// Evaluates to different types depending on the type of `Self`:
     /// - if `Self == Bool<true>`: evaluates to `Then`
     /// - if `Self == Bool<false>`: evaluates to `Else`
@@ -245,6 +328,14 @@ impl Boolean for Bool<false> {
 
     type Xor<Rhs: Boolean> = Rhs;
 
+    type Implies<Rhs: Boolean> = Bool<true>;
+
+    type Nand<Rhs: Boolean> = Bool<true>;
+
+    type Nor<Rhs: Boolean> = Rhs::Not;
+
+    type BoolEq<Rhs: Boolean> = Rhs::Not;
+
     type IfTrue<Then, Else> = Else;
 
     type IfTruePI<Then: PeanoInt, Else: PeanoInt> = Else;
@@ -261,6 +352,14 @@ impl Boolean for Bool<true> {
 
     type Xor<Rhs: Boolean> = Rhs::Not;
 
+    type Implies<Rhs: Boolean> = Rhs;
+
+    type Nand<Rhs: Boolean> = Rhs::Not;
+
+    type Nor<Rhs: Boolean> = Bool<false>;
+
+    type BoolEq<Rhs: Boolean> = Rhs;
+
     type IfTrue<Then, Else> = Then;
 
     type IfTruePI<Then: PeanoInt, Else: PeanoInt> = Then;
@@ -268,6 +367,58 @@ impl Boolean for Bool<true> {
     type IfTrueB<Then: Boolean, Else: Boolean> = Then;
 }
 
+//////////////////////////////////////////////////////////////////////////////
+
+/// A type-level list of [`Boolean`]s, 
+/// implemented for tuples of up to 16 [`Boolean`]s.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use nlist::boolean::{self, Bool};
+/// 
+/// type F = Bool<false>;
+/// type T = Bool<true>;
+/// 
+/// let _: boolean::All<()> = Bool::<true>;
+/// let _: boolean::All<(T, T, T)> = Bool::<true>;
+/// let _: boolean::All<(T, F, T)> = Bool::<false>;
+/// 
+/// let _: boolean::Any<()> = Bool::<false>;
+/// let _: boolean::Any<(F, F, F)> = Bool::<false>;
+/// let _: boolean::Any<(F, T, F)> = Bool::<true>;
+/// 
+/// ```
+pub trait BooleanList {
+    /// Whether all the [`Boolean`]s in this list are `Bool<true>`,
+    /// `Bool<true>` for an empty list.
+    type All: Boolean;
+
+    /// Whether any of the [`Boolean`]s in this list is `Bool<true>`,
+    /// `Bool<false>` for an empty list.
+    type Any: Boolean;
+}
+
+impl BooleanList for () {
+    type All = Bool<true>;
+    type Any = Bool<false>;
+}
+
+macro_rules! impl_boolean_list {
+    ($first:ident $($rem:ident)*) => {
+        impl<$first: Boolean, $($rem: Boolean),*> BooleanList for ($first, $($rem,)*) {
+            type All = And<$first, <($($rem,)*) as BooleanList>::All>;
+            type Any = Or<$first, <($($rem,)*) as BooleanList>::Any>;
+        }
+
+        impl_boolean_list!{$($rem)*}
+    };
+    () => {};
+}
+
+impl_boolean_list!{B0 B1 B2 B3 B4 B5 B6 B7 B8 B9 B10 B11 B12 B13 B14 B15}
+
+//////////////////////////////////////////////////////////////////////////////
 
 /// Diverges when given a proof of `Bool<true> == Bool<false>`
 /// (which is a contradiction, because they're different types).
@@ -0,0 +1,258 @@
+//! Contains proofs of properties of [`Boolean`]s
+//! 
+//! These properties are useful in generic contexts, where the compiler does no
+//! reasoning WRT the logical properties of [`Boolean`]s. 
+//! 
+//! Proofs about comparisons between [`PeanoInt`](crate::PeanoInt)s,
+//! like [`lt_is_not_flipped_le`](crate::peano::proofs::lt_is_not_flipped_le),
+//! are in the [`peano::proofs`](crate::peano::proofs) module.
+//! 
+//! # Example
+//! 
+//! Getting the first element out of two lists at once,
+//! given a proof that both of them are non-empty.
+//! 
+//! ```rust
+//! use nlist::{NList, PeanoInt, Zero, nlist};
+//! use nlist::boolean::{self, Bool, proofs};
+//! use nlist::peano::{self, IsLt};
+//! use nlist::typewit::TypeEq;
+//! 
+//! assert_eq!(firsts(&nlist![3, 5], &nlist!["foo"], TypeEq::NEW), (&3, &"foo"));
+//! 
+//! const fn firsts<'a, A, B, LA, LB>(
+//!     a: &'a NList<A, LA>,
+//!     b: &'a NList<B, LB>,
+//!     both_nonempty: TypeEq<boolean::And<IsLt<Zero, LA>, IsLt<Zero, LB>>, Bool<true>>,
+//! ) -> (&'a A, &'a B)
+//! where
+//!     LA: PeanoInt,
+//!     LB: PeanoInt,
+//! {
+//!     (
+//!         a.index_alt::<Zero>(proofs::and_true_left::<_, IsLt<Zero, LB>>(both_nonempty)),
+//!         b.index_alt::<Zero>(proofs::and_true_right::<IsLt<Zero, LA>, _>(both_nonempty)),
+//!     )
+//! }
+//! ```
+
+// proofs are all about spelling out complex types
+#![allow(clippy::type_complexity)]
+
+use crate::boolean::{AndFn, Bool, Boolean, BoolWitG, And, Not, Or};
+
+use typewit::TypeEq;
+
+
+/// Proof that `Not<Not<B>>` == `B`
+pub const fn double_negation<B>() -> TypeEq<Not<Not<B>>, B>
+where
+    B: Boolean,
+{
+    // `Boolean::Not` is bounded by `Boolean<Not = Self>`
+    TypeEq::NEW
+}
+
+/// Proof that `Not<And<A, B>>` == `Or<Not<A>, Not<B>>`
+pub const fn de_morgan_and<A, B>() -> TypeEq<Not<And<A, B>>, Or<Not<A>, Not<B>>>
+where
+    A: Boolean,
+    B: Boolean,
+{
+    typewit::type_fn! {
+        struct LhsFn<B: Boolean>;
+        impl<A: Boolean> A => Not<And<A, B>>
+    }
+    typewit::type_fn! {
+        struct RhsFn<B: Boolean>;
+        impl<A: Boolean> A => Or<Not<A>, Not<B>>
+    }
+
+    match A::BOOL_WIT {
+        BoolWitG::True(a_te) => {
+            a_te.map(LhsFn::<B>::NEW).join(a_te.map(RhsFn::<B>::NEW).flip())
+        }
+        BoolWitG::False(a_te) => {
+            a_te.map(LhsFn::<B>::NEW).join(a_te.map(RhsFn::<B>::NEW).flip())
+        }
+    }
+}
+
+/// Proof that `Not<Or<A, B>>` == `And<Not<A>, Not<B>>`
+pub const fn de_morgan_or<A, B>() -> TypeEq<Not<Or<A, B>>, And<Not<A>, Not<B>>>
+where
+    A: Boolean,
+    B: Boolean,
+{
+    typewit::type_fn! {
+        struct LhsFn<B: Boolean>;
+        impl<A: Boolean> A => Not<Or<A, B>>
+    }
+    typewit::type_fn! {
+        struct RhsFn<B: Boolean>;
+        impl<A: Boolean> A => And<Not<A>, Not<B>>
+    }
+
+    match A::BOOL_WIT {
+        BoolWitG::True(a_te) => {
+            a_te.map(LhsFn::<B>::NEW).join(a_te.map(RhsFn::<B>::NEW).flip())
+        }
+        BoolWitG::False(a_te) => {
+            a_te.map(LhsFn::<B>::NEW).join(a_te.map(RhsFn::<B>::NEW).flip())
+        }
+    }
+}
+
+/// Proof that `And<A, B>` == `And<B, A>`
+pub const fn commutative_and<A, B>() -> TypeEq<And<A, B>, And<B, A>>
+where
+    A: Boolean,
+    B: Boolean,
+{
+    typewit::type_fn! {
+        struct LhsFn;
+        impl<A: Boolean, B: Boolean> (A, B) => And<A, B>
+    }
+    typewit::type_fn! {
+        struct RhsFn;
+        impl<A: Boolean, B: Boolean> (A, B) => And<B, A>
+    }
+
+    match (A::BOOL_WIT, B::BOOL_WIT) {
+        (BoolWitG::True(a_te), BoolWitG::True(b_te)) => {
+            a_te.zip(b_te).map(LhsFn).join(a_te.zip(b_te).map(RhsFn).flip())
+        }
+        (BoolWitG::True(a_te), BoolWitG::False(b_te)) => {
+            a_te.zip(b_te).map(LhsFn).join(a_te.zip(b_te).map(RhsFn).flip())
+        }
+        (BoolWitG::False(a_te), BoolWitG::True(b_te)) => {
+            a_te.zip(b_te).map(LhsFn).join(a_te.zip(b_te).map(RhsFn).flip())
+        }
+        (BoolWitG::False(a_te), BoolWitG::False(b_te)) => {
+            a_te.zip(b_te).map(LhsFn).join(a_te.zip(b_te).map(RhsFn).flip())
+        }
+    }
+}
+
+/// Proof that `Or<A, B>` == `Or<B, A>`
+pub const fn commutative_or<A, B>() -> TypeEq<Or<A, B>, Or<B, A>>
+where
+    A: Boolean,
+    B: Boolean,
+{
+    typewit::type_fn! {
+        struct LhsFn;
+        impl<A: Boolean, B: Boolean> (A, B) => Or<A, B>
+    }
+    typewit::type_fn! {
+        struct RhsFn;
+        impl<A: Boolean, B: Boolean> (A, B) => Or<B, A>
+    }
+
+    match (A::BOOL_WIT, B::BOOL_WIT) {
+        (BoolWitG::True(a_te), BoolWitG::True(b_te)) => {
+            a_te.zip(b_te).map(LhsFn).join(a_te.zip(b_te).map(RhsFn).flip())
+        }
+        (BoolWitG::True(a_te), BoolWitG::False(b_te)) => {
+            a_te.zip(b_te).map(LhsFn).join(a_te.zip(b_te).map(RhsFn).flip())
+        }
+        (BoolWitG::False(a_te), BoolWitG::True(b_te)) => {
+            a_te.zip(b_te).map(LhsFn).join(a_te.zip(b_te).map(RhsFn).flip())
+        }
+        (BoolWitG::False(a_te), BoolWitG::False(b_te)) => {
+            a_te.zip(b_te).map(LhsFn).join(a_te.zip(b_te).map(RhsFn).flip())
+        }
+    }
+}
+
+/// Proof that `And<And<A, B>, C>` == `And<A, And<B, C>>`
+pub const fn associative_and<A, B, C>() -> TypeEq<And<And<A, B>, C>, And<A, And<B, C>>>
+where
+    A: Boolean,
+    B: Boolean,
+    C: Boolean,
+{
+    typewit::type_fn! {
+        struct LhsFn<B: Boolean, C: Boolean>;
+        impl<A: Boolean> A => And<And<A, B>, C>
+    }
+    typewit::type_fn! {
+        struct RhsFn<B: Boolean, C: Boolean>;
+        impl<A: Boolean> A => And<A, And<B, C>>
+    }
+
+    match A::BOOL_WIT {
+        BoolWitG::True(a_te) => {
+            a_te.map(LhsFn::<B, C>::NEW).join(a_te.map(RhsFn::<B, C>::NEW).flip())
+        }
+        BoolWitG::False(a_te) => {
+            a_te.map(LhsFn::<B, C>::NEW).join(a_te.map(RhsFn::<B, C>::NEW).flip())
+        }
+    }
+}
+
+/// Proof that `Or<Or<A, B>, C>` == `Or<A, Or<B, C>>`
+pub const fn associative_or<A, B, C>() -> TypeEq<Or<Or<A, B>, C>, Or<A, Or<B, C>>>
+where
+    A: Boolean,
+    B: Boolean,
+    C: Boolean,
+{
+    typewit::type_fn! {
+        struct LhsFn<B: Boolean, C: Boolean>;
+        impl<A: Boolean> A => Or<Or<A, B>, C>
+    }
+    typewit::type_fn! {
+        struct RhsFn<B: Boolean, C: Boolean>;
+        impl<A: Boolean> A => Or<A, Or<B, C>>
+    }
+
+    match A::BOOL_WIT {
+        BoolWitG::True(a_te) => {
+            a_te.map(LhsFn::<B, C>::NEW).join(a_te.map(RhsFn::<B, C>::NEW).flip())
+        }
+        BoolWitG::False(a_te) => {
+            a_te.map(LhsFn::<B, C>::NEW).join(a_te.map(RhsFn::<B, C>::NEW).flip())
+        }
+    }
+}
+
+/// Proof that, if `And<A, B> == true`, then `A == true`
+pub const fn and_true_left<A, B>(
+    and_te: TypeEq<And<A, B>, Bool<true>>
+) -> TypeEq<A, Bool<true>>
+where
+    A: Boolean,
+    B: Boolean,
+{
+    match A::BOOL_WIT {
+        BoolWitG::True(a_te) => a_te,
+        BoolWitG::False(a_te) => {
+            let false_te = a_te.zip(TypeEq::new::<B>()).map(AndFn::NEW);
+            super::contradiction(and_te.flip().join(false_te))
+        }
+    }
+}
+
+/// Proof that, if `And<A, B> == true`, then `B == true`
+pub const fn and_true_right<A, B>(
+    and_te: TypeEq<And<A, B>, Bool<true>>
+) -> TypeEq<B, Bool<true>>
+where
+    A: Boolean,
+    B: Boolean,
+{
+    and_true_left::<B, A>(commutative_and::<B, A>().join(and_te))
+}
+
+/// Proof that, if `A == true` and `B == true`, then `And<A, B> == true`
+pub const fn and_true_intro<A, B>(
+    a_te: TypeEq<A, Bool<true>>,
+    b_te: TypeEq<B, Bool<true>>,
+) -> TypeEq<And<A, B>, Bool<true>>
+where
+    A: Boolean,
+    B: Boolean,
+{
+    a_te.zip(b_te).map(AndFn::NEW)
+}
@@ -1,5 +1,5 @@
 use crate::PeanoInt;
-use crate::boolean::{self, Boolean};
+use crate::boolean::{self, Boolean, BooleanList};
 
 use crate::macros::internal_macros::{alt_fn_docs, declare_type_fn};
 
@@ -7,6 +7,10 @@ declare_type_fn!{ NotFn, boolean, "Not", Boolean::Not<>, Boolean }
 declare_type_fn!{ AndFn, boolean, "And", Boolean::And<R>, Boolean }
 declare_type_fn!{ OrFn, boolean, "Or", Boolean::Or<R>, Boolean }
 declare_type_fn!{ XorFn, boolean, "Xor", Boolean::Xor<R>, Boolean }
+declare_type_fn!{ ImpliesFn, boolean, "Implies", Boolean::Implies<R>, Boolean }
+declare_type_fn!{ NandFn, boolean, "Nand", Boolean::Nand<R>, Boolean }
+declare_type_fn!{ NorFn, boolean, "Nor", Boolean::Nor<R>, Boolean }
+declare_type_fn!{ BoolEqFn, boolean, "BoolEq", Boolean::BoolEq<R>, Boolean }
 declare_type_fn!{ IfTrueFn, boolean, "IfTrue", Boolean::IfTrue<Then, Else>, __NoBound }
 declare_type_fn!{ IfTrueBFn, boolean, "IfTrueB", Boolean::IfTrueB<Then, Else>, Boolean }
 declare_type_fn!{ IfTruePIFn, boolean, "IfTruePI", Boolean::IfTruePI<Then, Else>, PeanoInt }
@@ -45,3 +49,19 @@ typewit::type_fn!{
         Then: PeanoInt,
         Else: PeanoInt,
 }
+
+typewit::type_fn!{
+    /// Type-level function ([`TypeFn`](typewit::TypeFn) implementor) form of 
+    /// [`boolean::All`]
+    pub struct AllFn;
+
+    impl<List: BooleanList> List => boolean::All<List>
+}
+
+typewit::type_fn!{
+    /// Type-level function ([`TypeFn`](typewit::TypeFn) implementor) form of 
+    /// [`boolean::Any`]
+    pub struct AnyFn;
+
+    impl<List: BooleanList> List => boolean::Any<List>
+}
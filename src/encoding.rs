@@ -0,0 +1,300 @@
+//! Fixed-size binary encoding, where the encoded size is part of the type.
+//!
+//! Types implement [`Encode`] and [`Decode`] to convert to and from
+//! an [`NList<u8, Self::Size>`](NList), so that mismatched lengths
+//! are compile-time errors instead of runtime length checks.
+//!
+//! Multi-byte integers are encoded with an explicit byte order,
+//! by wrapping them in [`Le`] (little-endian) or [`Be`] (big-endian).
+//!
+//! # Example
+//!
+//! A wire-format header
+//!
+//! ```rust
+//! use nlist::{NList, Peano, nlist};
+//! use nlist::encoding::{Be, Decode, Encode};
+//!
+//! #[derive(Debug, PartialEq)]
+//! struct Header {
+//!     kind: u8,
+//!     len: u16,
+//!     id: u32,
+//! }
+//!
+//! type HeaderFields = (u8, Be<u16>, Be<u32>);
+//!
+//! impl Encode for Header {
+//!     type Size = <HeaderFields as Encode>::Size;
+//!
+//!     fn encode(&self) -> NList<u8, Self::Size> {
+//!         (self.kind, Be(self.len), Be(self.id)).encode()
+//!     }
+//! }
+//!
+//! impl Decode for Header {
+//!     fn decode(bytes: NList<u8, Self::Size>) -> Self {
+//!         let (kind, Be(len), Be(id)) = HeaderFields::decode(bytes);
+//!         Header { kind, len, id }
+//!     }
+//! }
+//!
+//! let header = Header { kind: 3, len: 0x0105, id: 0x0A0B0C0D };
+//!
+//! // the encoded size is known at compile-time
+//! let bytes: NList<u8, Peano!(7)> = header.encode();
+//!
+//! assert_eq!(bytes, nlist![3, 0x01, 0x05, 0x0A, 0x0B, 0x0C, 0x0D]);
+//! assert_eq!(Header::decode(bytes), header);
+//!
+//! ```
+
+use crate::{
+    nlist::{splitting::split_add, NList},
+    peano::{self, FromUsize, IntoPeano, PeanoInt, Usize, Zero},
+};
+
+
+/// Types that can be encoded into exactly `Self::Size` bytes.
+///
+/// # Example
+///
+/// ```rust
+/// use nlist::{NList, Peano, nlist};
+/// use nlist::encoding::{Be, Encode, Le};
+///
+/// let bytes: NList<u8, Peano!(4)> = (Le(0x0102u16), Be(0x0304u16)).encode();
+///
+/// assert_eq!(bytes, nlist![0x02, 0x01, 0x03, 0x04]);
+///
+/// ```
+pub trait Encode {
+    /// The amount of bytes that `Self` is encoded into.
+    type Size: PeanoInt;
+
+    /// Encodes `self` into bytes.
+    fn encode(&self) -> NList<u8, Self::Size>;
+}
+
+/// Types that can be decoded from exactly `Self::Size` bytes.
+///
+/// Decoding bytes produced by [`Encode::encode`] must return an equal value.
+///
+/// # Example
+///
+/// ```rust
+/// use nlist::nlist;
+/// use nlist::encoding::{Be, Decode};
+///
+/// let decoded = <[Be<u16>; 2]>::decode(nlist![0x01, 0x02, 0x03, 0x04]);
+///
+/// assert_eq!(decoded, [Be(0x0102), Be(0x0304)]);
+///
+/// ```
+pub trait Decode: Encode {
+    /// Decodes a `Self` from bytes.
+    fn decode(bytes: NList<u8, Self::Size>) -> Self;
+}
+
+
+/// Wrapper for encoding an integer in little-endian byte order.
+///
+/// # Example
+///
+/// ```rust
+/// use nlist::{NList, Peano, nlist};
+/// use nlist::encoding::Le;
+///
+/// const BYTES: NList<u8, Peano!(4)> = Le(0x0A0B0C0Du32).encode();
+/// const DECODED: Le<u32> = Le::<u32>::decode(BYTES);
+///
+/// assert_eq!(BYTES, nlist![0x0D, 0x0C, 0x0B, 0x0A]);
+/// assert_eq!(DECODED, Le(0x0A0B0C0D));
+///
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Le<T>(pub T);
+
+/// Wrapper for encoding an integer in big-endian byte order.
+///
+/// # Example
+///
+/// ```rust
+/// use nlist::{NList, Peano, nlist};
+/// use nlist::encoding::Be;
+///
+/// const BYTES: NList<u8, Peano!(4)> = Be(0x0A0B0C0Du32).encode();
+/// const DECODED: Be<u32> = Be::<u32>::decode(BYTES);
+///
+/// assert_eq!(BYTES, nlist![0x0A, 0x0B, 0x0C, 0x0D]);
+/// assert_eq!(DECODED, Be(0x0A0B0C0D));
+///
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Be<T>(pub T);
+
+
+macro_rules! impl_int_encoding {
+    ($(($wrapper:ident, $to_bytes:ident, $from_bytes:ident) => [$($ty:ident)*])*) => {$($(
+        impl $wrapper<$ty> {
+            /// Const equivalent of [`Encode::encode`]
+            pub const fn encode(&self) -> NList<u8, Peano!(core::mem::size_of::<$ty>())> {
+                NList::from_array(self.0.$to_bytes())
+            }
+
+            /// Const equivalent of [`Decode::decode`]
+            pub const fn decode(bytes: NList<u8, Peano!(core::mem::size_of::<$ty>())>) -> Self {
+                Self(<$ty>::$from_bytes(bytes.into_array()))
+            }
+        }
+
+        impl Encode for $wrapper<$ty> {
+            type Size = Peano!(core::mem::size_of::<$ty>());
+
+            fn encode(&self) -> NList<u8, Self::Size> {
+                $wrapper::<$ty>::encode(self)
+            }
+        }
+
+        impl Decode for $wrapper<$ty> {
+            fn decode(bytes: NList<u8, Self::Size>) -> Self {
+                $wrapper::<$ty>::decode(bytes)
+            }
+        }
+    )*)*};
+}
+
+// `usize` and `isize` are intentionally not supported,
+// because their encoded size would depend on the target.
+impl_int_encoding! {
+    (Le, to_le_bytes, from_le_bytes) => [u8 u16 u32 u64 u128 i8 i16 i32 i64 i128]
+    (Be, to_be_bytes, from_be_bytes) => [u8 u16 u32 u64 u128 i8 i16 i32 i64 i128]
+}
+
+macro_rules! impl_byte_encoding {
+    ($($ty:ident)*) => {$(
+        impl Encode for $ty {
+            type Size = Peano!(1);
+
+            fn encode(&self) -> NList<u8, Self::Size> {
+                NList::from_array(self.to_le_bytes())
+            }
+        }
+
+        impl Decode for $ty {
+            fn decode(bytes: NList<u8, Self::Size>) -> Self {
+                <$ty>::from_le_bytes(bytes.into_array())
+            }
+        }
+    )*};
+}
+
+// single-byte integers have no byte order
+impl_byte_encoding! {u8 i8}
+
+
+impl<T, L> Encode for NList<T, L>
+where
+    T: Encode,
+    L: PeanoInt,
+{
+    type Size = peano::Mul<L, T::Size>;
+
+    fn encode(&self) -> NList<u8, Self::Size> {
+        self.each_ref().map(|elem| elem.encode()).flatten()
+    }
+}
+
+impl<T, L> Decode for NList<T, L>
+where
+    T: Decode,
+    L: PeanoInt,
+{
+    fn decode(bytes: NList<u8, Self::Size>) -> Self {
+        NList::<NList<u8, T::Size>, L>::unflatten(bytes).map(T::decode)
+    }
+}
+
+
+impl<T, const N: usize> Encode for [T; N]
+where
+    T: Encode,
+    Usize<N>: IntoPeano,
+{
+    type Size = peano::Mul<FromUsize<N>, T::Size>;
+
+    fn encode(&self) -> NList<u8, Self::Size> {
+        NList::<_, FromUsize<N>>::from_array(self.each_ref()).map(|elem| elem.encode()).flatten()
+    }
+}
+
+impl<T, const N: usize> Decode for [T; N]
+where
+    T: Decode,
+    Usize<N>: IntoPeano,
+{
+    fn decode(bytes: NList<u8, Self::Size>) -> Self {
+        NList::<T, FromUsize<N>>::decode(bytes).into_array()
+    }
+}
+
+
+macro_rules! tuple_size {
+    () => (Zero);
+    ($first:ident $($rest:ident)*) => (peano::Add<$first::Size, tuple_size!($($rest)*)>);
+}
+
+macro_rules! impl_tuple_encoding {
+    ($(($($field:ident: $ty:ident),*))*) => {$(
+        impl<$($ty: Encode),*> Encode for ($($ty,)*) {
+            type Size = tuple_size!($($ty)*);
+
+            fn encode(&self) -> NList<u8, Self::Size> {
+                let ($($field,)*) = self;
+                impl_tuple_encoding!{@encode [$($field)*]}
+            }
+        }
+
+        impl<$($ty: Decode),*> Decode for ($($ty,)*) {
+            fn decode(bytes: NList<u8, Self::Size>) -> Self {
+                impl_tuple_encoding!{@decode bytes [$($field: $ty)*] []}
+            }
+        }
+    )*};
+    (@encode []) => (NList::nil());
+    (@encode [$first:ident $($rest:ident)*]) => (
+        $first.encode().concat(impl_tuple_encoding!{@encode [$($rest)*]})
+    );
+    (@decode $bytes:ident [] [$($decoded:ident)*]) => ({
+        let _: NList<u8, Zero> = $bytes;
+        ($($decoded,)*)
+    });
+    (@decode $bytes:ident [$field:ident: $ty:ident $($rest:tt)*] [$($decoded:ident)*]) => ({
+        let (head, $bytes) = split_add::<u8, $ty::Size, _>($bytes);
+        let $field = $ty::decode(head);
+        impl_tuple_encoding!{@decode $bytes [$($rest)*] [$($decoded)* $field]}
+    });
+}
+
+impl Encode for () {
+    type Size = Zero;
+
+    fn encode(&self) -> NList<u8, Self::Size> {
+        NList::nil()
+    }
+}
+
+impl Decode for () {
+    fn decode(_: NList<u8, Self::Size>) -> Self {}
+}
+
+impl_tuple_encoding! {
+    (a: A)
+    (a: A, b: B)
+    (a: A, b: B, c: C)
+    (a: A, b: B, c: C, d: D)
+    (a: A, b: B, c: C, d: D, e: E)
+    (a: A, b: B, c: C, d: D, e: E, f: F)
+    (a: A, b: B, c: C, d: D, e: E, f: F, g: G)
+    (a: A, b: B, c: C, d: D, e: E, f: F, g: G, h: H)
+}
@@ -0,0 +1,332 @@
+//! Combinators for awaiting all the futures in an [`NList`] at once,
+//! without allocating.
+//!
+//! The futures must be [`Unpin`],
+//! `!Unpin` futures can be pinned on the stack with [`core::pin::pin`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use nlist::{NList, Peano, nlist};
+//!
+//! use core::pin::pin;
+//!
+//! async fn read_sensor(id: u8) -> u16 {
+//!     u16::from(id) * 100
+//! }
+//!
+//! async fn read_all() -> NList<u16, Peano!(3)> {
+//!     nlist![
+//!         pin!(read_sensor(1)),
+//!         pin!(read_sensor(2)),
+//!         pin!(read_sensor(3)),
+//!     ].join().await
+//! }
+//!
+//! assert_eq!(block_on(read_all()), nlist![100, 200, 300]);
+//!
+//! # fn block_on<F: core::future::Future>(fut: F) -> F::Output {
+//! #     use std::{sync::Arc, task::{Context, Poll, Wake, Waker}};
+//! #     struct NoopWake;
+//! #     impl Wake for NoopWake { fn wake(self: Arc<Self>) {} }
+//! #     let waker = Waker::from(Arc::new(NoopWake));
+//! #     let mut fut = pin!(fut);
+//! #     loop {
+//! #         if let Poll::Ready(x) = fut.as_mut().poll(&mut Context::from_waker(&waker)) {
+//! #             return x;
+//! #         }
+//! #     }
+//! # }
+//! ```
+
+use core::{
+    fmt::{self, Debug},
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use crate::{
+    nlist::{Fin, NList},
+    peano::{PeanoInt, PlusOne},
+};
+
+
+// The state of one of the futures in a `Join` or `TryJoin`
+enum Slot<F, O> {
+    Pending(F),
+    Done(O),
+    Taken,
+}
+
+impl<F, O> Slot<F, O> {
+    fn take_output(&mut self) -> O {
+        match core::mem::replace(self, Slot::Taken) {
+            Slot::Done(output) => output,
+            _ => unreachable!("all futures have completed"),
+        }
+    }
+}
+
+
+impl<F, L> NList<F, L>
+where
+    F: Future + Unpin,
+    L: PeanoInt,
+{
+    /// Returns a future that polls all the futures in this list,
+    /// resolving to a list of their outputs once they've all completed.
+    ///
+    /// Every pending future is polled each time the returned future is polled.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use nlist::nlist;
+    ///
+    /// use core::future::ready;
+    ///
+    /// async fn example() {
+    ///     let joined = nlist![ready(3), ready(5), ready(8)].join().await;
+    ///
+    ///     assert_eq!(joined, nlist![3, 5, 8]);
+    /// }
+    /// ```
+    pub fn join(self) -> Join<F, L> {
+        Join { slots: self.map(Slot::Pending), done: false }
+    }
+}
+
+impl<F, T, E, L> NList<F, L>
+where
+    F: Future<Output = Result<T, E>> + Unpin,
+    L: PeanoInt,
+{
+    /// Returns a future that polls all the futures in this list,
+    /// resolving to a list of their `Ok` values once they've all completed,
+    /// or to the first `Err` that any of them returns.
+    ///
+    /// Every pending future is polled each time the returned future is polled.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use nlist::nlist;
+    ///
+    /// use core::future::ready;
+    ///
+    /// async fn example() {
+    ///     let all_ok = nlist![ready(Ok::<u8, &str>(3)), ready(Ok(5))].try_join().await;
+    ///     assert_eq!(all_ok, Ok(nlist![3, 5]));
+    ///
+    ///     let has_err = nlist![ready(Ok(3)), ready(Err("oops")), ready(Ok(8))].try_join().await;
+    ///     assert_eq!(has_err, Err("oops"));
+    /// }
+    /// ```
+    pub fn try_join(self) -> TryJoin<F, T, L> {
+        TryJoin { slots: self.map(Slot::Pending), done: false }
+    }
+}
+
+impl<F, L> NList<F, PlusOne<L>>
+where
+    F: Future + Unpin,
+    L: PeanoInt,
+{
+    /// Returns a future that polls all the futures in this list,
+    /// resolving to the index and output of the first one that completes.
+    ///
+    /// Each time the returned future is polled,
+    /// it starts polling from the future after the one it started from the last time,
+    /// so that a future that's always ready can't starve the others.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use nlist::nlist;
+    ///
+    /// use core::future::{pending, ready, Future};
+    /// use core::pin::Pin;
+    ///
+    /// async fn example() {
+    ///     let (index, output) = nlist![
+    ///         Box::pin(pending()) as Pin<Box<dyn Future<Output = u8>>>,
+    ///         Box::pin(ready(5)),
+    ///         Box::pin(pending()),
+    ///     ].select().await;
+    ///
+    ///     assert_eq!(index.get(), 1);
+    ///     assert_eq!(output, 5);
+    /// }
+    /// ```
+    pub fn select(self) -> Select<F, PlusOne<L>> {
+        Select { futures: self, start: 0, done: false }
+    }
+}
+
+
+/// Future returned by [`NList::join`].
+#[must_use = "futures do nothing unless polled"]
+pub struct Join<F: Future, L: PeanoInt> {
+    slots: NList<Slot<F, F::Output>, L>,
+    done: bool,
+}
+
+// the futures are only polled through `Pin::new`, which requires them to be `Unpin`,
+// and the outputs are never pinned
+impl<F: Future, L: PeanoInt> Unpin for Join<F, L> {}
+
+impl<F, L> Future for Join<F, L>
+where
+    F: Future + Unpin,
+    L: PeanoInt,
+{
+    type Output = NList<F::Output, L>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.done {
+            panic!("`Join` polled after completion");
+        }
+
+        let mut all_done = true;
+
+        self.slots.each_mut().for_each(|_, slot| {
+            if let Slot::Pending(fut) = slot {
+                match Pin::new(fut).poll(cx) {
+                    Poll::Ready(output) => *slot = Slot::Done(output),
+                    Poll::Pending => all_done = false,
+                }
+            }
+        });
+
+        if all_done {
+            self.done = true;
+            Poll::Ready(self.slots.each_mut().map(Slot::take_output))
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<F: Future, L: PeanoInt> Debug for Join<F, L> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("Join").field("len", &L::USIZE).finish_non_exhaustive()
+    }
+}
+
+
+/// Future returned by [`NList::try_join`].
+#[must_use = "futures do nothing unless polled"]
+pub struct TryJoin<F, T, L: PeanoInt> {
+    slots: NList<Slot<F, T>, L>,
+    done: bool,
+}
+
+// the futures are only polled through `Pin::new`, which requires them to be `Unpin`,
+// and the outputs are never pinned
+impl<F, T, L: PeanoInt> Unpin for TryJoin<F, T, L> {}
+
+impl<F, T, E, L> Future for TryJoin<F, T, L>
+where
+    F: Future<Output = Result<T, E>> + Unpin,
+    L: PeanoInt,
+{
+    type Output = Result<NList<T, L>, E>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.done {
+            panic!("`TryJoin` polled after completion");
+        }
+
+        let mut all_done = true;
+        let mut error = None;
+
+        self.slots.each_mut().for_each(|_, slot| {
+            if error.is_some() {
+                return;
+            }
+
+            if let Slot::Pending(fut) = slot {
+                match Pin::new(fut).poll(cx) {
+                    Poll::Ready(Ok(output)) => *slot = Slot::Done(output),
+                    Poll::Ready(Err(e)) => error = Some(e),
+                    Poll::Pending => all_done = false,
+                }
+            }
+        });
+
+        if let Some(e) = error {
+            self.done = true;
+            Poll::Ready(Err(e))
+        } else if all_done {
+            self.done = true;
+            Poll::Ready(Ok(self.slots.each_mut().map(Slot::take_output)))
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<F, T, L: PeanoInt> Debug for TryJoin<F, T, L> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("TryJoin").field("len", &L::USIZE).finish_non_exhaustive()
+    }
+}
+
+
+/// Future returned by [`NList::select`].
+#[must_use = "futures do nothing unless polled"]
+pub struct Select<F, L: PeanoInt> {
+    futures: NList<F, L>,
+    // the index of the first future polled in the next call to `poll`
+    start: usize,
+    done: bool,
+}
+
+impl<F: Unpin, L: PeanoInt> Unpin for Select<F, L> {}
+
+impl<F, L> Future for Select<F, L>
+where
+    F: Future + Unpin,
+    L: PeanoInt,
+{
+    type Output = (Fin<L>, F::Output);
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.done {
+            panic!("`Select` polled after completion");
+        }
+
+        let this = &mut *self;
+        let start = this.start;
+        this.start = (start + 1) % L::USIZE;
+
+        let mut ready = None;
+
+        // polls the futures in `start..` first, then the ones in `..start`
+        for polled_range in [start..L::USIZE, 0..start] {
+            this.futures.each_mut().for_each(|index, fut| {
+                if ready.is_none() && polled_range.contains(&index) {
+                    if let Poll::Ready(output) = Pin::new(fut).poll(cx) {
+                        ready = Some((index, output));
+                    }
+                }
+            });
+        }
+
+        match ready {
+            Some((index, output)) => {
+                this.done = true;
+                let index = Fin::new(index).expect("index is less than the length");
+                Poll::Ready((index, output))
+            }
+            None => Poll::Pending,
+        }
+    }
+}
+
+impl<F, L: PeanoInt> Debug for Select<F, L> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("Select").field("len", &L::USIZE).finish_non_exhaustive()
+    }
+}
@@ -95,8 +95,15 @@ mod imply_trait;
 
 pub mod receiver;
 
+pub mod nmap;
+
+pub mod encoding;
+
+pub mod future;
+
 pub use crate::{
     nlist::*,
+    nmap::{NMap, NSet},
     peano::{PeanoInt, PeanoWit, PlusOne, Zero},
 };
 
@@ -107,9 +114,17 @@ pub use crate::{
 pub mod __ {
     pub use konst::destructure;
 
-    pub use core::primitive::bool;
+    pub use crate::nlist::splitting::split_suffix;
+
+    pub use core::primitive::{bool, usize};
+
+    pub use core::mem::forget;
+
+    pub use core::option::Option::{self, None, Some};
+
+    pub use core::result::Result::{self, Err, Ok};
 
-    pub use core::compile_error;
+    pub use core::{compile_error, concat, stringify};
 }
 
 
@@ -1,4 +1,6 @@
 pub(crate) mod internal_macros;
+mod bool_macro;
+mod const_visitor_macro;
 mod destructuring_macros;
 mod from_fn_macro;
 mod iterator_macros;
@@ -8,10 +10,32 @@ mod iterator_macros;
 
 /// Constructs an [`NList`](crate::NList) 
 ///
-/// This macro can be used in two ways:
+/// This macro can be used in these ways:
 /// - `nlist![a, b, c]`: creates an NList with the listed elements
+/// - `nlist![a, b; ..rest]`: creates an NList with the listed elements,
+///   followed by the elements of the `rest` NList
+///   (with `rest: NList<T, L>` this evaluates to `NList<T, peano::Add<Peano!(2), L>>`)
 /// - `nlist![val; LEN]`: creates an NList by repeating a [`Copy`] value `LEN` times.
-/// (`LEN` must be either a usize expression or `_`)
+/// - `nlist![val => clone; LEN]`: creates an NList by [cloning](Clone) `val`,
+///   with [`NList::repeat_clone`].
+/// - `nlist![from_fn |i| expr; LEN]`: creates an NList by evaluating `expr` for each index `i`,
+///   with [`NList::from_fn`].
+///
+/// In all of the repeating forms,
+/// `LEN` must be either a usize expression or `_`,
+/// where `_` infers the length from context.
+///
+/// `..` is only treated as splicing after a `;`,
+/// so `nlist![..5, ..8]` is still a list of two [`RangeTo`]s,
+/// and `nlist![..]` is a list of one [`RangeFull`].
+/// To construct a list whose length is inferred from context,
+/// use one of the repeating forms with a `_` length, or [`Default::default`].
+///
+/// The `from_fn` marker is required to construct a list from the index,
+/// because `nlist![|x| x + 1; LEN]` repeats the (`Copy`) closure `LEN` times.
+///
+/// The `nlist![val => clone; LEN]` and `nlist![from_fn |i| expr; LEN]` forms 
+/// can't be used in const contexts, the other forms can.
 ///
 /// # Example
 ///
@@ -26,6 +50,21 @@ mod iterator_macros;
 ///
 /// ```
 ///
+/// # Splicing a list
+///
+/// ```rust
+/// use nlist::{NList, Peano, PeanoInt, peano, nlist};
+///
+/// const LIST: NList<u32, Peano!(5)> = prepend_two(nlist![8, 13, 21]);
+///
+/// assert_eq!(LIST, nlist![3, 5, 8, 13, 21]);
+///
+/// const fn prepend_two<L: PeanoInt>(rest: NList<u32, L>) -> NList<u32, peano::Add<Peano!(2), L>> {
+///     nlist![3, 5; ..rest]
+/// }
+///
+/// ```
+///
 /// # Repeat elements
 ///
 /// Repeating a [`Copy`] value to construct an [`NList`]
@@ -43,10 +82,62 @@ mod iterator_macros;
 ///
 /// ```
 ///
+/// Repeating a [`Clone`] value
+///
+/// ```rust
+/// use nlist::{NList, Peano, nlist};
+///
+/// let list: NList<String, Peano!(3)> = nlist![String::from("hi") => clone; _];
+/// assert_eq!(list.into_array(), ["hi", "hi", "hi"]);
+///
+/// let list = nlist![vec![3, 5] => clone; 2];
+/// assert_eq!(list.into_array(), [vec![3, 5], vec![3, 5]]);
+///
+/// ```
+///
+/// # Constructing from the index
+///
+/// ```rust
+/// use nlist::{NList, Peano, nlist};
+///
+/// let list = nlist![from_fn |i| i * 10; 4];
+/// assert_eq!(list.into_array(), [0, 10, 20, 30]);
+///
+/// let list: NList<u64, Peano!(3)> = nlist![from_fn |i| 1 << i; _];
+/// assert_eq!(list.into_array(), [1, 2, 4]);
+///
+/// ```
+///
 /// [`Copy`]: core::marker::Copy
 /// [`NList`]: crate::NList
+/// [`NList::from_fn`]: crate::NList::from_fn
+/// [`NList::repeat_clone`]: crate::NList::repeat_clone
+/// [`Default::default`]: core::default::Default::default
+/// [`RangeTo`]: core::ops::RangeTo
+/// [`RangeFull`]: core::ops::RangeFull
 #[macro_export]
 macro_rules! nlist {
+    (from_fn |$param:tt $(: $param_ty:ty)?| $body:expr; _) => {
+        $crate::NList::from_fn(|$param $(: $param_ty)?| $body)
+    };
+    (from_fn |$param:tt $(: $param_ty:ty)?| $body:expr; $len:expr) => {
+        $crate::NList::<_, $crate::Peano!($len)>::from_fn(|$param $(: $param_ty)?| $body)
+    };
+    ($($expr:expr),+ ; .. $rest:expr $(,)?) => {
+        $crate::__nlist!{[$($expr)*] {
+            let rest: $crate::NList<_, _> = $rest;
+            rest
+        }}
+    };
+    ($($expr:expr),+ ; .. $rest:expr, $($more:tt)+) => {
+        $crate::__::compile_error!{"`..` splicing is only supported at the end of `nlist`"}
+    };
+    ($expr:expr => clone; _) => {
+        $crate::NList::repeat_clone($expr)
+    };
+    ($expr:expr => clone; $len:expr) => {
+        $crate::NList::<_, $crate::Peano!($len)>::repeat_clone($expr)
+    };
     ($expr:expr; _) => {
         $crate::NList::repeat_copy($expr)
     };
@@ -54,18 +145,18 @@ macro_rules! nlist {
         $crate::NList::<_, $crate::Peano!($len)>::repeat_copy($expr)
     };
     ($($expr:expr),* $(,)?) => {
-        $crate::__nlist!{$($expr)*}
+        $crate::__nlist!{[$($expr)*] $crate::NList::nil()}
     };
 }
 
 #[macro_export]
 #[doc(hidden)]
 macro_rules! __nlist {
-    ($first:tt $($rest:tt)*) => {
-        $crate::NList::cons($first, $crate::__nlist!($($rest)*))
+    ([$first:tt $($rest:tt)*] $tail:expr) => {
+        $crate::NList::cons($first, $crate::__nlist!([$($rest)*] $tail))
     };
-    () => {
-        $crate::NList::nil()
+    ([] $tail:expr) => {
+        $tail
     };
 }
 
@@ -0,0 +1,189 @@
+/// Evaluates a boolean expression to a type-level [`Boolean`](crate::boolean::Boolean)
+/// 
+/// # Syntax
+/// 
+/// These are the supported operands:
+/// - `true`/`false`: evaluate to `Bool<true>`/`Bool<false>` respectively.
+/// - identifiers: which must be [`Boolean`](crate::boolean::Boolean) types, e.g.: `B`.
+/// - `{ ... }`: braced types, 
+///   for any [`Boolean`](crate::boolean::Boolean) type, e.g.: `{ IsLt<A, B> }`.
+/// - `( ... )`: parenthesized sub-expressions.
+/// 
+/// These are the supported operators, from highest to lowest precedence,
+/// which is the same precedence as in Rust expressions:
+/// 
+/// | Syntax          | Evaluates to                                    |
+/// |-----------------|-------------------------------------------------|
+/// | `!A`            | [`Not<A>`](crate::boolean::Not)                 |
+/// | `A & B`         | [`And<A, B>`](crate::boolean::And)              |
+/// | `A ^ B`         | [`Xor<A, B>`](crate::boolean::Xor)              |
+/// | `A \| B`        | [`Or<A, B>`](crate::boolean::Or)                |
+/// | `A == B`        | [`BoolEq<A, B>`](crate::boolean::BoolEq)        |
+/// | `A != B`        | [`Xor<A, B>`](crate::boolean::Xor)              |
+/// | `A && B`        | [`And<A, B>`](crate::boolean::And)              |
+/// | `A \|\| B`      | [`Or<A, B>`](crate::boolean::Or)                |
+/// | `A => B`        | [`Implies<A, B>`](crate::boolean::Implies)      |
+/// 
+/// All binary operators are left associative, except for `=>`,
+/// which is right associative.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use nlist::{Peano, PeanoInt};
+/// use nlist::boolean::{Bool, Boolean, BoolWitG};
+/// use nlist::peano::{IsLt, IsZero};
+/// 
+/// assert!(!in_range::<Peano!(0)>());
+/// assert!(in_range::<Peano!(1)>());
+/// assert!(in_range::<Peano!(2)>());
+/// assert!(!in_range::<Peano!(3)>());
+/// 
+/// const fn in_range<L: PeanoInt>() -> bool {
+///     type InRange<L> = nlist::Bool!(!{IsZero<L>} && {IsLt<L, Peano!(3)>});
+/// 
+///     matches!(InRange::<L>::BOOL_WIT, BoolWitG::True(_))
+/// }
+/// 
+/// let _: nlist::Bool!(true => false) = Bool::<false>;
+/// let _: nlist::Bool!(false | true & false) = Bool::<false>;
+/// let _: nlist::Bool!((false | true) & true) = Bool::<true>;
+/// let _: nlist::Bool!(true ^ false == true) = Bool::<true>;
+/// ```
+/// 
+#[macro_export]
+macro_rules! Bool {
+    ($($expr:tt)+) => {
+        $crate::__bool_expr!{@implies [] $($expr)+}
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bool_expr {
+    // `=>` operator, right associative
+    (@implies [$($lhs:tt)+] => $($rhs:tt)+) => {
+        $crate::boolean::Implies<
+            $crate::__bool_expr!{@lazy_or [] [] $($lhs)+},
+            $crate::__bool_expr!{@implies [] $($rhs)+},
+        >
+    };
+    (@implies [$($lhs:tt)*] $first:tt $($rem:tt)*) => {
+        $crate::__bool_expr!{@implies [$($lhs)* $first] $($rem)*}
+    };
+    (@implies [$($lhs:tt)+]) => {
+        $crate::__bool_expr!{@lazy_or [] [] $($lhs)+}
+    };
+
+    // `||` operator
+    (@lazy_or [$($prev:tt)*] [$($curr:tt)+] || $($rem:tt)+) => {
+        $crate::__bool_expr!{@lazy_or [$($prev)* ($($curr)+) Or] [] $($rem)+}
+    };
+    (@lazy_or [$($prev:tt)*] [$($curr:tt)*] $first:tt $($rem:tt)*) => {
+        $crate::__bool_expr!{@lazy_or [$($prev)*] [$($curr)* $first] $($rem)*}
+    };
+    (@lazy_or [$($prev:tt)*] [$($curr:tt)+]) => {
+        $crate::__bool_expr!{@fold lazy_and [] $($prev)* ($($curr)+)}
+    };
+
+    // `&&` operator
+    (@lazy_and [$($prev:tt)*] [$($curr:tt)+] && $($rem:tt)+) => {
+        $crate::__bool_expr!{@lazy_and [$($prev)* ($($curr)+) And] [] $($rem)+}
+    };
+    (@lazy_and [$($prev:tt)*] [$($curr:tt)*] $first:tt $($rem:tt)*) => {
+        $crate::__bool_expr!{@lazy_and [$($prev)*] [$($curr)* $first] $($rem)*}
+    };
+    (@lazy_and [$($prev:tt)*] [$($curr:tt)+]) => {
+        $crate::__bool_expr!{@fold eq [] $($prev)* ($($curr)+)}
+    };
+
+    // `==` and `!=` operators
+    (@eq [$($prev:tt)*] [$($curr:tt)+] == $($rem:tt)+) => {
+        $crate::__bool_expr!{@eq [$($prev)* ($($curr)+) BoolEq] [] $($rem)+}
+    };
+    (@eq [$($prev:tt)*] [$($curr:tt)+] != $($rem:tt)+) => {
+        $crate::__bool_expr!{@eq [$($prev)* ($($curr)+) Xor] [] $($rem)+}
+    };
+    (@eq [$($prev:tt)*] [$($curr:tt)*] $first:tt $($rem:tt)*) => {
+        $crate::__bool_expr!{@eq [$($prev)*] [$($curr)* $first] $($rem)*}
+    };
+    (@eq [$($prev:tt)*] [$($curr:tt)+]) => {
+        $crate::__bool_expr!{@fold or [] $($prev)* ($($curr)+)}
+    };
+
+    // `|` operator
+    (@or [$($prev:tt)*] [$($curr:tt)+] | $($rem:tt)+) => {
+        $crate::__bool_expr!{@or [$($prev)* ($($curr)+) Or] [] $($rem)+}
+    };
+    (@or [$($prev:tt)*] [$($curr:tt)*] $first:tt $($rem:tt)*) => {
+        $crate::__bool_expr!{@or [$($prev)*] [$($curr)* $first] $($rem)*}
+    };
+    (@or [$($prev:tt)*] [$($curr:tt)+]) => {
+        $crate::__bool_expr!{@fold xor [] $($prev)* ($($curr)+)}
+    };
+
+    // `^` operator
+    (@xor [$($prev:tt)*] [$($curr:tt)+] ^ $($rem:tt)+) => {
+        $crate::__bool_expr!{@xor [$($prev)* ($($curr)+) Xor] [] $($rem)+}
+    };
+    (@xor [$($prev:tt)*] [$($curr:tt)*] $first:tt $($rem:tt)*) => {
+        $crate::__bool_expr!{@xor [$($prev)*] [$($curr)* $first] $($rem)*}
+    };
+    (@xor [$($prev:tt)*] [$($curr:tt)+]) => {
+        $crate::__bool_expr!{@fold and [] $($prev)* ($($curr)+)}
+    };
+
+    // `&` operator
+    (@and [$($prev:tt)*] [$($curr:tt)+] & $($rem:tt)+) => {
+        $crate::__bool_expr!{@and [$($prev)* ($($curr)+) And] [] $($rem)+}
+    };
+    (@and [$($prev:tt)*] [$($curr:tt)*] $first:tt $($rem:tt)*) => {
+        $crate::__bool_expr!{@and [$($prev)*] [$($curr)* $first] $($rem)*}
+    };
+    (@and [$($prev:tt)*] [$($curr:tt)+]) => {
+        $crate::__bool_expr!{@fold unary [] $($prev)* ($($curr)+)}
+    };
+
+    // left-associative folding of the operands of a binary operator
+    (@fold $next:ident [] ($($first:tt)+) $($rem:tt)*) => {
+        $crate::__bool_expr!{@fold $next [$crate::__bool_expr!{@$next [] [] $($first)+}] $($rem)*}
+    };
+    (@fold $next:ident [$($acc:tt)+] $op:ident ($($operand:tt)+) $($rem:tt)*) => {
+        $crate::__bool_expr!{
+            @fold $next 
+            [$crate::boolean::$op<$($acc)+, $crate::__bool_expr!{@$next [] [] $($operand)+}>]
+            $($rem)*
+        }
+    };
+    (@fold $next:ident [$($acc:tt)+]) => {
+        $($acc)+
+    };
+
+    // unary operator and operands
+    (@unary [] [] ! $($operand:tt)+) => {
+        $crate::boolean::Not<$crate::__bool_expr!{@unary [] [] $($operand)+}>
+    };
+    (@unary [] [] true) => {
+        $crate::boolean::Bool<true>
+    };
+    (@unary [] [] false) => {
+        $crate::boolean::Bool<false>
+    };
+    (@unary [] [] ($($expr:tt)+)) => {
+        $crate::__bool_expr!{@implies [] $($expr)+}
+    };
+    (@unary [] [] {$ty:ty}) => {
+        $ty
+    };
+    (@unary [] [] $ident:ident) => {
+        $ident
+    };
+    (@unary [] [] $($tokens:tt)*) => {
+        $crate::__::compile_error!{$crate::__::concat!(
+            "expected an operand (`true`, `false`, an identifier, `{type}`, or `(expr)`)",
+            ", found: `",
+            $crate::__::stringify!($($tokens)*),
+            "`",
+        )}
+    };
+}
@@ -0,0 +1,294 @@
+/// Declares a type with const fns that iterate over [`NList`]s,
+/// from closures that handle one element at a time.
+///
+/// This is an alternative to writing a recursive const fn
+/// with the `rec_*` macros (e.g.: [`rec_map`]),
+/// the recursion is done by the generated functions.
+///
+/// # Traits
+///
+/// Each function also implements the matching trait for the declared type,
+/// so that it can be passed to the generic [`NList`] methods:
+/// - `map_const` implements [`ConstMapper<E>`](crate::ConstMapper),
+///   used by [`NList::map_const`]
+/// - `fold_const` implements [`ConstFolder<E>`](crate::ConstFolder),
+///   used by [`NList::fold_const`]
+/// - `for_each_const` implements [`ConstVisitor<E>`](crate::ConstVisitor),
+///   used by [`NList::for_each_const`]
+///
+/// Calling trait methods in const fns requires const trait impls,
+/// which aren't stable as of Rust 1.83,
+/// so the generic [`NList`] methods can only be called at runtime,
+/// while the inherent functions generated by this macro can be called in const.
+///
+/// # Syntax
+///
+/// The macro takes a unit struct declaration followed by any of these functions,
+/// in this order, each of them optional:
+///
+/// - `map_const = |elem: E| -> U { ... };`:
+///   generates `const fn map_const<L: PeanoInt>(list: NL) -> NList<U, L>`
+///
+/// - `fold_const = |accum: A, elem: E| -> A { ... };`:
+///   generates `const fn fold_const<L: PeanoInt>(list: NL, accum: A) -> A`,
+///   the closure returns the next value of the accumulator.
+///   The `-> A` return type is optional, and must be the accumulator type if written.
+///
+/// - `for_each_const = |elem: E| { ... };`:
+///   generates `const fn for_each_const<L: PeanoInt>(list: NL)`
+///
+/// Where `E` determines the type of the `NL` parameter:
+/// - If `E == T`: `NL == NList<T, L>`
+/// - If `E == &T`: `NL == &NList<T, L>`
+/// - If `E == &mut T`: `NL == &mut NList<T, L>`
+///
+/// (so lists of references can only be taken by reference)
+///
+/// Taking the list by value in `fold_const` and `for_each_const`
+/// requires the closure to consume the element,
+/// which is not possible in const for non-`Copy` elements that are only used by reference.
+///
+/// # Example
+///
+/// ```rust
+/// use nlist::{NList, Peano, nlist};
+///
+/// nlist::const_visitor! {
+///     /// Operations over lists of `u128`
+///     struct Hundreds;
+///
+///     map_const = |elem: u128| -> u128 { elem + 100 };
+///
+///     fold_const = |sum: u128, elem: &u128| { sum + *elem };
+///
+///     for_each_const = |elem: &mut u128| { *elem *= 100 };
+/// }
+///
+/// const MAPPED: NList<u128, Peano!(3)> = Hundreds::map_const(nlist![3, 5, 8]);
+/// assert_eq!(MAPPED, nlist![103, 105, 108]);
+///
+/// const SUM: u128 = Hundreds::fold_const(&nlist![3, 5, 8], 0);
+/// assert_eq!(SUM, 16);
+///
+/// const MULTIPLIED: NList<u128, Peano!(3)> = {
+///     let mut list = nlist![3, 5, 8];
+///     Hundreds::for_each_const(&mut list);
+///     list
+/// };
+/// assert_eq!(MULTIPLIED, nlist![300, 500, 800]);
+///
+/// ```
+///
+/// [`NList`]: crate::NList
+/// [`NList::map_const`]: crate::NList::map_const
+/// [`NList::fold_const`]: crate::NList::fold_const
+/// [`NList::for_each_const`]: crate::NList::for_each_const
+/// [`rec_map`]: crate::rec_map
+#[macro_export]
+macro_rules! const_visitor {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident;
+
+        $($ops:tt)*
+    ) => {
+        $(#[$attr])*
+        $vis struct $name;
+
+        $crate::__const_visitor_map!{($vis $name) $($ops)*}
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __const_visitor_map {
+    (
+        ($vis:vis $name:ident) 
+        map_const = |$elem:tt: &mut $elem_ty:ty $(,)?| -> $ret_ty:ty $block:block;
+        $($rem:tt)*
+    ) => {
+        $crate::__const_visitor_map!{
+            @fn ($vis $name) (&mut) (&'__a mut) $elem $elem_ty, $ret_ty, $block
+        }
+        $crate::__const_visitor_fold!{($vis $name) $($rem)*}
+    };
+    (
+        ($vis:vis $name:ident) 
+        map_const = |$elem:tt: & $elem_ty:ty $(,)?| -> $ret_ty:ty $block:block;
+        $($rem:tt)*
+    ) => {
+        $crate::__const_visitor_map!{
+            @fn ($vis $name) (&) (&'__a) $elem $elem_ty, $ret_ty, $block
+        }
+        $crate::__const_visitor_fold!{($vis $name) $($rem)*}
+    };
+    (
+        ($vis:vis $name:ident) 
+        map_const = |$elem:tt: $elem_ty:ty $(,)?| -> $ret_ty:ty $block:block;
+        $($rem:tt)*
+    ) => {
+        $crate::__const_visitor_map!{@fn ($vis $name) () () $elem $elem_ty, $ret_ty, $block}
+        $crate::__const_visitor_fold!{($vis $name) $($rem)*}
+    };
+    (
+        @fn ($vis:vis $name:ident) ($($reffness:tt)*) ($($lt_reffness:tt)*) 
+        $elem:tt $elem_ty:ty, $ret_ty:ty, $block:block
+    ) => {
+        impl $name {
+            /// Maps each element of the list.
+            $vis const fn map_const<L: $crate::PeanoInt>(
+                list: $($reffness)* $crate::NList<$elem_ty, L>,
+            ) -> $crate::NList<$ret_ty, L> {
+                $crate::rec_map!{list, |$elem: $($reffness)* $elem_ty, next| {
+                    let elem: $ret_ty = $block;
+                    (elem, Self::map_const(next))
+                }}
+            }
+        }
+
+        impl<'__a> $crate::ConstMapper<$($lt_reffness)* $elem_ty> for $name {
+            type Output = $ret_ty;
+
+            fn map_elem($elem: $($lt_reffness)* $elem_ty) -> $ret_ty $block
+        }
+    };
+    (($vis:vis $name:ident) $($rem:tt)*) => {
+        $crate::__const_visitor_fold!{($vis $name) $($rem)*}
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __const_visitor_fold {
+    (
+        ($vis:vis $name:ident) fold_const =
+            |$accum:tt: $accum_ty:ty, $elem:tt: &mut $elem_ty:ty $(,)?|
+            $(-> $ret_ty:ty)? $block:block;
+        $($rem:tt)*
+    ) => {
+        $crate::__const_visitor_fold!{
+            @fn ($vis $name) (&mut) (&'__a mut) 
+            $accum $accum_ty, $elem $elem_ty, $(-> $ret_ty)? $block
+        }
+        $crate::__const_visitor_for_each!{($vis $name) $($rem)*}
+    };
+    (
+        ($vis:vis $name:ident) fold_const =
+            |$accum:tt: $accum_ty:ty, $elem:tt: & $elem_ty:ty $(,)?|
+            $(-> $ret_ty:ty)? $block:block;
+        $($rem:tt)*
+    ) => {
+        $crate::__const_visitor_fold!{
+            @fn ($vis $name) (&) (&'__a) 
+            $accum $accum_ty, $elem $elem_ty, $(-> $ret_ty)? $block
+        }
+        $crate::__const_visitor_for_each!{($vis $name) $($rem)*}
+    };
+    (
+        ($vis:vis $name:ident) fold_const =
+            |$accum:tt: $accum_ty:ty, $elem:tt: $elem_ty:ty $(,)?|
+            $(-> $ret_ty:ty)? $block:block;
+        $($rem:tt)*
+    ) => {
+        $crate::__const_visitor_fold!{
+            @fn ($vis $name) () () 
+            $accum $accum_ty, $elem $elem_ty, $(-> $ret_ty)? $block
+        }
+        $crate::__const_visitor_for_each!{($vis $name) $($rem)*}
+    };
+    (
+        @fn ($vis:vis $name:ident) ($($reffness:tt)*) ($($lt_reffness:tt)*)
+        $accum:tt $accum_ty:ty,
+        $elem:tt $elem_ty:ty,
+        $(-> $ret_ty:ty)? $block:block
+    ) => {
+        impl $name {
+            /// Folds the elements of the list into an accumulator.
+            $vis const fn fold_const<L: $crate::PeanoInt>(
+                list: $($reffness)* $crate::NList<$elem_ty, L>,
+                accum: $accum_ty,
+            ) -> $accum_ty {
+                $crate::rec_fold!{list, accum, |elem: $($reffness)* $elem_ty, next| {
+                    let $accum: $accum_ty = accum;
+                    let $elem: $($reffness)* $elem_ty = elem;
+                    let accum: $accum_ty = $crate::__const_visitor_fold!{@ret $(-> $ret_ty)? $block};
+                    Self::fold_const(next, accum)
+                }}
+            }
+        }
+
+        impl<'__a> $crate::ConstFolder<$($lt_reffness)* $elem_ty> for $name {
+            type Accum = $accum_ty;
+
+            fn fold_elem($accum: $accum_ty, $elem: $($lt_reffness)* $elem_ty) -> $accum_ty {
+                $crate::__const_visitor_fold!{@ret $(-> $ret_ty)? $block}
+            }
+        }
+    };
+    (@ret $block:block) => {
+        $block
+    };
+    // checks that the return type annotation is the type of the accumulator
+    (@ret -> $ret_ty:ty $block:block) => {{
+        let ret: $ret_ty = $block;
+        ret
+    }};
+    (($vis:vis $name:ident) $($rem:tt)*) => {
+        $crate::__const_visitor_for_each!{($vis $name) $($rem)*}
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __const_visitor_for_each {
+    (
+        ($vis:vis $name:ident) 
+        for_each_const = |$elem:tt: &mut $elem_ty:ty $(,)?| $block:block; 
+    ) => {
+        $crate::__const_visitor_for_each!{
+            @fn ($vis $name) (&mut) (&'__a mut) $elem $elem_ty, $block
+        }
+    };
+    (
+        ($vis:vis $name:ident) 
+        for_each_const = |$elem:tt: & $elem_ty:ty $(,)?| $block:block; 
+    ) => {
+        $crate::__const_visitor_for_each!{@fn ($vis $name) (&) (&'__a) $elem $elem_ty, $block}
+    };
+    (
+        ($vis:vis $name:ident) 
+        for_each_const = |$elem:tt: $elem_ty:ty $(,)?| $block:block; 
+    ) => {
+        $crate::__const_visitor_for_each!{@fn ($vis $name) () () $elem $elem_ty, $block}
+    };
+    (
+        @fn ($vis:vis $name:ident) ($($reffness:tt)*) ($($lt_reffness:tt)*) 
+        $elem:tt $elem_ty:ty, $block:block
+    ) => {
+        impl $name {
+            /// Calls a function on each element of the list.
+            $vis const fn for_each_const<L: $crate::PeanoInt>(
+                list: $($reffness)* $crate::NList<$elem_ty, L>,
+            ) {
+                $crate::rec_for_each!{list, |$elem: $($reffness)* $elem_ty, next| {
+                    let () = $block;
+                    Self::for_each_const(next)
+                }}
+            }
+        }
+
+        impl<'__a> $crate::ConstVisitor<$($lt_reffness)* $elem_ty> for $name {
+            fn visit_elem($elem: $($lt_reffness)* $elem_ty) {
+                let () = $block;
+            }
+        }
+    };
+    (($vis:vis $name:ident)) => {};
+    (($vis:vis $name:ident) $($rem:tt)+) => {
+        $crate::__::compile_error!{$crate::__::concat!(
+            "expected `map_const`, `fold_const`, or `for_each_const` function (in that order), ",
+            "found: ",
+            $crate::__::stringify!($($rem)*),
+        )}
+    };
+}
@@ -3,12 +3,21 @@
 /// This macro uses the same syntax as array patterns, with the limitation that 
 /// it only supports `..` patterns at the end.
 /// 
+/// `..` can't be supported elsewhere in a pattern, 
+/// because [`NList`] is a linked list with a generic length,
+/// so elements after the `..` are at a position that a pattern can't express.
+/// 
 /// # Alternatives
 /// 
 /// The [`unlist`](crate::unlist) macro allows destructuring [`NList`] 
 /// by value in some contexts where this macro can't be used,
+/// and supports `..` patterns anywhere in the list,
 /// refer to its docs for more details.
 /// 
+/// `nlist_pat` subpatterns of `unlist` are destructured by `unlist`,
+/// so they also support `..` patterns anywhere in the list
+/// (e.g.: `unlist!{[nlist_pat![first, rest @ .., last]] = list}`).
+/// 
 /// # Example
 /// 
 /// ### Destructuring
@@ -44,6 +53,23 @@
 /// }
 /// ```
 /// 
+/// ### Nested lists
+/// 
+/// `nlist_pat` can be used as a subpattern of `nlist_pat`,
+/// to destructure an [`NList2D`](crate::NList2D) in one pattern.
+/// 
+/// ```rust
+/// use nlist::{nlist, nlist_pat};
+/// 
+/// let nlist_pat![nlist_pat![a, b], nlist_pat![c, rest @ ..]] = 
+///     &mut nlist![nlist![3, 5], nlist![8, 13]];
+/// 
+/// assert_eq!(a, &mut 3);
+/// assert_eq!(b, &mut 5);
+/// assert_eq!(c, &mut 8);
+/// assert_eq!(rest, &mut nlist![13]);
+/// ```
+/// 
 /// ### Pattern matching
 /// 
 /// ```rust
@@ -72,9 +98,10 @@ macro_rules! nlist_pat {
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __nlist_pat {
-    (($($len:ty)?) .., $($rest:tt)+) => (
+    (($($len:ty)?) $($($($binding:ident)+)? $(_)? @)? .., $($rest:tt)+) => (
         $crate::__::compile_error!{
-            "nlist_pat only supports `..` patterns at the end"
+            "nlist_pat only supports `..` patterns at the end, \
+             the `unlist` macro supports `..` patterns anywhere"
         }
     );
     (($($len:ty)?) $($($($binding:ident)+)? $(_)? @)? .. $(,)?) => (
@@ -111,8 +138,18 @@ macro_rules! __nlist_count_elems {
 
 /// Destructures an [`NList`](crate::NList) by value into its elements
 /// 
-/// This macro uses the same syntax as array patterns, with the limitation that 
-/// it only supports `..` patterns at the end.
+/// This macro uses the same syntax as array patterns,
+/// supporting `..` patterns at the start, in the middle, and at the end.
+/// 
+/// Elements can be destructured with nested [`nlist_pat`] patterns
+/// (e.g.: `unlist!{[nlist_pat![a, b], nlist_pat![c, d]] = list}`),
+/// which are destructured with `unlist` semantics,
+/// so that lists of lists can be destructured by value in const fns.
+/// 
+/// A `rest @ ..` pattern that isn't at the end binds an 
+/// `NList<T, peano::SubSat<L, Suffix>>`,
+/// where `L` is the length of the list after the elements before `rest`,
+/// and `Suffix` is the amount of elements after `rest`.
 /// 
 /// # Motivation
 /// 
@@ -166,6 +203,28 @@ macro_rules! __nlist_count_elems {
 ///    | - value is dropped here
 /// 
 /// ```
+/// 
+/// ### `..` in the middle
+/// 
+/// ```rust
+/// use nlist::{NList, PeanoInt, Peano, nlist, peano, unlist};
+/// 
+/// assert_eq!(split_ends(nlist![3, 5]), (3, nlist![], 5));
+/// assert_eq!(split_ends(nlist![3, 5, 8, 13]), (3, nlist![5, 8], 13));
+/// 
+/// const fn split_ends<T, L>(list: NList<T, peano::Add<Peano!(2), L>>) -> (T, NList<T, L>, T)
+/// where
+///     L: PeanoInt
+/// {
+///     unlist!{[first, rest @ .., last] = list}
+///     
+///     // `rest` is an `NList<T, peano::SubSat<L, Peano!(0)>>`,
+///     // which the compiler doesn't know to be the same as `NList<T, L>`
+///     let rest = rest.coerce_len(peano::proofs::sub_identity());
+///     
+///     (first, rest, last)
+/// }
+/// ```
 #[macro_export]
 macro_rules! unlist {
     ([$($patterns:tt)*] = $val:expr $(;)?) => ( 
@@ -179,14 +238,32 @@ macro_rules! unlist {
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __unlist {
-    (($list:ident) .., $($rest:tt)+) => (
-        $crate::__::compile_error!{
-            "nlist_pat only supports `..` patterns at the end"
-        }
-    );
     (($list:ident) $($($($binding:ident)+)? $(_)? @)? .. $(,)?) => (
         let $crate::__first_pat!($($($($binding)+,)?)? _,) = $list;
     );
+    (($list:ident) $($($($binding:ident)+)? $(_)? @)? .., $($rest:tt)+) => (
+        let split = $crate::__::split_suffix::<
+            _,
+            _,
+            $crate::__nlist_count_elems!(($crate::Zero) $($rest)+),
+        >($list);
+
+        $crate::__::destructure!{($list, suffix) = split}
+
+        let $crate::__first_pat!($($($($binding)+,)?)? _,) = $list;
+
+        $crate::__unlist_suffix!{(suffix) $($rest)+}
+    );
+    (($list:ident) $(::)? $krate:ident :: nlist_pat ! $inner:tt $(, $($rest:tt)*)?) => (
+        $crate::__unlist!{($list) nlist_pat! $inner $(, $($rest)*)?}
+    );
+    (($list:ident) nlist_pat ! $inner:tt $(, $($rest:tt)*)?) => (
+        $crate::__::destructure!{(elem, $list) = $list.into_split_head()}
+
+        $crate::__unlist_nested!{elem $inner}
+
+        $crate::__unlist!{($list) $($($rest)*)?}
+    );
     (($list:ident) $pati:pat $(, $($rest:tt)*)?) => (
         $crate::__::destructure!{($pati, $list) = $list.into_split_head()}
 
@@ -194,3 +271,43 @@ macro_rules! __unlist {
     );
     (($list:ident) $(,)?) => ();
 }
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __unlist_suffix {
+    (($list:ident) $($($($binding:ident)+)? $(_)? @)? .. $(, $($rest:tt)*)?) => (
+        $crate::__::compile_error!{
+            "`..` can only be used once per `unlist` pattern"
+        }
+    );
+    (($list:ident) $(::)? $krate:ident :: nlist_pat ! $inner:tt $(, $($rest:tt)*)?) => (
+        $crate::__unlist_suffix!{($list) nlist_pat! $inner $(, $($rest)*)?}
+    );
+    (($list:ident) nlist_pat ! $inner:tt $(, $($rest:tt)*)?) => (
+        $crate::__::destructure!{(elem, $list) = $list.into_split_head()}
+
+        $crate::__unlist_nested!{elem $inner}
+
+        $crate::__unlist_suffix!{($list) $($($rest)*)?}
+    );
+    (($list:ident) $pati:pat $(, $($rest:tt)*)?) => (
+        $crate::__::destructure!{($pati, $list) = $list.into_split_head()}
+
+        $crate::__unlist_suffix!{($list) $($($rest)*)?}
+    );
+    (($list:ident) $(,)?) => ();
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __unlist_nested {
+    ($elem:ident [$($patterns:tt)*]) => (
+        $crate::unlist!{[$($patterns)*] = $elem}
+    );
+    ($elem:ident ($($patterns:tt)*)) => (
+        $crate::unlist!{[$($patterns)*] = $elem}
+    );
+    ($elem:ident {$($patterns:tt)*}) => (
+        $crate::unlist!{[$($patterns)*] = $elem}
+    );
+}
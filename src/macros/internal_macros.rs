@@ -76,6 +76,43 @@ macro_rules! __parse_closure_2_args {
     };
 }  
 
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __parse_step_and_recurse {
+    (
+        $callback:ident ($($args:tt)*)
+
+        |$($spat:tt $(: $sty:ty)?),* $(,)?| 
+        $( -> $ret_ty:ty )?
+        $block:block,
+        |$($rpat:tt $(: $rty:ty)?),* $(,)?| $recurse:expr
+        $(,)?
+    ) => {
+        $crate::$callback! {
+            $($args)*
+
+            |$($spat: $crate::__first_ty!{$($sty,)? _,}),*| 
+            -> $crate::__first_ty!{$($ret_ty,)? _,}
+            $block,
+            |$($rpat: $crate::__first_ty!{$($rty,)? _,}),*| $recurse
+        }
+    };
+    (
+        $callback:ident ($($args:tt)*)
+
+        |$($spat:tt $(: $sty:ty)?),* $(,)?| $expr:expr,
+        |$($rpat:tt $(: $rty:ty)?),* $(,)?| $recurse:expr
+        $(,)?
+    ) => {
+        $crate::$callback! {
+            $($args)*
+
+            |$($spat: $crate::__first_ty!{$($sty,)? _,}),*| -> _ { $expr },
+            |$($rpat: $crate::__first_ty!{$($rty,)? _,}),*| $recurse
+        }
+    };
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 
 macro_rules! declare_type_fn {
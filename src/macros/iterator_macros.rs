@@ -51,11 +51,22 @@ macro_rules! early_termination_warning {() => {"
 Because iteration over the list might terminate before the list is fully consumed,
 by-value iteration over non-Copy types does not work in const,
 and by-value iteration over Copy types requires doing what the by-value example does.
+
+The [`rec_find_map_rest`](crate::rec_find_map_rest) macro supports by-value iteration 
+over non-Copy types in const, by returning the elements that weren't consumed.
 "}}
 
 use early_termination_warning;
 
 
+macro_rules! receiver_params {() => {"
+The element and rest-of-list parameters depend on the type of the list argument:
+- If it's `NList<T, L>`: the parameters are `T` and `NList<T, L::SubOneSat>`
+- If it's `&NList<T, L>`: the parameters are `&T` and `&NList<T, L::SubOneSat>`
+- If it's `&mut NList<T, L>`: the parameters are `&mut T` and `&mut NList<T, L::SubOneSat>`
+"}}
+
+
 //////////////////////////////////////////////////////////////////////////////
 
 /// Helper for writing const fn equivalents of [`NList::all`](crate::NList::all)
@@ -345,6 +356,138 @@ macro_rules! __rec_find_map {
 
 //////////////////////////////////////////////////////////////////////////////
 
+/// Helper for writing const fn equivalents of [`NList::find_map`](crate::NList::find_map)
+/// that take the list by value and return the elements that weren't consumed.
+/// 
+/// This macro acts like a function with this signature:
+/// 
+/// ```rust
+/// use nlist::{NList, PeanoInt, Remainder};
+/// 
+/// fn rec_find_map_rest<T, L, R, M, F>(
+///     list: NList<T, L>, 
+///     mapper: M, 
+///     recurse: F,
+/// ) -> (Option<R>, Remainder<T, L>)
+/// where
+///     L: PeanoInt,
+///     M: FnOnce(&mut Option<T>) -> Option<R>,
+///     F: FnOnce(NList<T, L::SubOneSat>) -> (Option<R>, Remainder<T, L::SubOneSat>),
+/// # { nlist::rec_find_map_rest!{list, |elem| mapper(elem), |next| recurse(next)} }
+/// ```
+/// 
+/// `mapper` is only called when the list is non-empty (i.e.: when `L != 0`),
+/// it's passed a `Some` containing the first element,
+/// which it can take out of the `Option` to consume it.
+/// Returning `Some` stops iteration, returning `None` continues iteration.
+/// 
+/// `recurse` is only called when `mapper` returns `None`,
+/// it's expected to call the function that this macro is used in.
+/// 
+/// The returned [`Remainder`](crate::Remainder) holds all the elements of the list 
+/// that were left in the `Option` passed to `mapper`, 
+/// as well as those that `mapper` wasn't called with,
+/// allowing const code to iterate over lists of non-`Copy` elements 
+/// without dropping the unconsumed elements.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use nlist::{NList, Peano, PeanoInt, Remainder, nlist};
+/// 
+/// // `Thing` has a destructor, so it can't be dropped in const
+/// #[derive(Debug, PartialEq)]
+/// struct Thing(u32);
+/// 
+/// impl Drop for Thing {
+///     fn drop(&mut self) {}
+/// }
+/// 
+/// const FOUND: (Option<Thing>, Remainder<Thing, Peano!(4)>) = 
+///     find_odd(nlist![Thing(2), Thing(4), Thing(5), Thing(8)]);
+/// 
+/// let (found, rem) = FOUND;
+/// assert_eq!(found, Some(Thing(5)));
+/// assert_eq!(rem.into_vec(), vec![Thing(2), Thing(4), Thing(8)]);
+/// 
+/// 
+/// const fn find_odd<L>(list: NList<Thing, L>) -> (Option<Thing>, Remainder<Thing, L>)
+/// where
+///     L: PeanoInt
+/// {
+///     nlist::rec_find_map_rest!{
+///         list, 
+///         |elem: &mut Option<Thing>| match elem {
+///             Some(Thing(x)) if *x % 2 == 1 => elem.take(),
+///             _ => None,
+///         },
+///         |next| find_odd(next)
+///     }
+/// }
+/// ```
+/// 
+#[macro_export]
+macro_rules! rec_find_map_rest {
+    ($in_list:expr, $($closures:tt)*) => {
+        $crate::__parse_step_and_recurse!{__rec_find_map_rest ($in_list,) $($closures)*}
+    }
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __rec_find_map_rest {
+    (
+        $in_list:expr,
+        |$elem:tt: $elem_ty:ty| -> $ret_ty:ty $map_block:block,
+        |$next:tt: $next_ty:ty| $recurse:expr
+    ) => {
+        match $in_list {in_list => {
+            let in_list: $crate::NList<_, _> = in_list;
+
+            match $crate::NList::len_proof(&in_list) {
+                $crate::PeanoWit::Zero(len_te) => {
+                    // works around "destructor cannot be evaluated at compile-time" error
+                    _ = $crate::NList::coerce_len(in_list, len_te);
+
+                    ($crate::__::None, $crate::Remainder::nil().coerce_len(len_te.flip()))
+                }
+                $crate::PeanoWit::PlusOne(len_te) => {
+                    $crate::__::destructure!{
+                        (elem, $next) = $crate::NList::into_split_head(
+                            $crate::NList::coerce_len(in_list, len_te)
+                        )
+                    }
+
+                    let mut slot = $crate::__::Some(elem);
+                    let $next: $next_ty = $next;
+
+                    let found: $crate::__::Option<_> = {
+                        let $elem: $elem_ty = &mut slot;
+                        let ret: $ret_ty = $map_block;
+                        ret
+                    };
+
+                    if found.is_some() {
+                        let rem = $crate::Remainder::from_nlist($next);
+
+                        (found, $crate::Remainder::cons(slot, rem).coerce_len(len_te.flip()))
+                    } else {
+                        // `found` is `None`, forgetting it doesn't leak anything,
+                        // this works around "destructor cannot be evaluated at compile-time" error
+                        $crate::__::forget(found);
+
+                        $crate::__::destructure!{(found, rem) = $recurse}
+
+                        (found, $crate::Remainder::cons(slot, rem).coerce_len(len_te.flip()))
+                    }
+                }
+            }
+        }}
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+
 /// Helper for writing const fn equivalents of [`NList::map`](crate::NList::map)
 /// 
 #[doc = fn_equivalent!(
@@ -598,6 +741,674 @@ macro_rules! __rec_fold {
 
 //////////////////////////////////////////////////////////////////////////////
 
+/// Helper for writing const fn equivalents of [`Iterator::scan`]
+/// that output the accumulator after each step, e.g.: prefix sums.
+/// 
+/// This macro acts like a function with this signature:
+/// 
+/// ```rust
+/// use nlist::{NList, PeanoInt};
+/// 
+/// use nlist::receiver::Receiver;
+/// # use nlist::receiver::HktApply;
+/// 
+/// fn rec_scan<'a, P, T, L, A, S, F>(list: P, accum: A, step: S, recurse: F) -> NList<A, L>
+/// where
+///     P: Receiver<'a, NList<T, L>>,
+///     L: PeanoInt,
+///     T: 'a,
+///     A: Copy,
+///     S: FnOnce(
+///         A, 
+///         # /*
+///         ... // element parameter explained below
+///         # */
+///         # HktApply<'a, P::Hkt, T>, 
+///     ) -> A,
+///     F: FnOnce(
+///         # /*
+///         ... // rest-of-list parameter explained below
+///         # */
+///         # HktApply<'a, P::Hkt, NList<T, L::SubOneSat>>,
+///         A,
+///     ) -> NList<A, L::SubOneSat>,
+/// # { nlist::rec_scan!{list, accum, |a, b| step(a, b), |next, a| recurse(next, a)} }
+/// ```
+/// 
+/// The closures are only called when the list is non-empty (i.e.: when `L != 0`),
+/// `step` is passed the `accum` argument and the first element, 
+/// returning the first element of the output list,
+/// `recurse` is passed the rest of the list and the value returned by `step`,
+/// it's expected to call the function that this macro is used in.
+/// 
+#[doc = receiver_params!()]
+/// 
+/// # Example
+/// 
+/// Computing the offsets of fields in a const
+/// 
+/// ```rust
+/// use nlist::{NList, Peano, PeanoInt, nlist};
+/// 
+/// const OFFSETS: NList<usize, Peano!(4)> = prefix_sums(0, &nlist![3, 5, 8, 13]);
+/// 
+/// assert_eq!(OFFSETS, nlist![3, 8, 16, 29]);
+/// 
+/// const fn prefix_sums<L>(sum: usize, list: &NList<usize, L>) -> NList<usize, L>
+/// where
+///     L: PeanoInt
+/// {
+///     nlist::rec_scan!{list, sum, |sum, elem: &usize| sum + *elem, |next, sum| prefix_sums(sum, next)}
+/// }
+/// ```
+#[macro_export]
+macro_rules! rec_scan {
+    ($in_list:expr, $accum:expr, $($closures:tt)*) => {
+        $crate::__parse_step_and_recurse!{__rec_scan ($in_list, $accum,) $($closures)*}
+    }
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __rec_scan {
+    (
+        $in_list:expr, 
+        $accum:expr,
+
+        |$acc:tt: $acc_ty:ty, $elem:tt: $elem_ty:ty| -> $ret_ty:ty $block:block,
+        |$next:tt: $next_ty:ty, $next_acc:tt: $next_acc_ty:ty| $recurse:expr
+    ) => {
+        $crate::__rec_shared!{
+            $in_list,
+            len_te,
+            || $crate::NList::nil().coerce_len(len_te.flip()),
+            |$elem: $elem_ty, next: _| {
+                let $acc: $acc_ty = $accum;
+                let acc: $ret_ty = $block;
+
+                let $next: $next_ty = next;
+                let $next_acc: $next_acc_ty = acc;
+
+                $crate::NList::cons(acc, $recurse).coerce_len(len_te.flip())
+            }
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+
+/// Helper for writing const fn equivalents of [`NList::map`](crate::NList::map)
+/// where a state is threaded through the mapping.
+/// 
+/// This macro acts like a function with this signature:
+/// 
+/// ```rust
+/// use nlist::{NList, PeanoInt};
+/// 
+/// use nlist::receiver::Receiver;
+/// # use nlist::receiver::HktApply;
+/// 
+/// fn rec_map_with_state<'a, P, T, L, S, U, M, F>(
+///     list: P, 
+///     state: S, 
+///     mapper: M, 
+///     recurse: F,
+/// ) -> (NList<U, L>, S)
+/// where
+///     P: Receiver<'a, NList<T, L>>,
+///     L: PeanoInt,
+///     T: 'a,
+///     M: FnOnce(
+///         S, 
+///         # /*
+///         ... // element parameter explained below
+///         # */
+///         # HktApply<'a, P::Hkt, T>, 
+///     ) -> (U, S),
+///     F: FnOnce(
+///         # /*
+///         ... // rest-of-list parameter explained below
+///         # */
+///         # HktApply<'a, P::Hkt, NList<T, L::SubOneSat>>,
+///         S,
+///     ) -> (NList<U, L::SubOneSat>, S),
+/// # { nlist::rec_map_with_state!{list, state, |a, b| mapper(a, b), |next, a| recurse(next, a)} }
+/// ```
+/// 
+/// The closures are only called when the list is non-empty (i.e.: when `L != 0`),
+/// `mapper` is passed the `state` argument and the first element,
+/// returning the first element of the output list and the next state,
+/// `recurse` is passed the rest of the list and the next state,
+/// it's expected to call the function that this macro is used in.
+/// 
+/// When the list is empty, this returns an empty list and the `state` argument.
+/// 
+#[doc = receiver_params!()]
+/// 
+/// # Example
+/// 
+/// Allocating ranges for buffers of different sizes
+/// 
+/// ```rust
+/// use nlist::{NList, Peano, PeanoInt, nlist};
+/// 
+/// use std::ops::Range;
+/// 
+/// const RANGES: (NList<Range<usize>, Peano!(3)>, usize) = 
+///     alloc_ranges(100, nlist![3, 5, 8]);
+/// 
+/// assert_eq!(RANGES.0, nlist![100..103, 103..108, 108..116]);
+/// assert_eq!(RANGES.1, 116);
+/// 
+/// const fn alloc_ranges<L>(start: usize, sizes: NList<usize, L>) -> (NList<Range<usize>, L>, usize)
+/// where
+///     L: PeanoInt
+/// {
+///     nlist::rec_map_with_state!{
+///         sizes, 
+///         start,
+///         |start, size| (start..start + size, start + size),
+///         |next, start| alloc_ranges(start, next),
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! rec_map_with_state {
+    ($in_list:expr, $state:expr, $($closures:tt)*) => {
+        $crate::__parse_step_and_recurse!{__rec_map_with_state ($in_list, $state,) $($closures)*}
+    }
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __rec_map_with_state {
+    (
+        $in_list:expr, 
+        $state:expr,
+
+        |$st:tt: $st_ty:ty, $elem:tt: $elem_ty:ty| -> $ret_ty:ty $block:block,
+        |$next:tt: $next_ty:ty, $next_st:tt: $next_st_ty:ty| $recurse:expr
+    ) => {
+        $crate::__rec_shared!{
+            $in_list,
+            len_te,
+            || ($crate::NList::nil().coerce_len(len_te.flip()), $state),
+            |$elem: $elem_ty, next: _| {
+                let $st: $st_ty = $state;
+                $crate::__::destructure!{(elem, state): $ret_ty = $block}
+
+                let $next: $next_ty = next;
+                let $next_st: $next_st_ty = state;
+
+                $crate::__::destructure!{(next, state) = $recurse}
+
+                ($crate::NList::cons(elem, next).coerce_len(len_te.flip()), state)
+            }
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+
+/// Helper for writing const fn equivalents of mapping 
+/// the [`enumerate`](Iterator::enumerate)d elements of an [`NList`](crate::NList).
+/// 
+/// This macro acts like a function with this signature:
+/// 
+/// ```rust
+/// use nlist::{NList, PeanoInt};
+/// 
+/// use nlist::receiver::Receiver;
+/// # use nlist::receiver::HktApply;
+/// 
+/// fn rec_enumerate<'a, P, T, L, U, M, F>(
+///     list: P, 
+///     index: usize, 
+///     mapper: M, 
+///     recurse: F,
+/// ) -> NList<U, L>
+/// where
+///     P: Receiver<'a, NList<T, L>>,
+///     L: PeanoInt,
+///     T: 'a,
+///     M: FnOnce(
+///         usize, 
+///         # /*
+///         ... // element parameter explained below
+///         # */
+///         # HktApply<'a, P::Hkt, T>, 
+///     ) -> U,
+///     F: FnOnce(
+///         # /*
+///         ... // rest-of-list parameter explained below
+///         # */
+///         # HktApply<'a, P::Hkt, NList<T, L::SubOneSat>>,
+///         usize,
+///     ) -> NList<U, L::SubOneSat>,
+/// # { nlist::rec_enumerate!{list, index, |a, b| mapper(a, b), |next, a| recurse(next, a)} }
+/// ```
+/// 
+/// The closures are only called when the list is non-empty (i.e.: when `L != 0`),
+/// `mapper` is passed the `index` argument and the first element,
+/// returning the first element of the output list,
+/// `recurse` is passed the rest of the list and `index + 1`,
+/// it's expected to call the function that this macro is used in.
+/// 
+#[doc = receiver_params!()]
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use nlist::{NList, Peano, PeanoInt, nlist};
+/// 
+/// const LIST: NList<(usize, char), Peano!(3)> = enumerate(0, nlist!['f', 'o', 'o']);
+/// 
+/// assert_eq!(LIST, nlist![(0, 'f'), (1, 'o'), (2, 'o')]);
+/// 
+/// const fn enumerate<L>(index: usize, list: NList<char, L>) -> NList<(usize, char), L>
+/// where
+///     L: PeanoInt
+/// {
+///     nlist::rec_enumerate!{list, index, |i, c| (i, c), |next, i| enumerate(i, next)}
+/// }
+/// ```
+#[macro_export]
+macro_rules! rec_enumerate {
+    ($in_list:expr, $index:expr, $($closures:tt)*) => {
+        $crate::__parse_step_and_recurse!{__rec_enumerate ($in_list, $index,) $($closures)*}
+    }
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __rec_enumerate {
+    (
+        $in_list:expr, 
+        $index:expr,
+
+        |$i:tt: $i_ty:ty, $elem:tt: $elem_ty:ty| -> $ret_ty:ty $block:block,
+        |$next:tt: $next_ty:ty, $next_i:tt: $next_i_ty:ty| $recurse:expr
+    ) => {
+        $crate::__rec_shared!{
+            $in_list,
+            len_te,
+            || $crate::NList::nil().coerce_len(len_te.flip()),
+            |$elem: $elem_ty, next: _| {
+                let index: $crate::__::usize = $index;
+
+                let $i: $i_ty = index;
+                let elem: $ret_ty = $block;
+
+                let $next: $next_ty = next;
+                let $next_i: $next_i_ty = index + 1;
+
+                $crate::NList::cons(elem, $recurse).coerce_len(len_te.flip())
+            }
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+
+/// Helper for writing const fn equivalents of 
+/// [`zip`](crate::NList::zip)ping two lists and then [`map`](crate::NList::map)ping them.
+/// 
+/// This macro acts like a function with this signature:
+/// 
+/// ```rust
+/// use nlist::{NList, PeanoInt};
+/// 
+/// use nlist::receiver::Receiver;
+/// # use nlist::receiver::HktApply;
+/// 
+/// fn rec_zip_map<'a, P, Q, T, T2, L, U, M, F>(
+///     lhs: P, 
+///     rhs: Q, 
+///     mapper: M, 
+///     recurse: F,
+/// ) -> NList<U, L>
+/// where
+///     P: Receiver<'a, NList<T, L>>,
+///     Q: Receiver<'a, NList<T2, L>>,
+///     L: PeanoInt,
+///     T: 'a,
+///     T2: 'a,
+///     M: FnOnce(
+///         # /*
+///         ... // element parameters explained below
+///         # */
+///         # HktApply<'a, P::Hkt, T>, 
+///         # HktApply<'a, Q::Hkt, T2>, 
+///     ) -> U,
+///     F: FnOnce(
+///         # /*
+///         ... // rest-of-list parameters explained below
+///         # */
+///         # HktApply<'a, P::Hkt, NList<T, L::SubOneSat>>,
+///         # HktApply<'a, Q::Hkt, NList<T2, L::SubOneSat>>,
+///     ) -> NList<U, L::SubOneSat>,
+/// # { nlist::rec_zip_map!{lhs, rhs, |a, b| mapper(a, b), |a, b| recurse(a, b)} }
+/// ```
+/// 
+/// The closures are only called when the lists are non-empty (i.e.: when `L != 0`),
+/// `mapper` is passed the first element of each list, 
+/// returning the first element of the output list,
+/// `recurse` is passed the rest of each list,
+/// it's expected to call the function that this macro is used in.
+/// 
+/// The element and rest-of-list parameters for each list
+/// depend on whether the list is passed by value, reference, or mutable reference,
+/// in the same way as with [`rec_map`](crate::rec_map).
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use nlist::{NList, Peano, PeanoInt, nlist};
+/// 
+/// const LIST: NList<u64, Peano!(3)> = mul(&nlist![3, 5, 8], nlist![10, 100, 1000]);
+/// 
+/// assert_eq!(LIST, nlist![30, 500, 8000]);
+/// 
+/// const fn mul<L>(lhs: &NList<u64, L>, rhs: NList<u64, L>) -> NList<u64, L>
+/// where
+///     L: PeanoInt
+/// {
+///     nlist::rec_zip_map!{lhs, rhs, |l: &u64, r: u64| *l * r, |lnext, rnext| mul(lnext, rnext)}
+/// }
+/// ```
+#[macro_export]
+macro_rules! rec_zip_map {
+    ($lhs:expr, $rhs:expr, $($closures:tt)*) => {
+        $crate::__parse_step_and_recurse!{__rec_zip_map ($lhs, $rhs,) $($closures)*}
+    }
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __rec_zip_map {
+    (
+        $lhs:expr, 
+        $rhs:expr,
+
+        |$lelem:tt: $lelem_ty:ty, $relem:tt: $relem_ty:ty| -> $ret_ty:ty $block:block,
+        |$lnext:tt: $lnext_ty:ty, $rnext:tt: $rnext_ty:ty| $recurse:expr
+    ) => {
+        match $rhs {rhs => {
+            $crate::__rec_shared!{
+                $lhs,
+                len_te,
+                || {
+                    // works around "destructor cannot be evaluated at compile-time" error
+                    _ = $crate::NList::coerce_len_poly(rhs, len_te);
+
+                    $crate::NList::nil().coerce_len(len_te.flip())
+                },
+                |$lelem: $lelem_ty, lnext: _| {
+                    $crate::__::destructure!{
+                        ($relem, rnext) = $crate::NList::split_head_poly(
+                            $crate::NList::coerce_len_poly(rhs, len_te)
+                        )
+                    }
+                    let _: $relem_ty = $relem;
+
+                    let elem: $ret_ty = $block;
+
+                    let $lnext: $lnext_ty = lnext;
+                    let $rnext: $rnext_ty = rnext;
+
+                    $crate::NList::cons(elem, $recurse).coerce_len(len_te.flip())
+                }
+            }
+        }}
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+
+/// Helper for writing const fn equivalents of 
+/// counting the elements of an [`NList`](crate::NList) that match a predicate.
+/// 
+/// This macro acts like a function with this signature:
+/// 
+/// ```rust
+/// use nlist::{NList, PeanoInt};
+/// 
+/// use nlist::receiver::Receiver;
+/// # use nlist::receiver::HktApply;
+/// 
+/// fn rec_filter_count<'a, P, T, L, M, F>(list: P, predicate: M, recurse: F) -> usize
+/// where
+///     P: Receiver<'a, NList<T, L>>,
+///     L: PeanoInt,
+///     T: 'a,
+///     M: FnOnce(
+///         # /*
+///         ... // element parameter explained below
+///         # */
+///         # HktApply<'a, P::Hkt, T>, 
+///     ) -> bool,
+///     F: FnOnce(
+///         # /*
+///         ... // rest-of-list parameter explained below
+///         # */
+///         # HktApply<'a, P::Hkt, NList<T, L::SubOneSat>>,
+///     ) -> usize,
+/// # { nlist::rec_filter_count!{list, |a| predicate(a), |next| recurse(next)} }
+/// ```
+/// 
+/// The closures are only called when the list is non-empty (i.e.: when `L != 0`),
+/// `predicate` is passed the first element,
+/// `recurse` is passed the rest of the list,
+/// it's expected to call the function that this macro is used in.
+/// 
+#[doc = receiver_params!()]
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use nlist::{NList, Peano, PeanoInt, nlist};
+/// 
+/// const EVENS: usize = count_evens(&nlist![3, 5, 8, 13, 21, 34]);
+/// 
+/// assert_eq!(EVENS, 2);
+/// 
+/// const fn count_evens<L>(list: &NList<u32, L>) -> usize
+/// where
+///     L: PeanoInt
+/// {
+///     nlist::rec_filter_count!{list, |elem| *elem % 2 == 0, |next| count_evens(next)}
+/// }
+/// ```
+#[macro_export]
+macro_rules! rec_filter_count {
+    ($in_list:expr, $($closures:tt)*) => {
+        $crate::__parse_step_and_recurse!{__rec_filter_count ($in_list,) $($closures)*}
+    }
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __rec_filter_count {
+    (
+        $in_list:expr, 
+
+        |$elem:tt: $elem_ty:ty| -> $ret_ty:ty $block:block,
+        |$next:tt: $next_ty:ty| $recurse:expr
+    ) => {
+        $crate::__rec_shared!{
+            $in_list,
+            len_te,
+            || 0,
+            |$elem: $elem_ty, next: _| {
+                let matches: $ret_ty = $block;
+                let matches: $crate::__::bool = matches;
+
+                let $next: $next_ty = next;
+                let count: $crate::__::usize = $recurse;
+
+                matches as $crate::__::usize + count
+            }
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+
+/// Helper for writing const fn equivalents of [`NList::try_map`](crate::NList::try_map)
+/// 
+/// This macro acts like a function with this signature:
+/// 
+/// ```rust
+/// use nlist::{NList, PeanoInt};
+/// 
+/// use nlist::receiver::Receiver;
+/// # use nlist::receiver::HktApply;
+/// 
+/// fn rec_try_map<'a, P, T, L, U, E, M, F>(
+///     list: P, 
+///     mapper: M, 
+///     recurse: F,
+/// ) -> Result<NList<U, L>, E>
+/// where
+///     P: Receiver<'a, NList<T, L>>,
+///     L: PeanoInt,
+///     T: 'a,
+///     U: Copy,
+///     E: Copy,
+///     M: FnOnce(
+///         # /*
+///         ... // element parameter explained below
+///         # */
+///         # HktApply<'a, P::Hkt, T>, 
+///     ) -> Result<U, E>,
+///     F: FnOnce(
+///         # /*
+///         ... // rest-of-list parameter explained below
+///         # */
+///         # HktApply<'a, P::Hkt, NList<T, L::SubOneSat>>,
+///     ) -> Result<NList<U, L::SubOneSat>, E>,
+/// # { nlist::rec_try_map!{list, |a| mapper(a), |next| recurse(next)} }
+/// ```
+/// 
+/// The closures are only called when the list is non-empty (i.e.: when `L != 0`),
+/// `mapper` is passed the first element,
+/// `recurse` is passed the rest of the list if `mapper` returned `Ok`,
+/// it's expected to call the function that this macro is used in.
+/// 
+/// When either closure returns an error, that error is returned,
+/// and the rest of the list isn't mapped.
+/// 
+/// `U` and `E` are required to be `Copy` because `recurse` returns 
+/// a `Result` containing a list of generic length,
+/// and as of Rust 1.83, const fns can't match on such a `Result` by value
+/// (rustc errors with "destructor cannot be evaluated at compile-time",
+/// even if every match arm moves out of it).
+/// This macro copies the values out of a reference to that `Result` instead.
+/// At runtime, [`NList::try_map`](crate::NList::try_map) doesn't have this requirement.
+/// Const fns that are generic over the length can only take the list by reference,
+/// because the unmapped elements of a list taken by value are dropped on error.
+/// 
+#[doc = receiver_params!()]
+/// 
+/// # Example
+/// 
+/// Parsing the numbers of an IPv4 address
+/// 
+/// ```rust
+/// use nlist::{NList, Peano, PeanoInt, nlist};
+/// 
+/// const IP: NList<u8, Peano!(4)> = match parse_all(&nlist!["127", "0", "0", "1"]) {
+///     Ok(x) => x,
+///     Err(_) => panic!("invalid octet"),
+/// };
+/// 
+/// assert_eq!(IP, nlist![127, 0, 0, 1]);
+/// 
+/// assert_eq!(parse_all(&nlist!["255", "256", "0"]), Err(InvalidOctet));
+/// 
+/// const fn parse_all<L>(list: &NList<&str, L>) -> Result<NList<u8, L>, InvalidOctet>
+/// where
+///     L: PeanoInt
+/// {
+///     nlist::rec_try_map!{list, |elem| parse_octet(elem), |next| parse_all(next)}
+/// }
+/// 
+/// #[derive(Debug, PartialEq, Copy, Clone)]
+/// struct InvalidOctet;
+/// 
+/// const fn parse_octet(str: &str) -> Result<u8, InvalidOctet> {
+///     let bytes = str.as_bytes();
+///     if bytes.is_empty() {
+///         return Err(InvalidOctet);
+///     }
+/// 
+///     let mut num: u16 = 0;
+///     let mut i = 0;
+///     while i < bytes.len() {
+///         let digit = bytes[i].wrapping_sub(b'0');
+///         num = num * 10 + digit as u16;
+///         if digit > 9 || num > 255 {
+///             return Err(InvalidOctet);
+///         }
+///         i += 1;
+///     }
+///     Ok(num as u8)
+/// }
+/// ```
+#[macro_export]
+macro_rules! rec_try_map {
+    ($in_list:expr, $($closures:tt)*) => {
+        $crate::__parse_step_and_recurse!{__rec_try_map ($in_list,) $($closures)*}
+    }
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __rec_try_map {
+    (
+        $in_list:expr, 
+
+        |$elem:tt: $elem_ty:ty| -> $ret_ty:ty $block:block,
+        |$next:tt: $next_ty:ty| $recurse:expr
+    ) => {
+        $crate::__rec_shared!{
+            $in_list,
+            len_te,
+            || $crate::__::Ok($crate::NList::nil().coerce_len(len_te.flip())),
+            |$elem: $elem_ty, next: _| {
+                let mapped: $ret_ty = $block;
+
+                let $next: $next_ty = next;
+
+                match mapped {
+                    $crate::__::Ok(elem) => {
+                        let mapped_next = $recurse;
+
+                        // matching on `mapped_next` by value errors in const fns 
+                        // generic over the length, with 
+                        // "destructor cannot be evaluated at compile-time",
+                        // so this copies the values out of a reference to it.
+                        let ret = match mapped_next.as_ref() {
+                            $crate::__::Ok(next) => $crate::__::Ok(
+                                $crate::NList::cons(elem, next.copy()).coerce_len(len_te.flip())
+                            ),
+                            $crate::__::Err(e) => $crate::__::Err(*e),
+                        };
+
+                        // `mapped_next` only contains `Copy` values, forgetting it leaks nothing
+                        $crate::__::forget(mapped_next);
+
+                        ret
+                    }
+                    $crate::__::Err(e) => $crate::__::Err(e),
+                }
+            }
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __rec_shared {
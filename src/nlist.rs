@@ -179,6 +179,144 @@ impl<T, L: PeanoInt> NList<T, L> {
         const { index_list() }.map(f)
     }
 
+    /// Constructs a list by cloning `elem`, moving `elem` into the first element.
+    ///
+    /// This clones `elem` `L - 1` times, and drops it if `L == 0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{NList, nlist, Peano};
+    ///
+    /// let list: NList<String, Peano!(3)> = NList::repeat_clone("hi".to_string());
+    ///
+    /// assert_eq!(list, nlist!["hi", "hi", "hi"].map(String::from));
+    /// ```
+    pub fn repeat_clone(elem: T) -> Self
+    where
+        T: Clone,
+    {
+        match L::PEANO_WIT {
+            PeanoWit::Zero(len_te) => NList::nil_sub(len_te),
+            PeanoWit::PlusOne(len_te) => {
+                let next = match <L::SubOneSat as PeanoInt>::PEANO_WIT {
+                    PeanoWit::Zero(next_len_te) => {
+                        return NList::cons_sub(elem, NList::nil_sub(next_len_te), len_te)
+                    }
+                    PeanoWit::PlusOne(_) => NList::repeat_clone(elem.clone()),
+                };
+
+                NList::cons_sub(elem, next, len_te)
+            }
+        }
+    }
+
+    /// Constructs a list by calling `f` for each element.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{NList, nlist, Peano};
+    ///
+    /// let mut pow = 1;
+    /// let list: NList<u32, Peano!(4)> = NList::repeat_with(|| {
+    ///     pow *= 3;
+    ///     pow
+    /// });
+    ///
+    /// assert_eq!(list, nlist![3, 9, 27, 81]);
+    /// ```
+    pub fn repeat_with<F>(mut f: F) -> Self
+    where
+        F: FnMut() -> T,
+    {
+        Self::from_fn(|_| f())
+    }
+
+    /// Constructs a list where each element is computed from the previous one.
+    ///
+    /// The first element is `first`,
+    /// and every later element is computed by calling `f` with the previous element.
+    ///
+    /// `f` is called `L - 1` times, and `first` is dropped if `L == 0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{NList, nlist, Peano};
+    ///
+    /// let list: NList<u64, Peano!(6)> = NList::successors(1, |x| x * 10);
+    ///
+    /// assert_eq!(list, nlist![1, 10, 100, 1000, 10000, 100000]);
+    /// ```
+    pub fn successors<F>(first: T, mut f: F) -> Self
+    where
+        F: FnMut(&T) -> T,
+    {
+        fn inner<T, L, F>(elem: T, f: &mut F) -> NList<T, L>
+        where
+            L: PeanoInt,
+            F: FnMut(&T) -> T,
+        {
+            match L::PEANO_WIT {
+                PeanoWit::Zero(len_te) => NList::nil_sub(len_te),
+                PeanoWit::PlusOne(len_te) => {
+                    let next = match <L::SubOneSat as PeanoInt>::PEANO_WIT {
+                        PeanoWit::Zero(next_len_te) => NList::nil_sub(next_len_te),
+                        PeanoWit::PlusOne(_) => inner(f(&elem), f),
+                    };
+
+                    NList::cons_sub(elem, next, len_te)
+                }
+            }
+        }
+
+        inner(first, &mut f)
+    }
+
+    /// Fallible version of [`from_fn`](Self::from_fn),
+    /// constructs a list by calling `f` with the index of each element,
+    /// returning the first error that `f` returns.
+    ///
+    /// On error, the elements that were already constructed are dropped,
+    /// and `f` isn't called again.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{NList, nlist, Peano};
+    ///
+    /// let strs = ["3", "5", "8"];
+    /// let ok: Result<NList<u8, Peano!(3)>, _> = NList::try_from_fn(|i| strs[i].parse());
+    /// assert_eq!(ok, Ok(nlist![3, 5, 8]));
+    ///
+    /// let strs = ["3", "hello", "8"];
+    /// let err: Result<NList<u8, Peano!(3)>, _> = NList::try_from_fn(|i| strs[i].parse());
+    /// assert!(err.is_err());
+    /// ```
+    pub fn try_from_fn<F, E>(mut f: F) -> Result<Self, E>
+    where
+        F: FnMut(usize) -> Result<T, E>,
+    {
+        fn inner<T, L, F, E>(index: usize, f: &mut F) -> Result<NList<T, L>, E>
+        where
+            L: PeanoInt,
+            F: FnMut(usize) -> Result<T, E>,
+        {
+            match L::PEANO_WIT {
+                PeanoWit::Zero(len_te) => Ok(NList::nil_sub(len_te)),
+                PeanoWit::PlusOne(len_te) => {
+                    let elem = f(index)?;
+                    let next = inner(index + 1, f)?;
+
+                    Ok(NList::cons_sub(elem, next, len_te))
+                }
+            }
+        }
+
+        inner(0, &mut f)
+    }
+
     /// Constructs an NList from an array
     ///
     /// # Example
@@ -221,14 +359,14 @@ impl<T, L: PeanoInt> NList<T, L> {
 
     /// Alternate constructor for [`NList::nil`],
     /// for constructing an empty `NList` in a generic context.
-    const fn nil_sub(len_te: TypeEq<L, Zero>) -> Self {
+    pub(crate) const fn nil_sub(len_te: TypeEq<L, Zero>) -> Self {
         NList::nil().coerce_len(len_te.flip())
     }
 
     /// Alternate constructor for [`NList::cons`],
     /// for constructing a `NList` out of the tail of another `NList`
     /// in a generic context.
-    const fn cons_sub<L2: PeanoInt>(
+    pub(crate) const fn cons_sub<L2: PeanoInt>(
         val: T,
         next: NList<T, L2>,
         len_te: TypeEq<L, PlusOne<L2>>,
@@ -793,8 +931,30 @@ impl<T, L: PeanoInt> NList<T, L> {
 }
 
 mod flatten;
-mod splitting;
+pub(crate) mod splitting;
 mod iteratorlike;
+mod remainder;
+mod ring_buffer;
+mod bounded;
+mod builder;
+mod fin;
+mod perm;
+mod zipper;
+mod bit_nlist;
+mod int_bytes;
+mod const_visitor;
+
+pub use self::remainder::Remainder;
+pub use self::ring_buffer::RingBuffer;
+pub use self::bounded::BoundedNList;
+pub use self::builder::NListBuilder;
+pub use self::fin::Fin;
+pub use self::perm::Perm;
+pub use self::zipper::Zipper;
+pub use self::bit_nlist::BitNList;
+
+pub(crate) use self::bit_nlist::BitWords;
+pub use self::const_visitor::{ConstFolder, ConstMapper, ConstVisitor};
 
 impl<T, L: PeanoInt> NList<T, L> {
     /// Converts this list into a `Vec`
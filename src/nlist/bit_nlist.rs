@@ -0,0 +1,576 @@
+use core::{
+    fmt::{self, Debug},
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not},
+};
+
+use crate::{
+    boolean::Bool,
+    peano::{FromUsize, IfZeroPI, PeanoInt, PeanoWit, PlusOne, SubOneSat, Zero},
+};
+
+use super::{Fin, NList};
+
+
+const WORD_BITS: usize = 64;
+
+// The amount of words that a `BitNList<L>` is stored in
+type Words<L> = <L as BitWords>::Words;
+
+// Computes how many 64-bit words are needed to store `Self` bits.
+//
+// This is a supertrait of `PeanoInt` so that `Words<L>` can be used with any `L: PeanoInt`,
+// it's not nameable outside this crate.
+pub trait BitWords {
+    // The amount of 64-bit words needed to store `Self` bits
+    type Words: PeanoInt;
+
+    // The amount of unused bits in the last of the `Words` words
+    type Room: PeanoInt;
+}
+
+impl BitWords for Zero {
+    type Words = Zero;
+
+    type Room = Zero;
+}
+
+impl<T: PeanoInt> BitWords for PlusOne<T> {
+    type Words = IfZeroPI<T::Room, PlusOne<T::Words>, T::Words>;
+
+    type Room = IfZeroPI<T::Room, FromUsize<63>, SubOneSat<T::Room>>;
+}
+
+
+/// A list of `L` bits, packed into 64-bit words.
+///
+/// This is a more compact alternative to `NList<bool, L>`,
+/// which uses one byte per element.
+///
+/// # Example
+///
+/// Flags of a hardware register
+///
+/// ```rust
+/// use nlist::{BitNList, Peano, nlist};
+///
+/// type Flags = BitNList<Peano!(4)>;
+///
+/// type Enabled = Peano!(0);
+/// type Interrupt = Peano!(2);
+///
+/// const STATUS: Flags = {
+///     let mut flags = Flags::zeros();
+///     flags.set::<Enabled>(true);
+///     flags.set::<Interrupt>(true);
+///     flags
+/// };
+///
+/// assert!(STATUS.get::<Enabled>());
+/// assert!(STATUS.get::<Interrupt>());
+/// assert_eq!(STATUS.count_ones(), 2);
+///
+/// let mask = Flags::from_nlist(nlist![true, true, false, false]);
+///
+/// assert_eq!((STATUS & mask).to_nlist(), nlist![true, false, false, false]);
+/// assert_eq!((!STATUS).to_nlist(), nlist![false, true, false, true]);
+///
+/// ```
+pub struct BitNList<L: PeanoInt> {
+    // bits past `L::USIZE` are always zero
+    words: NList<u64, Words<L>>,
+    _len: PhantomData<L>,
+}
+
+impl<L: PeanoInt> BitNList<L> {
+    /// Constructs a `BitNList` with all bits unset.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{BitNList, Peano};
+    ///
+    /// const ZEROS: BitNList<Peano!(3)> = BitNList::zeros();
+    ///
+    /// assert_eq!(ZEROS.count_ones(), 0);
+    ///
+    /// ```
+    pub const fn zeros() -> Self {
+        Self { words: NList::repeat_copy(0), _len: PhantomData }
+    }
+
+    /// Constructs a `BitNList` with all bits set.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{BitNList, Peano};
+    ///
+    /// const ONES: BitNList<Peano!(3)> = BitNList::ones();
+    ///
+    /// assert_eq!(ONES.count_ones(), 3);
+    ///
+    /// ```
+    pub const fn ones() -> Self {
+        let zeros = Self::zeros();
+        let ones = zeros.complement();
+
+        // `zeros` only holds integers, forgetting it avoids
+        // the "destructor cannot be evaluated at compile-time" error
+        core::mem::forget(zeros);
+
+        ones
+    }
+
+    /// Constructs a `BitNList` from a list of bools.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{BitNList, Peano, nlist};
+    ///
+    /// const BITS: BitNList<Peano!(3)> = BitNList::from_nlist(nlist![true, false, true]);
+    ///
+    /// assert_eq!(BITS.to_nlist(), nlist![true, false, true]);
+    ///
+    /// ```
+    pub const fn from_nlist(list: NList<bool, L>) -> Self {
+        const fn inner<L2: PeanoInt, W: PeanoInt>(
+            list: &NList<bool, L2>,
+            words: &mut NList<u64, W>,
+            index: usize,
+        ) {
+            if let PeanoWit::PlusOne(len_te) = L2::PEANO_WIT {
+                let (elem, next) = list.as_coerce_len(len_te).split_head();
+                if *elem {
+                    *word_mut(words, index) |= 1 << (index % WORD_BITS);
+                }
+                inner(next, words, index + 1);
+            }
+        }
+
+        let mut this = Self::zeros();
+        inner(&list, &mut this.words, 0);
+        list.assert_copy_drop();
+        this
+    }
+
+    /// Converts this `BitNList` into a list of bools.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{BitNList, Peano, nlist};
+    ///
+    /// let mut bits = BitNList::<Peano!(3)>::zeros();
+    /// bits.set::<Peano!(1)>(true);
+    ///
+    /// assert_eq!(bits.to_nlist(), nlist![false, true, false]);
+    ///
+    /// ```
+    pub const fn to_nlist(&self) -> NList<bool, L> {
+        const fn inner<L, L2>(this: &BitNList<L>, index: usize) -> NList<bool, L2>
+        where
+            L: PeanoInt,
+            L2: PeanoInt,
+        {
+            match L2::PEANO_WIT {
+                PeanoWit::Zero(len_te) => NList::nil_sub(len_te),
+                PeanoWit::PlusOne(len_te) => {
+                    NList::cons_sub(this.bit(index), inner(this, index + 1), len_te)
+                }
+            }
+        }
+
+        inner(self, 0)
+    }
+
+    /// Returns the amount of bits, which is always `L::USIZE`.
+    pub const fn len(&self) -> usize {
+        L::USIZE
+    }
+
+    /// Whether this `BitNList` holds no bits, which is true when `L::USIZE == 0`.
+    pub const fn is_empty(&self) -> bool {
+        L::USIZE == 0
+    }
+
+    /// Returns the bit at the `I` index.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{BitNList, Peano, nlist};
+    ///
+    /// let bits = BitNList::from_nlist(nlist![false, true, true]);
+    ///
+    /// assert!(!bits.get::<Peano!(0)>());
+    /// assert!(bits.get::<Peano!(1)>());
+    /// assert!(bits.get::<Peano!(2)>());
+    ///
+    /// ```
+    pub const fn get<I>(&self) -> bool
+    where
+        I: PeanoInt<IsLt<L> = Bool<true>>,
+    {
+        self.bit(I::USIZE)
+    }
+
+    /// Sets the bit at the `I` index to `value`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{BitNList, Peano, nlist};
+    ///
+    /// let mut bits = BitNList::from_nlist(nlist![false, true, true]);
+    ///
+    /// bits.set::<Peano!(0)>(true);
+    /// bits.set::<Peano!(2)>(false);
+    ///
+    /// assert_eq!(bits.to_nlist(), nlist![true, true, false]);
+    ///
+    /// ```
+    pub const fn set<I>(&mut self, value: bool)
+    where
+        I: PeanoInt<IsLt<L> = Bool<true>>,
+    {
+        self.set_bit(I::USIZE, value)
+    }
+
+    /// Returns the bit at the `index` index.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{BitNList, Fin, Peano, nlist};
+    ///
+    /// let bits = BitNList::from_nlist(nlist![false, true, true]);
+    ///
+    /// let found = Fin::<Peano!(3)>::all().map(|i| bits.get_fin(i));
+    /// assert_eq!(found, nlist![false, true, true]);
+    ///
+    /// ```
+    pub const fn get_fin(&self, index: Fin<L>) -> bool {
+        self.bit(index.get())
+    }
+
+    /// Sets the bit at the `index` index to `value`.
+    pub const fn set_fin(&mut self, index: Fin<L>, value: bool) {
+        self.set_bit(index.get(), value)
+    }
+
+    /// Returns the amount of set bits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{BitNList, nlist};
+    ///
+    /// let bits = BitNList::from_nlist(nlist![true, false, true, true]);
+    ///
+    /// assert_eq!(bits.count_ones(), 3);
+    /// assert_eq!(bits.count_zeros(), 1);
+    ///
+    /// ```
+    pub const fn count_ones(&self) -> usize {
+        const fn inner<W: PeanoInt>(words: &NList<u64, W>) -> usize {
+            match W::PEANO_WIT {
+                PeanoWit::Zero(_) => 0,
+                PeanoWit::PlusOne(len_te) => {
+                    let (word, next) = words.as_coerce_len(len_te).split_head();
+                    word.count_ones() as usize + inner(next)
+                }
+            }
+        }
+
+        inner(&self.words)
+    }
+
+    /// Returns the amount of unset bits.
+    pub const fn count_zeros(&self) -> usize {
+        L::USIZE - self.count_ones()
+    }
+
+    /// Returns an iterator over the bits, from the first to the last.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{BitNList, nlist};
+    ///
+    /// let bits = BitNList::from_nlist(nlist![true, false, true]);
+    ///
+    /// assert_eq!(bits.iter().collect::<Vec<_>>(), [true, false, true]);
+    ///
+    /// ```
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = bool> + ExactSizeIterator + '_ {
+        (0..L::USIZE).map(move |i| self.bit(i))
+    }
+
+    /// Const equivalent of the `&` operator.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{BitNList, nlist};
+    ///
+    /// let lhs = BitNList::from_nlist(nlist![false, false, true, true]);
+    /// let rhs = BitNList::from_nlist(nlist![false, true, false, true]);
+    ///
+    /// assert_eq!(lhs.and(&rhs).to_nlist(), nlist![false, false, false, true]);
+    ///
+    /// ```
+    pub const fn and(&self, rhs: &Self) -> Self {
+        Self::zip_words(self, rhs, BitOp::And)
+    }
+
+    /// Const equivalent of the `|` operator.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{BitNList, nlist};
+    ///
+    /// let lhs = BitNList::from_nlist(nlist![false, false, true, true]);
+    /// let rhs = BitNList::from_nlist(nlist![false, true, false, true]);
+    ///
+    /// assert_eq!(lhs.or(&rhs).to_nlist(), nlist![false, true, true, true]);
+    ///
+    /// ```
+    pub const fn or(&self, rhs: &Self) -> Self {
+        Self::zip_words(self, rhs, BitOp::Or)
+    }
+
+    /// Const equivalent of the `^` operator.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{BitNList, nlist};
+    ///
+    /// let lhs = BitNList::from_nlist(nlist![false, false, true, true]);
+    /// let rhs = BitNList::from_nlist(nlist![false, true, false, true]);
+    ///
+    /// assert_eq!(lhs.xor(&rhs).to_nlist(), nlist![false, true, true, false]);
+    ///
+    /// ```
+    pub const fn xor(&self, rhs: &Self) -> Self {
+        Self::zip_words(self, rhs, BitOp::Xor)
+    }
+
+    /// Const equivalent of the `!` operator.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{BitNList, nlist};
+    ///
+    /// let bits = BitNList::from_nlist(nlist![false, true, true]);
+    ///
+    /// assert_eq!(bits.complement().to_nlist(), nlist![true, false, false]);
+    ///
+    /// ```
+    pub const fn complement(&self) -> Self {
+        Self::zip_words(self, self, BitOp::Not)
+    }
+
+    const fn zip_words(lhs: &Self, rhs: &Self, op: BitOp) -> Self {
+        const fn inner<W: PeanoInt>(
+            lhs: &NList<u64, W>,
+            rhs: &NList<u64, W>,
+            op: BitOp,
+            bits_left: usize,
+        ) -> NList<u64, W> {
+            match W::PEANO_WIT {
+                PeanoWit::Zero(len_te) => NList::nil_sub(len_te),
+                PeanoWit::PlusOne(len_te) => {
+                    let (lword, lnext) = lhs.as_coerce_len(len_te).split_head();
+                    let (rword, rnext) = rhs.as_coerce_len(len_te).split_head();
+
+                    let mask = if bits_left >= WORD_BITS {
+                        u64::MAX
+                    } else {
+                        (1 << bits_left) - 1
+                    };
+
+                    let word = match op {
+                        BitOp::And => *lword & *rword,
+                        BitOp::Or => *lword | *rword,
+                        BitOp::Xor => *lword ^ *rword,
+                        BitOp::Not => !*lword,
+                    };
+
+                    let next = inner(lnext, rnext, op, bits_left.saturating_sub(WORD_BITS));
+
+                    NList::cons_sub(word & mask, next, len_te)
+                }
+            }
+        }
+
+        Self {
+            words: inner(&lhs.words, &rhs.words, op, L::USIZE),
+            _len: PhantomData,
+        }
+    }
+
+    const fn bit(&self, index: usize) -> bool {
+        if index >= L::USIZE {
+            panic!("index out of bounds");
+        }
+
+        match self.words.get(index / WORD_BITS) {
+            Some(word) => (*word >> (index % WORD_BITS)) & 1 == 1,
+            None => panic!("index out of bounds"),
+        }
+    }
+
+    const fn set_bit(&mut self, index: usize, value: bool) {
+        if index >= L::USIZE {
+            panic!("index out of bounds");
+        }
+
+        let word = word_mut(&mut self.words, index);
+        let bit = 1 << (index % WORD_BITS);
+
+        if value {
+            *word |= bit;
+        } else {
+            *word &= !bit;
+        }
+    }
+}
+
+// Returns the word that stores the bit at the `index` index
+const fn word_mut<W: PeanoInt>(words: &mut NList<u64, W>, index: usize) -> &mut u64 {
+    match words.get_mut(index / WORD_BITS) {
+        Some(word) => word,
+        None => panic!("index out of bounds"),
+    }
+}
+
+#[derive(Copy, Clone)]
+enum BitOp {
+    And,
+    Or,
+    Xor,
+    Not,
+}
+
+impl<L: PeanoInt> From<NList<bool, L>> for BitNList<L> {
+    fn from(list: NList<bool, L>) -> Self {
+        Self::from_nlist(list)
+    }
+}
+
+impl<L: PeanoInt> From<BitNList<L>> for NList<bool, L> {
+    fn from(bits: BitNList<L>) -> Self {
+        bits.to_nlist()
+    }
+}
+
+// `BitNList` can't be `Copy` (because `NList` isn't),
+// so the binary operators are also implemented for references,
+// to allow using the operands afterwards.
+macro_rules! impl_bit_ops {
+    ($(($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $op_fn:ident))*) => {$(
+        impl<L: PeanoInt> $trait for BitNList<L> {
+            type Output = Self;
+
+            fn $method(self, rhs: Self) -> Self {
+                self.$op_fn(&rhs)
+            }
+        }
+
+        impl<L: PeanoInt> $trait<&BitNList<L>> for BitNList<L> {
+            type Output = Self;
+
+            fn $method(self, rhs: &Self) -> Self {
+                self.$op_fn(rhs)
+            }
+        }
+
+        impl<L: PeanoInt> $trait<BitNList<L>> for &BitNList<L> {
+            type Output = BitNList<L>;
+
+            fn $method(self, rhs: BitNList<L>) -> BitNList<L> {
+                self.$op_fn(&rhs)
+            }
+        }
+
+        impl<L: PeanoInt> $trait<&BitNList<L>> for &BitNList<L> {
+            type Output = BitNList<L>;
+
+            fn $method(self, rhs: &BitNList<L>) -> BitNList<L> {
+                self.$op_fn(rhs)
+            }
+        }
+
+        impl<L: PeanoInt> $assign_trait for BitNList<L> {
+            fn $assign_method(&mut self, rhs: Self) {
+                *self = self.$op_fn(&rhs);
+            }
+        }
+
+        impl<L: PeanoInt> $assign_trait<&BitNList<L>> for BitNList<L> {
+            fn $assign_method(&mut self, rhs: &Self) {
+                *self = self.$op_fn(rhs);
+            }
+        }
+    )*};
+}
+
+impl_bit_ops! {
+    (BitAnd, bitand, BitAndAssign, bitand_assign, and)
+    (BitOr, bitor, BitOrAssign, bitor_assign, or)
+    (BitXor, bitxor, BitXorAssign, bitxor_assign, xor)
+}
+
+impl<L: PeanoInt> Not for BitNList<L> {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        self.complement()
+    }
+}
+
+impl<L: PeanoInt> Not for &BitNList<L> {
+    type Output = BitNList<L>;
+
+    fn not(self) -> BitNList<L> {
+        self.complement()
+    }
+}
+
+impl<L: PeanoInt> Default for BitNList<L> {
+    fn default() -> Self {
+        Self::zeros()
+    }
+}
+
+impl<L: PeanoInt> Clone for BitNList<L> {
+    fn clone(&self) -> Self {
+        Self { words: self.words.copy(), _len: PhantomData }
+    }
+}
+
+impl<L: PeanoInt> Debug for BitNList<L> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<L: PeanoInt> Hash for BitNList<L> {
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        self.words.hash(hasher)
+    }
+}
+
+impl<L: PeanoInt> PartialEq for BitNList<L> {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.words == rhs.words
+    }
+}
+
+impl<L: PeanoInt> Eq for BitNList<L> {}
@@ -0,0 +1,641 @@
+use core::fmt::{self, Debug};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use konst::destructure;
+
+use typewit::TypeEq;
+
+use crate::{
+    boolean::Bool,
+    peano::{PeanoInt, PeanoWit},
+};
+
+use super::NList;
+
+
+/// A list with a runtime length of at most `Cap` elements, stored inline.
+///
+/// This is returned by the [`NList`] methods that can't preserve the length of the list,
+/// like [`filter`](NList::filter) and [`take_while`](NList::take_while).
+///
+/// # Const usage
+///
+/// Because dropping a `BoundedNList` of generic capacity is not possible in const,
+/// const code that is generic over the capacity must consume the elements with
+/// [`pop`](Self::pop) and then call [`assert_empty_drop`](Self::assert_empty_drop),
+/// or convert it with [`try_into_nlist`](Self::try_into_nlist).
+///
+/// # Example
+///
+/// ```rust
+/// use nlist::{BoundedNList, NList, Peano, nlist};
+///
+/// let evens: BoundedNList<u32, Peano!(5)> = nlist![3, 4, 8, 13, 16].filter(|x| *x % 2 == 0);
+///
+/// assert_eq!(evens.len(), 3);
+/// assert_eq!(evens.capacity(), 5);
+///
+/// let list: NList<u32, Peano!(3)> = evens.try_into_nlist().unwrap();
+/// assert_eq!(list, nlist![4, 8, 16]);
+///
+/// ```
+///
+/// ### Const construction
+///
+/// ```rust
+/// use nlist::{BoundedNList, Peano, nlist};
+///
+/// const LIST: BoundedNList<u32, Peano!(5)> = {
+///     let mut list = BoundedNList::from_nlist(nlist![3, 5]);
+///     list.push(13);
+///     list.insert(2, 8);
+///     list
+/// };
+///
+/// assert_eq!(LIST.len(), 4);
+/// assert_eq!(LIST.into_vec(), vec![3, 5, 8, 13]);
+///
+/// ```
+pub struct BoundedNList<T, Cap: PeanoInt> {
+    // the first `len` slots are `Some`, the rest are `None`
+    list: NList<Option<T>, Cap>,
+    len: usize,
+}
+
+impl<T, Cap: PeanoInt> BoundedNList<T, Cap> {
+    /// Constructs an empty `BoundedNList`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{BoundedNList, Peano};
+    ///
+    /// const EMPTY: BoundedNList<u8, Peano!(4)> = BoundedNList::new();
+    ///
+    /// assert!(EMPTY.is_empty());
+    /// assert_eq!(EMPTY.capacity(), 4);
+    ///
+    /// ```
+    pub const fn new() -> Self {
+        Self { list: none_list(), len: 0 }
+    }
+
+    /// Constructs a `BoundedNList` with all the elements of `list`,
+    /// requires `L <= Cap`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{BoundedNList, Peano, nlist};
+    ///
+    /// const LIST: BoundedNList<u8, Peano!(4)> = BoundedNList::from_nlist(nlist![3, 5]);
+    ///
+    /// assert_eq!(LIST.len(), 2);
+    /// assert_eq!(LIST.capacity(), 4);
+    ///
+    /// ```
+    pub const fn from_nlist<L>(list: NList<T, L>) -> Self
+    where
+        L: PeanoInt<IsLe<Cap> = Bool<true>>,
+    {
+        Self::from_nlist_alt(list, TypeEq::NEW)
+    }
+
+    /// Alternate version of [`from_nlist`](Self::from_nlist)
+    /// which takes a proof of `L <= Cap` as a parameter.
+    pub const fn from_nlist_alt<L>(
+        list: NList<T, L>,
+        _le_te: TypeEq<L::IsLe<Cap>, Bool<true>>,
+    ) -> Self
+    where
+        L: PeanoInt,
+    {
+        let mut this = Self::new();
+        this.extend_from_nlist(list);
+        this
+    }
+
+    /// Constructs a `BoundedNList` from the `Some` elements of `list`, preserving their order.
+    pub(crate) fn from_sparse(list: NList<Option<T>, Cap>) -> Self {
+        let mut this = Self::new();
+
+        list.for_each(|_, elem| if let Some(elem) = elem {
+            this.push(elem);
+        });
+
+        this
+    }
+
+    /// Returns the maximum amount of elements that this `BoundedNList` can hold,
+    /// which is always `Cap::USIZE`.
+    pub const fn capacity(&self) -> usize {
+        Cap::USIZE
+    }
+
+    /// Returns the amount of elements in this `BoundedNList`,
+    /// which is always less than or equal to `Cap::USIZE`.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether this `BoundedNList` holds no elements.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Whether this `BoundedNList` can't hold any more elements.
+    pub const fn is_full(&self) -> bool {
+        self.len == Cap::USIZE
+    }
+
+    /// Returns a reference to the element at `index`,
+    /// returns `None` if `index >= self.len()`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::nlist;
+    ///
+    /// let list = nlist![3, 5, 8, 13].filter(|x| *x > 4);
+    ///
+    /// assert_eq!(list.get(0), Some(&5));
+    /// assert_eq!(list.get(2), Some(&13));
+    /// assert_eq!(list.get(3), None);
+    ///
+    /// ```
+    pub const fn get(&self, index: usize) -> Option<&T> {
+        match self.list.get(index) {
+            Some(elem) => elem.as_ref(),
+            None => None,
+        }
+    }
+
+    /// Returns a reference to the last element,
+    /// returns `None` if this `BoundedNList` is empty.
+    pub const fn last(&self) -> Option<&T> {
+        match self.len.checked_sub(1) {
+            Some(index) => self.get(index),
+            None => None,
+        }
+    }
+
+    /// Returns a mutable reference to the element at `index`,
+    /// returns `None` if `index >= self.len()`.
+    pub const fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        match self.list.get_mut(index) {
+            Some(elem) => elem.as_mut(),
+            None => None,
+        }
+    }
+
+    /// Appends `elem` to the end of this `BoundedNList`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this `BoundedNList` is full.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{BoundedNList, Peano};
+    ///
+    /// let mut list = BoundedNList::<u8, Peano!(2)>::new();
+    ///
+    /// list.push(3);
+    /// list.push(5);
+    ///
+    /// assert_eq!(list.into_vec(), vec![3, 5]);
+    ///
+    /// ```
+    pub const fn push(&mut self, elem: T) {
+        if self.is_full() {
+            panic!("cannot push into a full `BoundedNList`")
+        }
+
+        self.put_slot(self.len, Some(elem));
+        self.len += 1;
+    }
+
+    /// Appends `elem` to the end of this `BoundedNList`,
+    /// returning `Err(elem)` if it's full.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{BoundedNList, Peano};
+    ///
+    /// let mut list = BoundedNList::<u8, Peano!(1)>::new();
+    ///
+    /// assert_eq!(list.try_push(3), Ok(()));
+    /// assert_eq!(list.try_push(5), Err(5));
+    ///
+    /// assert_eq!(list.into_vec(), vec![3]);
+    ///
+    /// ```
+    pub const fn try_push(&mut self, elem: T) -> Result<(), T> {
+        if self.is_full() {
+            Err(elem)
+        } else {
+            self.push(elem);
+            Ok(())
+        }
+    }
+
+    /// Removes and returns the last element,
+    /// returns `None` if this `BoundedNList` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::nlist;
+    ///
+    /// let mut list = nlist![3, 5].filter(|_| true);
+    ///
+    /// assert_eq!(list.pop(), Some(5));
+    /// assert_eq!(list.pop(), Some(3));
+    /// assert_eq!(list.pop(), None);
+    ///
+    /// ```
+    pub const fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        self.len -= 1;
+        self.slot_mut(self.len).take()
+    }
+
+    /// Inserts `elem` at `index`, shifting all the elements after it to the right.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > self.len()` or if this `BoundedNList` is full.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{BoundedNList, Peano, nlist};
+    ///
+    /// let mut list: BoundedNList<u8, Peano!(4)> = BoundedNList::from_nlist(nlist![3, 8]);
+    ///
+    /// list.insert(1, 5);
+    /// list.insert(3, 13);
+    ///
+    /// assert_eq!(list.into_vec(), vec![3, 5, 8, 13]);
+    ///
+    /// ```
+    pub const fn insert(&mut self, index: usize, elem: T) {
+        if index > self.len {
+            panic!("insertion index is out of bounds")
+        } else if self.is_full() {
+            panic!("cannot insert into a full `BoundedNList`")
+        }
+
+        // puts `carry` in the slot at `index`, 
+        // shifting the elements from `index` onwards one slot to the right
+        const fn inner<T, L>(list: &mut NList<Option<T>, L>, index: usize, carry: Option<T>)
+        where
+            L: PeanoInt,
+        {
+            match L::PEANO_WIT {
+                PeanoWit::Zero(_) => {
+                    // there's always a vacant slot to shift into, so `carry` is `None` 
+                    core::mem::forget(carry)
+                }
+                PeanoWit::PlusOne(len_te) => {
+                    let (slot, next) = list.as_mut_coerce_len(len_te).split_head_mut();
+
+                    if index != 0 {
+                        inner(next, index - 1, carry)
+                    } else {
+                        let displaced = core::mem::replace(slot, carry);
+
+                        // stops after filling the first vacant slot
+                        if displaced.is_some() {
+                            inner(next, 0, displaced)
+                        } else {
+                            core::mem::forget(displaced)
+                        }
+                    }
+                }
+            }
+        }
+
+        inner(&mut self.list, index, Some(elem));
+        self.len += 1;
+    }
+
+    /// Removes and returns the element at `index`,
+    /// shifting all the elements after it to the left.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= self.len()`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::nlist;
+    ///
+    /// let mut list = nlist![3, 5, 8, 13].filter(|_| true);
+    ///
+    /// assert_eq!(list.remove(1), 5);
+    /// assert_eq!(list.remove(2), 13);
+    ///
+    /// assert_eq!(list.into_vec(), vec![3, 8]);
+    ///
+    /// ```
+    pub const fn remove(&mut self, index: usize) -> T {
+        if index >= self.len {
+            panic!("removal index is out of bounds")
+        }
+
+        // takes the element at `index`, 
+        // shifting the elements after it one slot to the left
+        const fn inner<T, L>(list: &mut NList<Option<T>, L>, index: usize) -> Option<T>
+        where
+            L: PeanoInt,
+        {
+            match L::PEANO_WIT {
+                PeanoWit::Zero(_) => None,
+                PeanoWit::PlusOne(len_te) => {
+                    let (slot, next) = list.as_mut_coerce_len(len_te).split_head_mut();
+
+                    if index != 0 {
+                        return inner(next, index - 1);
+                    }
+
+                    let elem = slot.take();
+
+                    // stops at the first vacant slot
+                    if elem.is_some() {
+                        let prev = core::mem::replace(slot, inner(next, 0));
+
+                        // `prev` is the `None` that `take` left, forgetting it avoids
+                        // the "destructor cannot be evaluated at compile-time" error
+                        core::mem::forget(prev);
+                    }
+
+                    elem
+                }
+            }
+        }
+
+        let elem = inner(&mut self.list, index).unwrap();
+
+        self.len -= 1;
+        elem
+    }
+
+    /// Shortens this `BoundedNList` to `len` elements, dropping the rest.
+    ///
+    /// This does nothing if `len >= self.len()`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::nlist;
+    ///
+    /// let mut list = nlist![3, 5, 8, 13].filter(|_| true);
+    ///
+    /// list.truncate(2);
+    ///
+    /// assert_eq!(list.into_vec(), vec![3, 5]);
+    ///
+    /// ```
+    pub fn truncate(&mut self, len: usize) {
+        while self.len > len {
+            drop(self.pop());
+        }
+    }
+
+    /// Appends all the elements of `list` to the end of this `BoundedNList`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self.len() + L2::USIZE > Cap::USIZE`,
+    /// without appending any elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{BoundedNList, Peano, nlist};
+    ///
+    /// let mut list = BoundedNList::<u8, Peano!(5)>::new();
+    ///
+    /// list.extend_from_nlist(nlist![3, 5]);
+    /// list.extend_from_nlist(nlist![8, 13, 21]);
+    ///
+    /// assert_eq!(list.into_vec(), vec![3, 5, 8, 13, 21]);
+    ///
+    /// ```
+    pub const fn extend_from_nlist<L2: PeanoInt>(&mut self, list: NList<T, L2>) {
+        const fn inner<T, Cap, L2>(this: &mut BoundedNList<T, Cap>, list: NList<T, L2>)
+        where
+            Cap: PeanoInt,
+            L2: PeanoInt,
+        {
+            match L2::PEANO_WIT {
+                PeanoWit::Zero(len_te) => {
+                    // works around "destructor cannot be evaluated at compile-time" error
+                    _ = list.coerce_len(len_te);
+                }
+                PeanoWit::PlusOne(len_te) => {
+                    destructure!{(elem, next) = list.coerce_len(len_te).into_split_head()}
+
+                    this.push(elem);
+                    inner(this, next)
+                }
+            }
+        }
+
+        if Cap::USIZE - self.len < L2::USIZE {
+            panic!("the list doesn't fit in the remaining capacity of the `BoundedNList`")
+        }
+
+        inner(self, list)
+    }
+
+    /// Helper method for dropping a `BoundedNList` that holds no elements in a const context.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this `BoundedNList` holds any elements.
+    pub const fn assert_empty_drop(self) {
+        if !self.is_empty() {
+            panic!("expected the `BoundedNList` to hold no elements");
+        }
+
+        core::mem::forget(self)
+    }
+
+    /// Converts this `BoundedNList` into an `NList` of length `L2`,
+    /// returning `Err(self)` if `self.len() != L2::USIZE`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{NList, Peano, nlist};
+    ///
+    /// let list = nlist![3, 5, 8, 13].filter(|x| *x % 2 == 1);
+    ///
+    /// let list = list.try_into_nlist::<Peano!(2)>().unwrap_err();
+    ///
+    /// assert_eq!(list.try_into_nlist::<Peano!(3)>().unwrap(), nlist![3, 5, 13]);
+    ///
+    /// ```
+    pub const fn try_into_nlist<L2: PeanoInt>(mut self) -> Result<NList<T, L2>, Self> {
+        const fn inner<T, L, L2>(list: &mut NList<Option<T>, L>, index: usize) -> NList<T, L2>
+        where
+            L: PeanoInt,
+            L2: PeanoInt,
+        {
+            match L2::PEANO_WIT {
+                PeanoWit::Zero(len_te) => NList::nil_sub(len_te),
+                PeanoWit::PlusOne(len_te) => {
+                    let Some(slot) = list.get_mut(index) else {
+                        panic!("BUG: the length is larger than the capacity")
+                    };
+                    let elem = slot.take().unwrap();
+
+                    NList::cons_sub(elem, inner(list, index + 1), len_te)
+                }
+            }
+        }
+
+        if self.len != L2::USIZE {
+            return Err(self);
+        }
+
+        let list = inner(&mut self.list, 0);
+
+        // all the elements were moved out of `self`
+        core::mem::forget(self);
+
+        Ok(list)
+    }
+
+    /// Converts this `BoundedNList` into a `Vec`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::nlist;
+    ///
+    /// let list = nlist![3, 5, 8, 13].filter(|x| *x > 4);
+    ///
+    /// assert_eq!(list.into_vec(), vec![5, 8, 13]);
+    ///
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn into_vec(self) -> Vec<T> {
+        self.list.into_vec().into_iter().flatten().collect()
+    }
+
+    const fn slot_mut(&mut self, index: usize) -> &mut Option<T> {
+        match self.list.get_mut(index) {
+            Some(slot) => slot,
+            None => panic!("index out of bounds"),
+        }
+    }
+
+    // writes into a vacant slot
+    const fn put_slot(&mut self, index: usize, elem: Option<T>) {
+        let prev = core::mem::replace(self.slot_mut(index), elem);
+
+        // `prev` is always `None`, forgetting it avoids
+        // the "destructor cannot be evaluated at compile-time" error
+        core::mem::forget(prev);
+    }
+}
+
+/// Constructs a list of vacant slots
+pub(super) const fn none_list<T, L: PeanoInt>() -> NList<Option<T>, L> {
+    match L::PEANO_WIT {
+        PeanoWit::Zero(len_te) => NList::nil_sub(len_te),
+        PeanoWit::PlusOne(len_te) => NList::cons_sub(None, none_list(), len_te),
+    }
+}
+
+impl<T, L, Cap> From<NList<T, L>> for BoundedNList<T, Cap>
+where
+    L: PeanoInt<IsLe<Cap> = Bool<true>>,
+    Cap: PeanoInt,
+{
+    fn from(list: NList<T, L>) -> Self {
+        Self::from_nlist(list)
+    }
+}
+
+impl<T, Cap: PeanoInt> Default for BoundedNList<T, Cap> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, Cap> Clone for BoundedNList<T, Cap>
+where
+    T: Clone,
+    Cap: PeanoInt,
+{
+    fn clone(&self) -> Self {
+        Self { list: self.list.clone(), len: self.len }
+    }
+}
+
+impl<T, Cap> Debug for BoundedNList<T, Cap>
+where
+    T: Debug,
+    Cap: PeanoInt,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut fmt = fmt.debug_list();
+
+        self.list.each_ref().for_each(|_, elem| {
+            if let Some(elem) = elem {
+                _ = fmt.entry(elem);
+            }
+        });
+
+        fmt.finish()
+    }
+}
+
+impl<T, U, Cap, Cap2> PartialEq<BoundedNList<U, Cap2>> for BoundedNList<T, Cap>
+where
+    T: PartialEq<U>,
+    Cap: PeanoInt,
+    Cap2: PeanoInt,
+{
+    fn eq(&self, rhs: &BoundedNList<U, Cap2>) -> bool {
+        self.len == rhs.len && slots_eq(&self.list, &rhs.list)
+    }
+}
+
+// compares the occupied slots of both lists pairwise,
+// requires both lists to have the same amount of occupied slots
+fn slots_eq<T, U, L, L2>(lhs: &NList<Option<T>, L>, rhs: &NList<Option<U>, L2>) -> bool
+where
+    T: PartialEq<U>,
+    L: PeanoInt,
+    L2: PeanoInt,
+{
+    let (PeanoWit::PlusOne(lhs_te), PeanoWit::PlusOne(rhs_te)) = (L::PEANO_WIT, L2::PEANO_WIT)
+    else { return true };
+
+    let (lhs_elem, lhs_next) = lhs.as_coerce_len(lhs_te).split_head();
+    let (rhs_elem, rhs_next) = rhs.as_coerce_len(rhs_te).split_head();
+
+    match (lhs_elem, rhs_elem) {
+        (Some(l), Some(r)) => l == r && slots_eq(lhs_next, rhs_next),
+        _ => true,
+    }
+}
+
+impl<T, Cap> Eq for BoundedNList<T, Cap>
+where
+    T: Eq,
+    Cap: PeanoInt,
+{}
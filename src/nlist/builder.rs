@@ -0,0 +1,164 @@
+use konst::destructure;
+
+use core::{
+    fmt::{self, Debug},
+    marker::PhantomData,
+};
+
+use crate::peano::{PeanoInt, PlusOne, Zero};
+
+use super::NList;
+
+
+/// Builder for an [`NList`] that's constructed one element at a time.
+///
+/// The `Filled` type parameter is the amount of elements that have been pushed,
+/// and `Remaining` is the amount of elements that still need to be pushed.
+///
+/// [`push`](Self::push) can only be called when `Remaining != 0`,
+/// and [`finish`](Self::finish) can only be called when `Remaining == 0`.
+///
+/// # Example
+///
+/// ```rust
+/// use nlist::{NList, NListBuilder, Peano, nlist};
+///
+/// const LIST: NList<&str, Peano!(3)> = {
+///     let builder = NListBuilder::<&str, _, Peano!(3)>::new();
+///     let builder = builder.push("foo");
+///     let builder = builder.push("bar");
+///     builder.push("baz").finish()
+/// };
+///
+/// assert_eq!(LIST, nlist!["foo", "bar", "baz"]);
+///
+/// ```
+///
+/// ### Parsing
+///
+/// ```rust
+/// use nlist::{NList, Peano, nlist};
+///
+/// fn parse_rgb(s: &str) -> Option<NList<u8, Peano!(3)>> {
+///     let mut iter = s.split(',').map(|x| x.trim().parse::<u8>().ok());
+///
+///     let builder = NList::builder()
+///         .push(iter.next()??)
+///         .push(iter.next()??)
+///         .push(iter.next()??);
+///
+///     iter.next().is_none().then(|| builder.finish())
+/// }
+///
+/// assert_eq!(parse_rgb("3, 5, 8"), Some(nlist![3, 5, 8]));
+/// assert_eq!(parse_rgb("3, 5"), None);
+/// assert_eq!(parse_rgb("3, 5, 8, 13"), None);
+/// assert_eq!(parse_rgb("3, 500, 8"), None);
+///
+/// ```
+pub struct NListBuilder<T, Filled: PeanoInt, Remaining: PeanoInt> {
+    // the pushed elements, in reverse order
+    reversed: NList<T, Filled>,
+    remaining: PhantomData<Remaining>,
+}
+
+impl<T, L: PeanoInt> NList<T, L> {
+    /// Constructs a builder for an `NList<T, L>`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{NList, Peano, nlist};
+    ///
+    /// let list: NList<u8, Peano!(2)> = NList::builder().push(3).push(5).finish();
+    ///
+    /// assert_eq!(list, nlist![3, 5]);
+    ///
+    /// ```
+    pub const fn builder() -> NListBuilder<T, Zero, L> {
+        NListBuilder::new()
+    }
+}
+
+impl<T, Remaining: PeanoInt> NListBuilder<T, Zero, Remaining> {
+    /// Constructs a builder with no elements pushed yet.
+    pub const fn new() -> Self {
+        Self {
+            reversed: NList::nil(),
+            remaining: PhantomData,
+        }
+    }
+}
+
+impl<T, Filled, Remaining> NListBuilder<T, Filled, PlusOne<Remaining>>
+where
+    Filled: PeanoInt,
+    Remaining: PeanoInt,
+{
+    /// Appends `elem` to the list being built.
+    pub const fn push(self, elem: T) -> NListBuilder<T, PlusOne<Filled>, Remaining> {
+        destructure!{NListBuilder { reversed, remaining: _ } = self}
+
+        NListBuilder {
+            reversed: NList::cons(elem, reversed),
+            remaining: PhantomData,
+        }
+    }
+}
+
+impl<T, Filled: PeanoInt> NListBuilder<T, Filled, Zero> {
+    /// Returns the built list, with the elements in the order that they were pushed.
+    pub const fn finish(self) -> NList<T, Filled> {
+        destructure!{NListBuilder { reversed, remaining: _ } = self}
+
+        reversed.reverse()
+    }
+}
+
+impl<T, Filled: PeanoInt, Remaining: PeanoInt> NListBuilder<T, Filled, Remaining> {
+    /// Returns the amount of elements that have been pushed,
+    /// which is always `Filled::USIZE`.
+    pub const fn filled(&self) -> usize {
+        Filled::USIZE
+    }
+
+    /// Returns the amount of elements that still need to be pushed,
+    /// which is always `Remaining::USIZE`.
+    pub const fn remaining(&self) -> usize {
+        Remaining::USIZE
+    }
+}
+
+impl<T, Remaining: PeanoInt> Default for NListBuilder<T, Zero, Remaining> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, Filled, Remaining> Clone for NListBuilder<T, Filled, Remaining>
+where
+    T: Clone,
+    Filled: PeanoInt,
+    Remaining: PeanoInt,
+{
+    fn clone(&self) -> Self {
+        Self {
+            reversed: self.reversed.clone(),
+            remaining: PhantomData,
+        }
+    }
+}
+
+impl<T, Filled, Remaining> Debug for NListBuilder<T, Filled, Remaining>
+where
+    T: Debug,
+    Filled: PeanoInt,
+    Remaining: PeanoInt,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("NListBuilder")
+            .field("filled", &self.reversed.each_ref().reverse())
+            .field("remaining", &Remaining::USIZE)
+            .finish()
+    }
+}
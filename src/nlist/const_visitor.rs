@@ -0,0 +1,204 @@
+use crate::peano::{PeanoInt, PeanoWit};
+
+use super::{Cons, NList};
+
+
+/// A function over elements of type `T`, used by [`NList::map_const`].
+///
+/// This trait is implemented by the types declared with the
+/// [`const_visitor`](crate::const_visitor) macro that have a `map_const` function.
+///
+/// # Const-ness
+///
+/// Calling trait methods in const fns requires const trait impls,
+/// which aren't stable as of Rust 1.83,
+/// so [`NList::map_const`] can only be called at runtime.
+/// In const contexts, use the inherent `map_const` function
+/// that `const_visitor` generates on the implementor.
+///
+/// # Example
+///
+/// ```rust
+/// use nlist::{ConstMapper, NList, PeanoInt, nlist};
+///
+/// struct Double;
+///
+/// impl ConstMapper<u32> for Double {
+///     type Output = u32;
+///
+///     fn map_elem(elem: u32) -> u32 {
+///         elem * 2
+///     }
+/// }
+///
+/// fn map_twice<M, L>(list: NList<u32, L>) -> NList<u32, L>
+/// where
+///     M: ConstMapper<u32, Output = u32>,
+///     L: PeanoInt,
+/// {
+///     list.map_const::<M>().map_const::<M>()
+/// }
+///
+/// assert_eq!(map_twice::<Double, _>(nlist![3, 5, 8]), nlist![12, 20, 32]);
+/// ```
+pub trait ConstMapper<T> {
+    /// The type that elements are mapped to
+    type Output;
+
+    /// Maps one element of the list
+    fn map_elem(elem: T) -> Self::Output;
+}
+
+/// A function that folds elements of type `T` into an accumulator,
+/// used by [`NList::fold_const`].
+///
+/// This trait is implemented by the types declared with the
+/// [`const_visitor`](crate::const_visitor) macro that have a `fold_const` function.
+///
+/// [`NList::fold_const`] can only be called at runtime,
+/// for the same reason as [`ConstMapper`].
+///
+/// # Example
+///
+/// ```rust
+/// use nlist::{ConstFolder, nlist};
+///
+/// nlist::const_visitor! {
+///     struct Sum;
+///
+///     fold_const = |accum: u64, elem: u8| -> u64 { accum + elem as u64 };
+/// }
+///
+/// assert_eq!(nlist![3, 5, 8].fold_const::<Sum>(100), 116);
+/// ```
+pub trait ConstFolder<T> {
+    /// The type of the accumulator
+    type Accum;
+
+    /// Folds one element of the list into the accumulator, returning the next accumulator.
+    fn fold_elem(accum: Self::Accum, elem: T) -> Self::Accum;
+}
+
+/// A function that is called on elements of type `T`,
+/// used by [`NList::for_each_const`].
+///
+/// This trait is implemented by the types declared with the
+/// [`const_visitor`](crate::const_visitor) macro that have a `for_each_const` function.
+///
+/// [`NList::for_each_const`] can only be called at runtime,
+/// for the same reason as [`ConstMapper`].
+///
+/// # Example
+///
+/// ```rust
+/// use nlist::{ConstVisitor, nlist};
+///
+/// nlist::const_visitor! {
+///     struct Increment;
+///
+///     for_each_const = |elem: &mut u8| { *elem += 1 };
+/// }
+///
+/// let mut list = nlist![3, 5, 8];
+/// list.each_mut().for_each_const::<Increment>();
+///
+/// assert_eq!(list, nlist![4, 6, 9]);
+/// ```
+pub trait ConstVisitor<T> {
+    /// Visits one element of the list
+    fn visit_elem(elem: T);
+}
+
+
+impl<T, L: PeanoInt> NList<T, L> {
+    /// Maps each element of this list with the `M` [`ConstMapper`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::nlist;
+    ///
+    /// nlist::const_visitor! {
+    ///     struct AddHundred;
+    ///
+    ///     map_const = |elem: u32| -> u32 { elem + 100 };
+    /// }
+    ///
+    /// assert_eq!(nlist![3, 5, 8].map_const::<AddHundred>(), nlist![103, 105, 108]);
+    ///
+    /// // the same function can be called in const
+    /// const LIST: nlist::NList<u32, nlist::Peano!(2)> = AddHundred::map_const(nlist![3, 5]);
+    /// assert_eq!(LIST, nlist![103, 105]);
+    /// ```
+    pub fn map_const<M>(self) -> NList<M::Output, L>
+    where
+        M: ConstMapper<T>,
+    {
+        match L::PEANO_WIT {
+            PeanoWit::Zero(len_te) => NList::nil_sub(len_te),
+            PeanoWit::PlusOne(len_te) => {
+                let Cons { elem, next, .. } = self.coerce_len(len_te).node;
+                NList::cons_sub(M::map_elem(elem), next.map_const::<M>(), len_te)
+            }
+        }
+    }
+
+    /// Folds the elements of this list into `accum` with the `F` [`ConstFolder`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::nlist;
+    ///
+    /// nlist::const_visitor! {
+    ///     struct Digits;
+    ///
+    ///     fold_const = |accum: u32, elem: &u8| { accum * 10 + *elem as u32 };
+    /// }
+    ///
+    /// let list = nlist![3, 5, 8];
+    ///
+    /// assert_eq!(list.each_ref().fold_const::<Digits>(1), 1358);
+    /// ```
+    pub fn fold_const<F>(self, accum: F::Accum) -> F::Accum
+    where
+        F: ConstFolder<T>,
+    {
+        match L::PEANO_WIT {
+            PeanoWit::Zero(_) => accum,
+            PeanoWit::PlusOne(len_te) => {
+                let Cons { elem, next, .. } = self.coerce_len(len_te).node;
+                next.fold_const::<F>(F::fold_elem(accum, elem))
+            }
+        }
+    }
+
+    /// Calls the `V` [`ConstVisitor`] on each element of this list.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::nlist;
+    ///
+    /// nlist::const_visitor! {
+    ///     struct Square;
+    ///
+    ///     for_each_const = |elem: &mut u32| { *elem *= *elem };
+    /// }
+    ///
+    /// let mut list = nlist![3, 5, 8];
+    /// list.each_mut().for_each_const::<Square>();
+    ///
+    /// assert_eq!(list, nlist![9, 25, 64]);
+    /// ```
+    pub fn for_each_const<V>(self)
+    where
+        V: ConstVisitor<T>,
+    {
+        if let PeanoWit::PlusOne(len_te) = L::PEANO_WIT {
+            let Cons { elem, next, .. } = self.coerce_len(len_te).node;
+            V::visit_elem(elem);
+            next.for_each_const::<V>()
+        }
+    }
+}
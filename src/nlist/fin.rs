@@ -0,0 +1,343 @@
+use core::{
+    cmp::Ordering,
+    fmt::{self, Debug, Display},
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+};
+
+use crate::{
+    boolean::Bool,
+    peano::{PeanoInt, PeanoWit},
+};
+
+use super::NList;
+
+
+/// A `usize` that's guaranteed to be less than `L::USIZE`.
+///
+/// This can be used to index an [`NList<T, L>`] without bounds checks,
+/// with the [`get_fin`](NList::get_fin) and [`get_fin_mut`](NList::get_fin_mut) methods.
+///
+/// # Example
+///
+/// ```rust
+/// use nlist::{Fin, Peano, nlist};
+///
+/// let list = nlist![3, 5, 8, 13];
+///
+/// let index = Fin::<Peano!(4)>::new(2).unwrap();
+/// assert_eq!(list.get_fin(index), &8);
+/// assert_eq!(list.get_fin(index.wrapping_add(3)), &5);
+///
+/// assert_eq!(Fin::<Peano!(4)>::new(4), None);
+///
+/// ```
+pub struct Fin<L: PeanoInt> {
+    index: usize,
+    _len: PhantomData<L>,
+}
+
+impl<L: PeanoInt> Fin<L> {
+    /// Constructs a `Fin`, returns `None` if `index >= L::USIZE`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{Fin, Peano};
+    ///
+    /// assert_eq!(Fin::<Peano!(2)>::new(0).map(Fin::get), Some(0));
+    /// assert_eq!(Fin::<Peano!(2)>::new(1).map(Fin::get), Some(1));
+    /// assert_eq!(Fin::<Peano!(2)>::new(2), None);
+    ///
+    /// ```
+    pub const fn new(index: usize) -> Option<Self> {
+        if index < L::USIZE {
+            Some(Self { index, _len: PhantomData })
+        } else {
+            None
+        }
+    }
+
+    /// Constructs a `Fin` from the type-level integer `I`, requires `I < L`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{Fin, Peano};
+    ///
+    /// const INDEX: Fin<Peano!(4)> = Fin::from_peano::<Peano!(3)>();
+    ///
+    /// assert_eq!(INDEX.get(), 3);
+    ///
+    /// ```
+    pub const fn from_peano<I>() -> Self
+    where
+        I: PeanoInt<IsLt<L> = Bool<true>>,
+    {
+        Self { index: I::USIZE, _len: PhantomData }
+    }
+
+    /// Returns the index as a `usize`.
+    pub const fn get(self) -> usize {
+        self.index
+    }
+
+    /// Returns a list of all the `Fin<L>`, in ascending order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{Fin, NList, Peano, nlist};
+    ///
+    /// const ALL: NList<Fin<Peano!(3)>, Peano!(3)> = Fin::all();
+    ///
+    /// assert_eq!(ALL.map(Fin::get), nlist![0, 1, 2]);
+    ///
+    /// ```
+    pub const fn all() -> NList<Self, L> {
+        const fn inner<L0, L>(index: usize) -> NList<Fin<L0>, L>
+        where
+            L0: PeanoInt,
+            L: PeanoInt,
+        {
+            match L::PEANO_WIT {
+                PeanoWit::Zero(len_te) => NList::nil_sub(len_te),
+                PeanoWit::PlusOne(len_te) => {
+                    let fin = Fin { index, _len: PhantomData };
+
+                    NList::cons_sub(fin, inner(index + 1), len_te)
+                }
+            }
+        }
+
+        inner(0)
+    }
+
+    /// Adds `n` to this index, wrapping around at `L::USIZE`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{Fin, Peano};
+    ///
+    /// let index = Fin::<Peano!(4)>::new(2).unwrap();
+    ///
+    /// assert_eq!(index.wrapping_add(1).get(), 3);
+    /// assert_eq!(index.wrapping_add(2).get(), 0);
+    /// assert_eq!(index.wrapping_add(7).get(), 1);
+    ///
+    /// ```
+    pub const fn wrapping_add(self, n: usize) -> Self {
+        // can't overflow, because both operands are less than `L::USIZE`
+        let sum = self.index + n % L::USIZE;
+
+        Self { index: sum % L::USIZE, _len: PhantomData }
+    }
+
+    /// Subtracts `n` from this index, wrapping around at `L::USIZE`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{Fin, Peano};
+    ///
+    /// let index = Fin::<Peano!(4)>::new(2).unwrap();
+    ///
+    /// assert_eq!(index.wrapping_sub(1).get(), 1);
+    /// assert_eq!(index.wrapping_sub(3).get(), 3);
+    /// assert_eq!(index.wrapping_sub(9).get(), 1);
+    ///
+    /// ```
+    pub const fn wrapping_sub(self, n: usize) -> Self {
+        self.wrapping_add(L::USIZE - n % L::USIZE)
+    }
+
+    /// Adds `n` to this index, returns `None` if the sum is `>= L::USIZE`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{Fin, Peano};
+    ///
+    /// let index = Fin::<Peano!(4)>::new(2).unwrap();
+    ///
+    /// assert_eq!(index.checked_add(1).map(Fin::get), Some(3));
+    /// assert_eq!(index.checked_add(2), None);
+    ///
+    /// ```
+    pub const fn checked_add(self, n: usize) -> Option<Self> {
+        match self.index.checked_add(n) {
+            Some(sum) => Self::new(sum),
+            None => None,
+        }
+    }
+
+    /// Subtracts `n` from this index, returns `None` if `n > self.get()`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{Fin, Peano};
+    ///
+    /// let index = Fin::<Peano!(4)>::new(2).unwrap();
+    ///
+    /// assert_eq!(index.checked_sub(2).map(Fin::get), Some(0));
+    /// assert_eq!(index.checked_sub(3), None);
+    ///
+    /// ```
+    pub const fn checked_sub(self, n: usize) -> Option<Self> {
+        match self.index.checked_sub(n) {
+            Some(index) => Some(Self { index, _len: PhantomData }),
+            None => None,
+        }
+    }
+
+    /// Converts this into a `Fin<L2>` with the same index, requires `L <= L2`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{Fin, Peano};
+    ///
+    /// let index = Fin::<Peano!(2)>::new(1).unwrap();
+    /// let widened: Fin<Peano!(5)> = index.widen();
+    ///
+    /// assert_eq!(widened.get(), 1);
+    ///
+    /// ```
+    pub const fn widen<L2>(self) -> Fin<L2>
+    where
+        L2: PeanoInt,
+        L: PeanoInt<IsLe<L2> = Bool<true>>,
+    {
+        Fin { index: self.index, _len: PhantomData }
+    }
+}
+
+impl<T, L: PeanoInt> NList<T, L> {
+    /// Returns a reference to the element at the `index` index.
+    ///
+    /// Unlike [`get`](Self::get), this can't fail,
+    /// because `Fin<L>` is always less than the length of the list.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{Fin, nlist};
+    ///
+    /// let list = nlist![3, 5, 8];
+    ///
+    /// assert_eq!(Fin::all().map(|i| list.get_fin(i)), nlist![&3, &5, &8]);
+    ///
+    /// ```
+    pub const fn get_fin(&self, index: Fin<L>) -> &T {
+        match self.get(index.index) {
+            Some(elem) => elem,
+            None => panic!("BUG: `Fin<L>` is out of bounds"),
+        }
+    }
+
+    /// Returns a mutable reference to the element at the `index` index.
+    ///
+    /// Unlike [`get_mut`](Self::get_mut), this can't fail,
+    /// because `Fin<L>` is always less than the length of the list.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{Fin, Peano, nlist};
+    ///
+    /// let mut list = nlist![3, 5, 8];
+    ///
+    /// *list.get_fin_mut(Fin::from_peano::<Peano!(1)>()) += 100;
+    ///
+    /// assert_eq!(list, nlist![3, 105, 8]);
+    ///
+    /// ```
+    pub const fn get_fin_mut(&mut self, index: Fin<L>) -> &mut T {
+        match self.get_mut(index.index) {
+            Some(elem) => elem,
+            None => panic!("BUG: `Fin<L>` is out of bounds"),
+        }
+    }
+
+    /// Returns a list with clones of the elements at each of the `indices`.
+    ///
+    /// Indices can be repeated, and the returned list can have any length.
+    ///
+    /// To reorder the elements without cloning them, you can use [`Perm::apply`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{Fin, Peano, nlist};
+    ///
+    /// let list = nlist!["left", "right"];
+    ///
+    /// let indices = nlist![0, 1, 1, 0, 0].map(|i| Fin::<Peano!(2)>::new(i).unwrap());
+    ///
+    /// assert_eq!(list.gather(indices), nlist!["left", "right", "right", "left", "left"]);
+    ///
+    /// ```
+    ///
+    /// [`Perm::apply`]: crate::Perm::apply
+    pub fn gather<M>(&self, indices: NList<Fin<L>, M>) -> NList<T, M>
+    where
+        T: Clone,
+        M: PeanoInt,
+    {
+        indices.map(|index| self.get_fin(index).clone())
+    }
+}
+
+impl<L: PeanoInt> Copy for Fin<L> {}
+
+impl<L: PeanoInt> Clone for Fin<L> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<L: PeanoInt> Debug for Fin<L> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.index, fmt)
+    }
+}
+
+impl<L: PeanoInt> Display for Fin<L> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.index, fmt)
+    }
+}
+
+impl<L: PeanoInt> Hash for Fin<L> {
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        self.index.hash(hasher)
+    }
+}
+
+impl<L: PeanoInt> PartialEq for Fin<L> {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.index == rhs.index
+    }
+}
+
+impl<L: PeanoInt> Eq for Fin<L> {}
+
+impl<L: PeanoInt> PartialOrd for Fin<L> {
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        Some(self.cmp(rhs))
+    }
+}
+
+impl<L: PeanoInt> Ord for Fin<L> {
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        self.index.cmp(&rhs.index)
+    }
+}
+
+impl<L: PeanoInt> From<Fin<L>> for usize {
+    fn from(fin: Fin<L>) -> usize {
+        fin.index
+    }
+}
@@ -7,7 +7,7 @@ use konst::destructure;
 
 use typewit::{type_fn, CallFn, TypeCmp, TypeEq};
 
-use super::{NList, NList2D, NListFn};
+use super::{splitting::split_add, NList, NList2D, NListFn};
 use crate::peano::{self, PeanoInt, PeanoWit, PlusOne, SubOneSat, Zero};
 
 impl<T, L: PeanoInt, L2: PeanoInt> NList<NList<T, L2>, L> {
@@ -311,6 +311,55 @@ impl<T, L: PeanoInt, L2: PeanoInt> NList<NList<T, L2>, L> {
 }
 
 
+impl<T, L: PeanoInt, L2: PeanoInt> NList<NList<T, L2>, L> {
+    /// Splits a flat list into `L` lists of `L2` elements, the inverse of [`flatten`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{NList, NList2D, Peano, nlist};
+    ///
+    /// const CHUNKS: NList2D<u32, Peano!(3), Peano!(2)> =
+    ///     NList::unflatten(nlist![3, 5, 8, 13, 21, 34]);
+    ///
+    /// assert_eq!(CHUNKS, nlist![nlist![3, 5], nlist![8, 13], nlist![21, 34]]);
+    /// assert_eq!(CHUNKS.flatten(), nlist![3, 5, 8, 13, 21, 34]);
+    /// ```
+    ///
+    /// [`flatten`]: NList::flatten
+    pub const fn unflatten(flat: NList<T, peano::Mul<L, L2>>) -> Self {
+        const fn inner<T, L, L2>(flat: NList<T, peano::Mul<L, L2>>) -> NList2D<T, L, L2>
+        where
+            L: PeanoInt,
+            L2: PeanoInt,
+        {
+            match L::PEANO_WIT {
+                PeanoWit::Zero(len_te) => {
+                    let flat_te = len_te.zip(TypeEq::new::<L2>()).map(peano::MulFn::NEW);
+
+                    // works around "destructor cannot be evaluated at compile-time" error
+                    _ = flat.coerce_len(flat_te);
+
+                    NList::nil_sub(len_te)
+                }
+                PeanoWit::PlusOne(len_te) => {
+                    // `L * L2 == L2 + (L - 1) * L2`
+                    let flat_te = len_te
+                        .zip(TypeEq::new::<L2>())
+                        .map(peano::MulFn::NEW)
+                        .join(peano::proofs::commutative_add::<peano::Mul<SubOneSat<L>, L2>, L2>());
+
+                    destructure!{(chunk, rest) = split_add(flat.coerce_len(flat_te))}
+
+                    NList::cons_sub(chunk, inner(rest), len_te)
+                }
+            }
+        }
+
+        inner(flat)
+    }
+}
+
 typewit::type_fn! {
     struct NList2DFn<T>;
 
@@ -0,0 +1,162 @@
+use konst::destructure;
+
+use crate::peano::{self, PeanoInt, PeanoWit};
+
+use super::{NList, NList2D};
+
+
+macro_rules! impl_int_bytes {
+    ($(
+        $ty:ident ($ex_len:literal: $($ex_elem:tt)*)
+        [$from_le:ident $from_be:ident $from_ne:ident $to_le:ident $to_be:ident $to_ne:ident]
+    )*) => {$(
+        impl<L: PeanoInt> NList<$ty, L> {
+            impl_int_bytes!{
+                @list_methods $ty
+                (
+                    "little-endian", to_le_bytes, from_le_bytes,
+                    #[doc = concat!(
+                        "# Example\n\n",
+                        "```rust\n",
+                        "use nlist::{NList, Peano, nlist};\n",
+                        "\n",
+                        "let list: NList<", stringify!($ty), ", Peano!(", $ex_len, ")> = ",
+                        "nlist![", stringify!($($ex_elem)*), "];\n",
+                        "let bytes = list.to_le_bytes();\n",
+                        "\n",
+                        "assert_eq!(\n",
+                        "    bytes.clone().into_vec(),\n",
+                        "    list.clone().into_vec().into_iter().flat_map(", stringify!($ty), "::to_le_bytes).collect::<Vec<u8>>(),\n",
+                        ");\n",
+                        "assert_eq!(NList::<", stringify!($ty), ", Peano!(", $ex_len, ")>::from_le_bytes(bytes), list);\n",
+                        "```\n",
+                    )]
+                )
+                ("big-endian", to_be_bytes, from_be_bytes,)
+                ("native-endian", to_ne_bytes, from_ne_bytes,)
+            }
+        }
+
+        impl NList<u8, Peano!(core::mem::size_of::<$ty>())> {
+            impl_int_bytes!{
+                @scalar_methods $ty
+                (
+                    "little-endian", $from_le, $to_le, to_le_bytes, from_le_bytes,
+                    #[doc = concat!(
+                        "# Example\n\n",
+                        "```rust\n",
+                        "use nlist::NList;\n",
+                        "\n",
+                        "let bytes = NList::", stringify!($from_le), "(3);\n",
+                        "\n",
+                        "assert_eq!(bytes.", stringify!($to_le), "(), 3);\n",
+                        "assert_eq!(bytes.into_array(), 3", stringify!($ty), ".to_le_bytes());\n",
+                        "```\n",
+                    )]
+                )
+                ("big-endian", $from_be, $to_be, to_be_bytes, from_be_bytes,)
+                ("native-endian", $from_ne, $to_ne, to_ne_bytes, from_ne_bytes,)
+            }
+        }
+    )*};
+    (@list_methods $ty:ident $((
+        $endian:literal, $to_bytes:ident, $from_bytes:ident, $(#[$example:meta])?
+    ))*) => {$(
+        #[doc = concat!(
+            "Converts each integer into its ", $endian, " bytes, ",
+            "returning the concatenation of those bytes.",
+        )]
+        ///
+        $(#[$example])?
+        pub const fn $to_bytes(&self) -> NList<u8, peano::Mul<L, Peano!(core::mem::size_of::<$ty>())>> {
+            const fn inner<L2: PeanoInt>(
+                list: &NList<$ty, L2>,
+            ) -> NList2D<u8, L2, Peano!(core::mem::size_of::<$ty>())> {
+                match L2::PEANO_WIT {
+                    PeanoWit::Zero(len_te) => NList::nil_sub(len_te),
+                    PeanoWit::PlusOne(len_te) => {
+                        let (elem, next) = list.as_coerce_len(len_te).split_head();
+                        let bytes = NList::from_array(elem.$to_bytes());
+                        NList::cons_sub(bytes, inner(next), len_te)
+                    }
+                }
+            }
+
+            inner(self).flatten()
+        }
+
+        #[doc = concat!(
+            "Constructs a list of integers from their concatenated ", $endian, " bytes.",
+        )]
+        pub const fn $from_bytes(
+            bytes: NList<u8, peano::Mul<L, Peano!(core::mem::size_of::<$ty>())>>,
+        ) -> Self {
+            const fn inner<L2: PeanoInt>(
+                chunks: NList2D<u8, L2, Peano!(core::mem::size_of::<$ty>())>,
+            ) -> NList<$ty, L2> {
+                match L2::PEANO_WIT {
+                    PeanoWit::Zero(len_te) => {
+                        // works around "destructor cannot be evaluated at compile-time" error
+                        _ = chunks.coerce_len(len_te);
+
+                        NList::nil_sub(len_te)
+                    }
+                    PeanoWit::PlusOne(len_te) => {
+                        destructure!{(chunk, next) = chunks.coerce_len(len_te).into_split_head()}
+                        let elem = <$ty>::$from_bytes(chunk.into_array());
+                        NList::cons_sub(elem, inner(next), len_te)
+                    }
+                }
+            }
+
+            inner(NList::unflatten(bytes))
+        }
+    )*};
+    (@scalar_methods $ty:ident $((
+        $endian:literal, $from_int:ident, $to_int:ident, $to_bytes:ident, $from_bytes:ident,
+        $(#[$example:meta])?
+    ))*) => {$(
+        #[doc = concat!(
+            "Constructs a list of the ", $endian, " bytes of a `", stringify!($ty), "`.",
+        )]
+        ///
+        $(#[$example])?
+        pub const fn $from_int(int: $ty) -> Self {
+            NList::from_array(int.$to_bytes())
+        }
+
+        #[doc = concat!(
+            "Converts this list of ", $endian, " bytes into a `", stringify!($ty), "`.",
+        )]
+        pub const fn $to_int(&self) -> $ty {
+            <$ty>::$from_bytes(self.copy().into_array())
+        }
+    )*};
+}
+
+impl_int_bytes! {
+    u8 (2: 3, 5)
+        [from_u8_le from_u8_be from_u8_ne to_u8_le to_u8_be to_u8_ne]
+    u16 (2: 3, 5)
+        [from_u16_le from_u16_be from_u16_ne to_u16_le to_u16_be to_u16_ne]
+    u32 (2: 3, 5)
+        [from_u32_le from_u32_be from_u32_ne to_u32_le to_u32_be to_u32_ne]
+    u64 (2: 3, 5)
+        [from_u64_le from_u64_be from_u64_ne to_u64_le to_u64_be to_u64_ne]
+    u128 (1: 3)
+        [from_u128_le from_u128_be from_u128_ne to_u128_le to_u128_be to_u128_ne]
+    usize (2: 3, 5)
+        [from_usize_le from_usize_be from_usize_ne to_usize_le to_usize_be to_usize_ne]
+    i8 (2: 3, -5)
+        [from_i8_le from_i8_be from_i8_ne to_i8_le to_i8_be to_i8_ne]
+    i16 (2: 3, -5)
+        [from_i16_le from_i16_be from_i16_ne to_i16_le to_i16_be to_i16_ne]
+    i32 (2: 3, -5)
+        [from_i32_le from_i32_be from_i32_ne to_i32_le to_i32_be to_i32_ne]
+    i64 (2: 3, -5)
+        [from_i64_le from_i64_be from_i64_ne to_i64_le to_i64_be to_i64_ne]
+    i128 (1: -3)
+        [from_i128_le from_i128_be from_i128_ne to_i128_le to_i128_be to_i128_ne]
+    isize (2: 3, -5)
+        [from_isize_le from_isize_be from_isize_ne to_isize_le to_isize_be to_isize_ne]
+}
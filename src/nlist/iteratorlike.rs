@@ -10,7 +10,7 @@ use crate::peano::{
 };
 
 #[allow(unused_imports)]
-use super::{Nil, Cons, NList, NListFn, NodeFn};
+use super::{BoundedNList, Nil, Cons, NList, NListFn, NodeFn};
 
 
 
@@ -411,6 +411,187 @@ impl<T, L: PeanoInt> NList<T, L> {
         }
     }
 
+    /// Fallibly maps the elements of this list,
+    /// returning the first error that `f` returns.
+    ///
+    /// On error, the elements that were already mapped and
+    /// the elements that weren't passed to `f` are dropped.
+    ///
+    /// # Alternatives
+    ///
+    /// You can use the [`rec_try_map`](crate::rec_try_map)
+    /// macro to emulate this method with a const function. 
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::nlist;
+    ///
+    /// let list = nlist!["192", "168", "0", "1"];
+    /// assert_eq!(list.try_map(|x| x.parse::<u8>()), Ok(nlist![192, 168, 0, 1]));
+    ///
+    /// let list = nlist!["192", "168", "0", "256"];
+    /// assert!(list.try_map(|x| x.parse::<u8>()).is_err());
+    ///
+    /// ```
+    pub fn try_map<F, R, E>(self, mut f: F) -> Result<NList<R, L>, E>
+    where
+        F: FnMut(T) -> Result<R, E>,
+    {
+        fn inner<T, L, F, R, E>(list: NList<T, L>, f: &mut F) -> Result<NList<R, L>, E>
+        where
+            L: PeanoInt,
+            F: FnMut(T) -> Result<R, E>,
+        {
+            match L::PEANO_WIT {
+                PeanoWit::Zero(len_te) => Ok(NList::nil_sub(len_te)),
+
+                PeanoWit::PlusOne(len_te) => {
+                    let Cons { elem, next, .. } = list.coerce_len(len_te).node;
+                    let elem = f(elem)?;
+                    let next = inner(next, f)?;
+
+                    Ok(NList::cons_sub(elem, next, len_te))
+                }
+            }
+        }
+
+        inner(self, &mut f)
+    }
+
+    /// Fallibly maps the elements of this list,
+    /// returning `None` as soon as `f` returns `None`.
+    ///
+    /// On `None`, the elements that were already mapped and
+    /// the elements that weren't passed to `f` are dropped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::nlist;
+    ///
+    /// let list = nlist![3u8, 5, 8];
+    /// assert_eq!(list.try_map_option(|x| x.checked_mul(20)), Some(nlist![60, 100, 160]));
+    ///
+    /// let list = nlist![3u8, 13, 8];
+    /// assert_eq!(list.try_map_option(|x| x.checked_mul(20)), None);
+    ///
+    /// ```
+    pub fn try_map_option<F, R>(self, mut f: F) -> Option<NList<R, L>>
+    where
+        F: FnMut(T) -> Option<R>,
+    {
+        self.try_map(|elem| f(elem).ok_or(())).ok()
+    }
+
+    /// Returns the elements for which `predicate(&element)` returns true.
+    ///
+    /// The returned list has a runtime length that's at most `L::USIZE`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{BoundedNList, Peano, nlist};
+    ///
+    /// let list = nlist![3, 4, 8, 13, 16];
+    ///
+    /// let evens: BoundedNList<u32, Peano!(5)> = list.filter(|x| *x % 2 == 0);
+    /// assert_eq!(evens.len(), 3);
+    /// assert_eq!(evens.into_vec(), vec![4, 8, 16]);
+    ///
+    /// ```
+    pub fn filter<F>(self, mut predicate: F) -> BoundedNList<T, L>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        BoundedNList::from_sparse(self.map(|elem| predicate(&elem).then_some(elem)))
+    }
+
+    /// Returns the elements of the list, removing consecutive repeated elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::nlist;
+    ///
+    /// let list = nlist![3, 3, 5, 8, 8, 8, 3];
+    ///
+    /// assert_eq!(list.dedup().into_vec(), vec![3, 5, 8, 3]);
+    ///
+    /// ```
+    pub fn dedup(self) -> BoundedNList<T, L>
+    where
+        T: PartialEq,
+    {
+        // the last kept element, which is only added to the output
+        // once an element different from it is found
+        let mut prev: Option<T> = None;
+
+        let mut out = BoundedNList::from_sparse(self.map(|elem| {
+            if prev.as_ref() == Some(&elem) {
+                None
+            } else {
+                prev.replace(elem)
+            }
+        }));
+
+        if let Some(last) = prev {
+            out.push(last);
+        }
+
+        out
+    }
+
+    /// Returns the elements before the first one for which `predicate(&element)`
+    /// returns false.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::nlist;
+    ///
+    /// let list = nlist![3, 5, 8, 13, 21];
+    ///
+    /// assert_eq!(list.take_while(|x| *x < 10).into_vec(), vec![3, 5, 8]);
+    ///
+    /// ```
+    pub fn take_while<F>(self, mut predicate: F) -> BoundedNList<T, L>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut taking = true;
+
+        BoundedNList::from_sparse(self.map(|elem| {
+            taking = taking && predicate(&elem);
+            taking.then_some(elem)
+        }))
+    }
+
+    /// Returns the elements starting from the first one for which `predicate(&element)`
+    /// returns false.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::nlist;
+    ///
+    /// let list = nlist![3, 5, 8, 13, 21];
+    ///
+    /// assert_eq!(list.skip_while(|x| *x < 10).into_vec(), vec![13, 21]);
+    ///
+    /// ```
+    pub fn skip_while<F>(self, mut predicate: F) -> BoundedNList<T, L>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut skipping = true;
+
+        BoundedNList::from_sparse(self.map(|elem| {
+            skipping = skipping && predicate(&elem);
+            (!skipping).then_some(elem)
+        }))
+    }
+
     /// Loops over the elements in the list, along with their index.
     ///
     /// # Alternatives
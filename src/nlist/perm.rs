@@ -0,0 +1,185 @@
+use core::{
+    fmt::{self, Debug},
+    hash::{Hash, Hasher},
+};
+
+use konst::destructure;
+
+use crate::peano::PeanoInt;
+
+use super::{Fin, NList};
+
+
+/// A permutation of the indices of an [`NList<T, L>`].
+///
+/// The permutation is stored as a list of indices,
+/// where the element at position `i` of the list returned by [`apply`](Self::apply)
+/// comes from the index at position `i` of [`as_nlist`](Self::as_nlist).
+///
+/// # Example
+///
+/// ```rust
+/// use nlist::{Perm, Peano, nlist};
+///
+/// type L = Peano!(3);
+///
+/// let rotate = Perm::<L>::from_usizes(nlist![1, 2, 0]).unwrap();
+///
+/// assert_eq!(rotate.apply(nlist!["a", "b", "c"]), nlist!["b", "c", "a"]);
+/// assert_eq!(rotate.inverse().apply(nlist!["b", "c", "a"]), nlist!["a", "b", "c"]);
+///
+/// let twice = rotate.compose(&rotate);
+/// assert_eq!(twice.apply(nlist!["a", "b", "c"]), nlist!["c", "a", "b"]);
+///
+/// // repeated indices are not a valid permutation
+/// assert!(Perm::<L>::from_usizes(nlist![1, 1, 0]).is_none());
+///
+/// ```
+pub struct Perm<L: PeanoInt> {
+    indices: NList<Fin<L>, L>,
+}
+
+impl<L: PeanoInt> Perm<L> {
+    /// Constructs a `Perm` from a list of indices,
+    /// returns `None` if any index is repeated.
+    pub fn new(indices: NList<Fin<L>, L>) -> Option<Self> {
+        let mut seen = NList::<bool, L>::repeat_copy(false);
+        let mut is_perm = true;
+
+        indices.each_ref().for_each(|_, &index| {
+            is_perm &= !core::mem::replace(seen.get_fin_mut(index), true);
+        });
+
+        is_perm.then_some(Self { indices })
+    }
+
+    /// Constructs a `Perm` from a list of indices,
+    /// returns `None` if any index is repeated or is `>= L::USIZE`.
+    pub fn from_usizes(indices: NList<usize, L>) -> Option<Self> {
+        Self::new(indices.try_map_option(Fin::new)?)
+    }
+
+    /// The permutation that leaves the elements in place.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{Perm, Peano, nlist};
+    ///
+    /// const ID: Perm<Peano!(3)> = Perm::identity();
+    ///
+    /// assert_eq!(ID.apply(nlist![3, 5, 8]), nlist![3, 5, 8]);
+    ///
+    /// ```
+    pub const fn identity() -> Self {
+        Self { indices: Fin::all() }
+    }
+
+    /// Gets the list of indices of this permutation.
+    pub const fn as_nlist(&self) -> &NList<Fin<L>, L> {
+        &self.indices
+    }
+
+    /// Converts this permutation into its list of indices.
+    pub const fn into_nlist(self) -> NList<Fin<L>, L> {
+        destructure!{Perm { indices } = self}
+
+        indices
+    }
+
+    /// Reorders the elements of `list`, without cloning them.
+    ///
+    /// The element at position `i` of the returned list is
+    /// the element of `list` at the index at position `i` of `self`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{Perm, Peano, nlist};
+    ///
+    /// let swap = Perm::<Peano!(2)>::from_usizes(nlist![1, 0]).unwrap();
+    ///
+    /// assert_eq!(swap.apply(nlist![String::from("foo"), String::from("bar")]), nlist!["bar", "foo"]);
+    ///
+    /// ```
+    pub fn apply<T>(&self, list: NList<T, L>) -> NList<T, L> {
+        let mut slots = list.map(Some);
+
+        self.indices.copy().map(|index| {
+            slots.get_fin_mut(index).take().expect("indices in a `Perm` are unique")
+        })
+    }
+
+    /// Returns the permutation equivalent to applying `other` and then `self`.
+    ///
+    /// That is, `self.compose(other).apply(list) == self.apply(other.apply(list))`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{Perm, Peano, nlist};
+    ///
+    /// let swap_first = Perm::<Peano!(3)>::from_usizes(nlist![1, 0, 2]).unwrap();
+    /// let swap_last = Perm::<Peano!(3)>::from_usizes(nlist![0, 2, 1]).unwrap();
+    ///
+    /// let composed = swap_first.compose(&swap_last);
+    ///
+    /// assert_eq!(composed.apply(nlist![3, 5, 8]), nlist![8, 3, 5]);
+    /// assert_eq!(swap_first.apply(swap_last.apply(nlist![3, 5, 8])), nlist![8, 3, 5]);
+    ///
+    /// ```
+    pub fn compose(&self, other: &Perm<L>) -> Self {
+        Self { indices: self.indices.copy().map(|index| *other.indices.get_fin(index)) }
+    }
+
+    /// Returns the permutation that undoes this one.
+    ///
+    /// That is, `self.inverse().apply(self.apply(list)) == list`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{Perm, Peano, nlist};
+    ///
+    /// let perm = Perm::<Peano!(4)>::from_usizes(nlist![2, 0, 3, 1]).unwrap();
+    ///
+    /// assert_eq!(perm.apply(nlist![3, 5, 8, 13]), nlist![8, 3, 13, 5]);
+    /// assert_eq!(perm.inverse().apply(nlist![8, 3, 13, 5]), nlist![3, 5, 8, 13]);
+    ///
+    /// ```
+    pub fn inverse(&self) -> Self {
+        let mut indices = Fin::all();
+
+        Fin::<L>::all().for_each(|_, pos| {
+            *indices.get_fin_mut(*self.indices.get_fin(pos)) = pos;
+        });
+
+        Self { indices }
+    }
+}
+
+impl<L: PeanoInt> Clone for Perm<L> {
+    fn clone(&self) -> Self {
+        Self { indices: self.indices.copy() }
+    }
+}
+
+impl<L: PeanoInt> Debug for Perm<L> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_tuple("Perm").field(&self.indices).finish()
+    }
+}
+
+impl<L: PeanoInt> Hash for Perm<L> {
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        self.indices.hash(hasher)
+    }
+}
+
+impl<L: PeanoInt> PartialEq for Perm<L> {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.indices == rhs.indices
+    }
+}
+
+impl<L: PeanoInt> Eq for Perm<L> {}
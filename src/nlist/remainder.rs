@@ -0,0 +1,260 @@
+use konst::destructure;
+
+use typewit::TypeEq;
+
+use core::fmt::{self, Debug};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::peano::{PeanoInt, PeanoWit, PlusOne, Zero};
+
+use super::{Cons, NList};
+
+
+/// The elements of an [`NList<T, L>`] that were not consumed by
+/// an operation that can terminate early.
+///
+/// This is returned by the [`rec_find_map_rest`](crate::rec_find_map_rest) macro,
+/// so that const code can iterate by value over lists of non-`Copy` elements
+/// without having to drop the elements that weren't consumed.
+///
+/// Each of the `L` slots of this type can either hold an element or be vacant,
+/// the [`len`](Self::len) method returns how many elements are held.
+///
+/// # Const usage
+///
+/// Because dropping a `Remainder` of non-`Copy` elements is not possible in const,
+/// const code must consume the elements with the [`pop_front`](Self::pop_front)
+/// method and then call [`assert_empty_drop`](Self::assert_empty_drop),
+/// or convert it to a list of `Option`s with
+/// [`into_option_nlist`](Self::into_option_nlist).
+///
+/// # Example
+///
+/// ```rust
+/// use nlist::{NList, Remainder, nlist};
+///
+/// let mut rem = Remainder::cons(Some(3), Remainder::cons(None, Remainder::from_nlist(nlist![8])));
+///
+/// assert_eq!(rem.len(), 2);
+/// assert_eq!(rem.pop_front(), Some(3));
+/// assert_eq!(rem.pop_front(), Some(8));
+/// assert_eq!(rem.pop_front(), None);
+///
+/// rem.assert_empty_drop();
+///
+/// ```
+pub struct Remainder<T, L: PeanoInt> {
+    list: NList<Option<T>, L>,
+}
+
+impl<T> Remainder<T, Zero> {
+    /// Constructs an empty `Remainder`
+    pub const fn nil() -> Self {
+        Self { list: NList::nil() }
+    }
+}
+
+impl<T, L: PeanoInt> Remainder<T, PlusOne<L>> {
+    /// Constructs a `Remainder` from its first slot and the rest of the remainder.
+    /// 
+    /// If `elem` is `None`, the first slot is vacant.
+    pub const fn cons(elem: Option<T>, next: Remainder<T, L>) -> Self {
+        destructure!{Remainder { list } = next}
+
+        Self { list: NList::cons(elem, list) }
+    }
+}
+
+impl<T, L: PeanoInt> Remainder<T, L> {
+    /// Constructs a `Remainder` that holds all the elements of `list`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{Remainder, nlist};
+    ///
+    /// let rem = Remainder::from_nlist(nlist![3, 5]);
+    ///
+    /// assert_eq!(rem.len(), 2);
+    /// assert_eq!(rem.into_option_nlist(), nlist![Some(3), Some(5)]);
+    ///
+    /// ```
+    pub const fn from_nlist(list: NList<T, L>) -> Self {
+        const fn inner<T, L: PeanoInt>(list: NList<T, L>) -> NList<Option<T>, L> {
+            match L::PEANO_WIT {
+                PeanoWit::Zero(len_te) => {
+                    // works around "destructor cannot be evaluated at compile-time" error
+                    _ = list.coerce_len(len_te);
+
+                    NList::nil_sub(len_te)
+                }
+                PeanoWit::PlusOne(len_te) => {
+                    destructure!{(elem, next) = list.coerce_len(len_te).into_split_head()}
+
+                    NList::cons_sub(Some(elem), inner(next), len_te)
+                }
+            }
+        }
+
+        Self { list: inner(list) }
+    }
+
+    /// Constructs a `Remainder` from a list where `None` elements are vacant slots.
+    pub const fn from_option_nlist(list: NList<Option<T>, L>) -> Self {
+        Self { list }
+    }
+
+    /// Converts this `Remainder` into a list where vacant slots are `None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{Remainder, nlist};
+    ///
+    /// let rem = Remainder::cons(None, Remainder::from_nlist(nlist![5, 8]));
+    ///
+    /// assert_eq!(rem.into_option_nlist(), nlist![None, Some(5), Some(8)]);
+    ///
+    /// ```
+    pub const fn into_option_nlist(self) -> NList<Option<T>, L> {
+        destructure!{Remainder { list } = self}
+
+        list
+    }
+
+    /// Gets a reference to the list of slots, where vacant slots are `None`.
+    pub const fn as_option_nlist(&self) -> &NList<Option<T>, L> {
+        &self.list
+    }
+
+    /// Given a proof that `L == L2`, coerces `Remainder<T, L>` to `Remainder<T, L2>`
+    pub const fn coerce_len<L2: PeanoInt>(self, len_te: TypeEq<L, L2>) -> Remainder<T, L2> {
+        Remainder { list: self.into_option_nlist().coerce_len(len_te) }
+    }
+
+    /// Returns the amount of elements held by this `Remainder`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{Remainder, nlist};
+    ///
+    /// let rem = Remainder::cons(None, Remainder::from_nlist(nlist![5, 8]));
+    ///
+    /// assert_eq!(rem.len(), 2);
+    ///
+    /// ```
+    pub const fn len(&self) -> usize {
+        const fn inner<T, L: PeanoInt>(list: &NList<Option<T>, L>) -> usize {
+            match L::PEANO_WIT {
+                PeanoWit::Zero(_) => 0,
+                PeanoWit::PlusOne(len_te) => {
+                    let Cons { elem, next, .. } = &list.as_coerce_len(len_te).node;
+
+                    elem.is_some() as usize + inner(next)
+                }
+            }
+        }
+
+        inner(&self.list)
+    }
+
+    /// Whether this `Remainder` holds no elements.
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes and returns the first element held by this `Remainder`,
+    /// returns `None` if it holds no elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{Remainder, nlist};
+    ///
+    /// let mut rem = Remainder::cons(None, Remainder::from_nlist(nlist![5, 8]));
+    ///
+    /// assert_eq!(rem.pop_front(), Some(5));
+    /// assert_eq!(rem.pop_front(), Some(8));
+    /// assert_eq!(rem.pop_front(), None);
+    ///
+    /// ```
+    pub const fn pop_front(&mut self) -> Option<T> {
+        const fn inner<T, L: PeanoInt>(list: &mut NList<Option<T>, L>) -> Option<T> {
+            match L::PEANO_WIT {
+                PeanoWit::Zero(_) => None,
+                PeanoWit::PlusOne(len_te) => {
+                    let Cons { elem, next, .. } = &mut list.as_mut_coerce_len(len_te).node;
+
+                    if elem.is_some() {
+                        elem.take()
+                    } else {
+                        inner(next)
+                    }
+                }
+            }
+        }
+
+        inner(&mut self.list)
+    }
+
+    /// Helper method for dropping a `Remainder` that holds no elements in a const context.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this `Remainder` holds any elements.
+    pub const fn assert_empty_drop(self) {
+        if !self.is_empty() {
+            panic!("expected the `Remainder` to hold no elements");
+        }
+
+        core::mem::forget(self)
+    }
+
+    /// Converts this `Remainder` into a `Vec` of the elements it holds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{Remainder, nlist};
+    ///
+    /// let rem = Remainder::cons(Some(3), Remainder::cons(None, Remainder::from_nlist(nlist![8])));
+    ///
+    /// assert_eq!(rem.into_vec(), vec![3, 8]);
+    ///
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn into_vec(self) -> Vec<T> {
+        self.list.into_vec().into_iter().flatten().collect()
+    }
+}
+
+impl<T, L> Clone for Remainder<T, L>
+where
+    T: Clone,
+    L: PeanoInt,
+{
+    fn clone(&self) -> Self {
+        Self { list: self.list.clone() }
+    }
+}
+
+impl<T, L> Debug for Remainder<T, L>
+where
+    T: Debug,
+    L: PeanoInt,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut fmt = fmt.debug_list();
+
+        self.list.each_ref().for_each(|_, elem| {
+            if let Some(elem) = elem {
+                _ = fmt.entry(elem);
+            }
+        });
+
+        fmt.finish()
+    }
+}
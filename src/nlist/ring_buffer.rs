@@ -0,0 +1,350 @@
+use core::fmt::{self, Debug};
+
+use crate::peano::{PeanoInt, PeanoWit};
+
+use super::{bounded::none_list, NList};
+
+
+/// A circular buffer that holds at most `Cap` elements, stored inline.
+///
+/// Pushing into a full `RingBuffer` with [`push_overwrite`](Self::push_overwrite)
+/// overwrites its oldest element.
+///
+/// # Const usage
+///
+/// Because dropping a `RingBuffer` of generic capacity is not possible in const,
+/// const code that is generic over the capacity must consume the elements with
+/// [`pop_front`](Self::pop_front) and then call [`assert_empty_drop`](Self::assert_empty_drop),
+/// or convert it with [`into_nlist_rotated`](Self::into_nlist_rotated).
+///
+/// # Example
+///
+/// Computing a moving average
+///
+/// ```rust
+/// use nlist::{Peano, RingBuffer};
+///
+/// let mut window = RingBuffer::<u32, Peano!(3)>::new();
+/// let mut averages = Vec::new();
+///
+/// for reading in [3, 5, 7, 9, 14] {
+///     window.push_overwrite(reading);
+///     averages.push(window.iter().sum::<u32>() / window.len() as u32);
+/// }
+///
+/// assert_eq!(averages, [3, 4, 5, 7, 10]);
+///
+/// ```
+pub struct RingBuffer<T, Cap: PeanoInt> {
+    slots: NList<Option<T>, Cap>,
+    // index of the oldest element
+    head: usize,
+    len: usize,
+}
+
+impl<T, Cap: PeanoInt> RingBuffer<T, Cap> {
+    /// Constructs an empty `RingBuffer`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{Peano, RingBuffer};
+    ///
+    /// const EMPTY: RingBuffer<u8, Peano!(4)> = RingBuffer::new();
+    ///
+    /// assert!(EMPTY.is_empty());
+    /// assert_eq!(EMPTY.capacity(), 4);
+    ///
+    /// ```
+    pub const fn new() -> Self {
+        Self { slots: none_list(), head: 0, len: 0 }
+    }
+
+    /// Returns the maximum amount of elements that this `RingBuffer` can hold,
+    /// which is always `Cap::USIZE`.
+    pub const fn capacity(&self) -> usize {
+        Cap::USIZE
+    }
+
+    /// Returns the amount of elements in this `RingBuffer`.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether this `RingBuffer` holds no elements.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Whether this `RingBuffer` holds `Cap::USIZE` elements.
+    pub const fn is_full(&self) -> bool {
+        self.len == Cap::USIZE
+    }
+
+    /// Appends `elem` as the newest element,
+    /// removing and returning the oldest element if this `RingBuffer` is full.
+    ///
+    /// If the capacity is zero, this returns `Some(elem)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{Peano, RingBuffer};
+    ///
+    /// let mut ring = RingBuffer::<u8, Peano!(2)>::new();
+    ///
+    /// assert_eq!(ring.push_overwrite(3), None);
+    /// assert_eq!(ring.push_overwrite(5), None);
+    /// assert_eq!(ring.push_overwrite(8), Some(3));
+    /// assert_eq!(ring.push_overwrite(13), Some(5));
+    ///
+    /// assert_eq!(ring.iter().collect::<Vec<_>>(), [&8, &13]);
+    ///
+    /// ```
+    pub const fn push_overwrite(&mut self, elem: T) -> Option<T> {
+        if Cap::USIZE == 0 {
+            Some(elem)
+        } else if self.is_full() {
+            let oldest = self.slot_mut(self.head).replace(elem);
+            self.head = (self.head + 1) % Cap::USIZE;
+            oldest
+        } else {
+            let tail = (self.head + self.len) % Cap::USIZE;
+            let prev = self.slot_mut(tail).replace(elem);
+
+            // `prev` is always `None`, forgetting it avoids
+            // the "destructor cannot be evaluated at compile-time" error
+            core::mem::forget(prev);
+
+            self.len += 1;
+            None
+        }
+    }
+
+    /// Removes and returns the oldest element,
+    /// returns `None` if this `RingBuffer` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{Peano, RingBuffer};
+    ///
+    /// let mut ring = RingBuffer::<u8, Peano!(2)>::new();
+    /// ring.push_overwrite(3);
+    /// ring.push_overwrite(5);
+    /// ring.push_overwrite(8);
+    ///
+    /// assert_eq!(ring.pop_front(), Some(5));
+    /// assert_eq!(ring.pop_front(), Some(8));
+    /// assert_eq!(ring.pop_front(), None);
+    ///
+    /// ```
+    pub const fn pop_front(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let elem = self.slot_mut(self.head).take();
+        self.head = (self.head + 1) % Cap::USIZE;
+        self.len -= 1;
+        elem
+    }
+
+    /// Returns a reference to the element at `index`, counting from the oldest element,
+    /// returns `None` if `index >= self.len()`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{Peano, RingBuffer};
+    ///
+    /// let mut ring = RingBuffer::<u8, Peano!(2)>::new();
+    /// ring.push_overwrite(3);
+    /// ring.push_overwrite(5);
+    /// ring.push_overwrite(8);
+    ///
+    /// assert_eq!(ring.get(0), Some(&5));
+    /// assert_eq!(ring.get(1), Some(&8));
+    /// assert_eq!(ring.get(2), None);
+    ///
+    /// ```
+    pub const fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None;
+        }
+
+        match self.slots.get((self.head + index) % Cap::USIZE) {
+            Some(slot) => slot.as_ref(),
+            None => None,
+        }
+    }
+
+    /// Returns an iterator over references to the elements,
+    /// from the oldest to the newest.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator + '_ {
+        (0..self.len).map(move |i| self.get(i).unwrap())
+    }
+
+    /// Returns a list of references to the elements, from the oldest to the newest,
+    /// followed by `None`s for the vacant slots.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{Peano, RingBuffer, nlist};
+    ///
+    /// let mut ring = RingBuffer::<u8, Peano!(3)>::new();
+    /// ring.push_overwrite(3);
+    /// ring.push_overwrite(5);
+    ///
+    /// assert_eq!(ring.as_nlist_rotated(), nlist![Some(&3), Some(&5), None]);
+    ///
+    /// ring.push_overwrite(8);
+    /// ring.push_overwrite(13);
+    ///
+    /// assert_eq!(ring.as_nlist_rotated(), nlist![Some(&5), Some(&8), Some(&13)]);
+    ///
+    /// ```
+    pub const fn as_nlist_rotated(&self) -> NList<Option<&T>, Cap> {
+        const fn inner<T, Cap, L>(this: &RingBuffer<T, Cap>, index: usize) -> NList<Option<&T>, L>
+        where
+            Cap: PeanoInt,
+            L: PeanoInt,
+        {
+            match L::PEANO_WIT {
+                PeanoWit::Zero(len_te) => NList::nil_sub(len_te),
+                PeanoWit::PlusOne(len_te) => {
+                    NList::cons_sub(this.get(index), inner(this, index + 1), len_te)
+                }
+            }
+        }
+
+        inner(self, 0)
+    }
+
+    /// Converts this `RingBuffer` into a list of the elements, from the oldest to the newest,
+    /// followed by `None`s for the vacant slots.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{Peano, RingBuffer, nlist};
+    ///
+    /// let mut ring = RingBuffer::<u8, Peano!(3)>::new();
+    /// ring.push_overwrite(3);
+    /// ring.push_overwrite(5);
+    ///
+    /// assert_eq!(ring.into_nlist_rotated(), nlist![Some(3), Some(5), None]);
+    ///
+    /// ```
+    pub const fn into_nlist_rotated(mut self) -> NList<Option<T>, Cap> {
+        const fn inner<T, Cap, L>(this: &mut RingBuffer<T, Cap>) -> NList<Option<T>, L>
+        where
+            Cap: PeanoInt,
+            L: PeanoInt,
+        {
+            match L::PEANO_WIT {
+                PeanoWit::Zero(len_te) => NList::nil_sub(len_te),
+                PeanoWit::PlusOne(len_te) => {
+                    let elem = this.pop_front();
+                    NList::cons_sub(elem, inner(this), len_te)
+                }
+            }
+        }
+
+        let list = inner(&mut self);
+        self.assert_empty_drop();
+        list
+    }
+
+    /// Converts this `RingBuffer` into a list of the elements, from the oldest to the newest,
+    /// returns `None` if this `RingBuffer` is not full.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{Peano, RingBuffer, nlist};
+    ///
+    /// let mut ring = RingBuffer::<u8, Peano!(3)>::new();
+    /// ring.push_overwrite(3);
+    /// ring.push_overwrite(5);
+    ///
+    /// assert_eq!(ring.clone().into_nlist(), None);
+    ///
+    /// ring.push_overwrite(8);
+    /// ring.push_overwrite(13);
+    ///
+    /// assert_eq!(ring.into_nlist(), Some(nlist![5, 8, 13]));
+    ///
+    /// ```
+    pub fn into_nlist(self) -> Option<NList<T, Cap>> {
+        if self.is_full() {
+            self.into_nlist_rotated().try_map_option(|elem| elem)
+        } else {
+            None
+        }
+    }
+
+    /// Helper method for dropping a `RingBuffer` that holds no elements in a const context.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this `RingBuffer` holds any elements.
+    pub const fn assert_empty_drop(self) {
+        if !self.is_empty() {
+            panic!("expected the `RingBuffer` to hold no elements");
+        }
+
+        core::mem::forget(self)
+    }
+
+    const fn slot_mut(&mut self, index: usize) -> &mut Option<T> {
+        match self.slots.get_mut(index) {
+            Some(slot) => slot,
+            None => panic!("index out of bounds"),
+        }
+    }
+}
+
+impl<T, Cap: PeanoInt> Default for RingBuffer<T, Cap> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, Cap> Clone for RingBuffer<T, Cap>
+where
+    T: Clone,
+    Cap: PeanoInt,
+{
+    fn clone(&self) -> Self {
+        Self { slots: self.slots.clone(), head: self.head, len: self.len }
+    }
+}
+
+impl<T, Cap> Debug for RingBuffer<T, Cap>
+where
+    T: Debug,
+    Cap: PeanoInt,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T, U, Cap, Cap2> PartialEq<RingBuffer<U, Cap2>> for RingBuffer<T, Cap>
+where
+    T: PartialEq<U>,
+    Cap: PeanoInt,
+    Cap2: PeanoInt,
+{
+    fn eq(&self, rhs: &RingBuffer<U, Cap2>) -> bool {
+        self.len == rhs.len && self.iter().zip(rhs.iter()).all(|(l, r)| l == r)
+    }
+}
+
+impl<T, Cap> Eq for RingBuffer<T, Cap>
+where
+    T: Eq,
+    Cap: PeanoInt,
+{}
@@ -4,6 +4,8 @@ use typewit::{const_marker::Bool, TypeCmp, TypeEq};
 
 use super::NList;
 use crate::peano::{self, PeanoInt, PeanoWit, PlusOne, SubOneSat, Zero};
+use crate::peano::proofs::{add_sub_cancel, add_sub_le, commutative_add, le_add, sub_sat_le};
+use crate::peano::type_fns::SubSatFn;
 use crate::boolean::{IfTruePI, Boolean};
 
 impl<T, L: PeanoInt> NList<T, L> {
@@ -189,3 +191,51 @@ typewit::type_fn!{
         At: PeanoInt,
         L: PeanoInt,
 }
+
+/// Splits `list` into the elements before the last `Suffix` elements,
+/// and the last `Suffix` elements.
+///
+/// Used by the [`unlist`](crate::unlist) macro for `..` patterns that aren't at the end.
+#[doc(hidden)]
+pub const fn split_suffix<T, L, Suffix>(
+    list: NList<T, L>,
+) -> (NList<T, peano::SubSat<L, Suffix>>, NList<T, Suffix>)
+where
+    L: PeanoInt,
+    Suffix: PeanoInt<IsLe<L> = Bool<true>>,
+{
+    type Prefix<L, Suffix> = peano::SubSat<L, Suffix>;
+
+    // `L == Suffix + (L - Suffix)`
+    let len_te = add_sub_le::<Suffix, L>(TypeEq::NEW).flip();
+
+    // `L - (L - Suffix) == Suffix`
+    let suffix_te = len_te
+        .zip(TypeEq::new::<Prefix<L, Suffix>>())
+        .map(SubSatFn::NEW)
+        .join(add_sub_cancel::<Suffix, Prefix<L, Suffix>>());
+
+    let split = list.split_at_alt::<Prefix<L, Suffix>>(sub_sat_le::<L, Suffix>());
+    destructure!{(prefix, suffix) = split}
+
+    (prefix, suffix.coerce_len(suffix_te))
+}
+
+/// Splits a list that's `A + B` long into its first `A` elements and the remaining `B` elements.
+pub(crate) const fn split_add<T, A, B>(
+    list: NList<T, peano::Add<A, B>>,
+) -> (NList<T, A>, NList<T, B>)
+where
+    A: PeanoInt,
+    B: PeanoInt,
+{
+    // `(A + B) - A == B`
+    let rem_te = commutative_add::<A, B>()
+        .zip(TypeEq::new::<A>())
+        .map(SubSatFn::NEW)
+        .join(add_sub_cancel::<B, A>());
+
+    destructure!{(before, after) = list.split_at_alt::<A>(le_add::<A, B>())}
+
+    (before, after.coerce_len(rem_te))
+}
@@ -0,0 +1,202 @@
+use konst::destructure;
+
+use core::fmt::{self, Debug};
+
+use crate::peano::{self, PeanoInt, PlusOne, Zero};
+
+use super::NList;
+
+
+/// A cursor over an [`NList`] that focuses on one of its elements.
+///
+/// `Before` is the amount of elements before the focused element,
+/// and `After` is the amount of elements after it,
+/// so the focus can only be moved within the bounds of the list.
+///
+/// # Example
+///
+/// ```rust
+/// use nlist::{Zipper, nlist};
+///
+/// let zipper = Zipper::from_nlist(nlist![3, 5, 8, 13]);
+/// assert_eq!(*zipper.focus(), 3);
+///
+/// let mut zipper = zipper.move_right().move_right();
+/// assert_eq!(*zipper.focus(), 8);
+/// assert_eq!(zipper.position(), 2);
+///
+/// *zipper.focus_mut() += 100;
+///
+/// let mut zipper = zipper.move_left();
+/// assert_eq!(zipper.replace(21), 5);
+///
+/// assert_eq!(zipper.into_nlist(), nlist![3, 21, 108, 13]);
+///
+/// ```
+///
+/// Moving the focus out of bounds is a compile-time error:
+///
+/// ```rust,compile_fail
+/// use nlist::{Zipper, nlist};
+///
+/// let zipper = Zipper::from_nlist(nlist![3, 5]);
+///
+/// let _ = zipper.move_right().move_right();
+/// ```
+pub struct Zipper<T, Before: PeanoInt, After: PeanoInt> {
+    // the elements before the focus, nearest first
+    before: NList<T, Before>,
+    focus: T,
+    after: NList<T, After>,
+}
+
+impl<T, L: PeanoInt> Zipper<T, Zero, L> {
+    /// Constructs a `Zipper` that focuses on the first element of `list`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{Zipper, nlist};
+    ///
+    /// let zipper = Zipper::from_nlist(nlist![3, 5, 8]);
+    ///
+    /// assert_eq!(*zipper.focus(), 3);
+    /// assert_eq!(zipper.position(), 0);
+    ///
+    /// ```
+    pub const fn from_nlist(list: NList<T, PlusOne<L>>) -> Self {
+        destructure!{(focus, after) = list.into_split_head()}
+
+        Self { before: NList::nil(), focus, after }
+    }
+}
+
+impl<T, Before, After> Zipper<T, Before, PlusOne<After>>
+where
+    Before: PeanoInt,
+    After: PeanoInt,
+{
+    /// Moves the focus to the next element.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{Zipper, nlist};
+    ///
+    /// let zipper = Zipper::from_nlist(nlist![3, 5, 8]).move_right();
+    ///
+    /// assert_eq!(*zipper.focus(), 5);
+    ///
+    /// ```
+    pub const fn move_right(self) -> Zipper<T, PlusOne<Before>, After> {
+        destructure!{Zipper { before, focus, after } = self}
+        destructure!{(next, after) = after.into_split_head()}
+
+        Zipper { before: NList::cons(focus, before), focus: next, after }
+    }
+}
+
+impl<T, Before, After> Zipper<T, PlusOne<Before>, After>
+where
+    Before: PeanoInt,
+    After: PeanoInt,
+{
+    /// Moves the focus to the previous element.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{Zipper, nlist};
+    ///
+    /// let zipper = Zipper::from_nlist(nlist![3, 5, 8]).move_right().move_right();
+    /// assert_eq!(*zipper.focus(), 8);
+    ///
+    /// let zipper = zipper.move_left();
+    /// assert_eq!(*zipper.focus(), 5);
+    ///
+    /// ```
+    pub const fn move_left(self) -> Zipper<T, Before, PlusOne<After>> {
+        destructure!{Zipper { before, focus, after } = self}
+        destructure!{(prev, before) = before.into_split_head()}
+
+        Zipper { before, focus: prev, after: NList::cons(focus, after) }
+    }
+}
+
+impl<T, Before, After> Zipper<T, Before, After>
+where
+    Before: PeanoInt,
+    After: PeanoInt,
+{
+    /// Returns a reference to the focused element.
+    pub const fn focus(&self) -> &T {
+        &self.focus
+    }
+
+    /// Returns a mutable reference to the focused element.
+    pub const fn focus_mut(&mut self) -> &mut T {
+        &mut self.focus
+    }
+
+    /// Replaces the focused element with `elem`, returning the previously focused element.
+    pub const fn replace(&mut self, elem: T) -> T {
+        core::mem::replace(&mut self.focus, elem)
+    }
+
+    /// Returns the index of the focused element, which is always `Before::USIZE`.
+    pub const fn position(&self) -> usize {
+        Before::USIZE
+    }
+
+    /// Converts this `Zipper` back into a list, with the elements in their original order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{NList, Peano, Zipper, nlist};
+    ///
+    /// const LIST: NList<u8, Peano!(3)> = {
+    ///     let mut zipper = Zipper::from_nlist(nlist![3, 5, 8]).move_right();
+    ///     *zipper.focus_mut() = 0;
+    ///     zipper.into_nlist()
+    /// };
+    ///
+    /// assert_eq!(LIST, nlist![3, 0, 8]);
+    ///
+    /// ```
+    pub const fn into_nlist(self) -> NList<T, peano::Add<Before, PlusOne<After>>> {
+        destructure!{Zipper { before, focus, after } = self}
+
+        before.reverse().concat(NList::cons(focus, after))
+    }
+}
+
+impl<T, Before, After> Clone for Zipper<T, Before, After>
+where
+    T: Clone,
+    Before: PeanoInt,
+    After: PeanoInt,
+{
+    fn clone(&self) -> Self {
+        Self {
+            before: self.before.clone(),
+            focus: self.focus.clone(),
+            after: self.after.clone(),
+        }
+    }
+}
+
+impl<T, Before, After> Debug for Zipper<T, Before, After>
+where
+    T: Debug,
+    Before: PeanoInt,
+    After: PeanoInt,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("Zipper")
+            .field("before", &self.before.each_ref().reverse())
+            .field("focus", &self.focus)
+            .field("after", &self.after)
+            .finish()
+    }
+}
@@ -0,0 +1,585 @@
+//! Sorted fixed-size maps and sets that can be constructed and queried in const.
+//!
+//! # Example
+//!
+//! ```rust
+//! use nlist::{NMap, Peano, nlist};
+//!
+//! const OPCODES: NMap<u8, &str, Peano!(4)> = NMap::from_nlist(nlist![
+//!     (0x90, "nop"),
+//!     (0x01, "add"),
+//!     (0xC3, "ret"),
+//!     (0x29, "sub"),
+//! ]);
+//!
+//! const RET: Option<&&str> = OPCODES.get(&0xC3);
+//!
+//! assert_eq!(RET, Some(&"ret"));
+//! assert_eq!(OPCODES.get(&0x29), Some(&"sub"));
+//! assert_eq!(OPCODES.get(&0x02), None);
+//!
+//! ```
+//!
+//! Duplicate keys cause a compile-time error when the map is constructed in a `const`:
+//!
+//! ```rust,compile_fail
+//! use nlist::{NMap, Peano, nlist};
+//!
+//! const MAP: NMap<u8, &str, Peano!(2)> = NMap::from_nlist(nlist![(1, "foo"), (1, "bar")]);
+//! ```
+
+use core::{
+    cmp::Ordering,
+    fmt::{self, Debug},
+};
+
+use konst::destructure;
+
+use typewit::TypeEq;
+
+use crate::{
+    nlist::NList,
+    peano::{PeanoInt, PeanoWit, PlusOne},
+};
+
+
+/// Types that can be used as the keys of an [`NMap`] or [`NSet`].
+///
+/// Keys are compared with [`MapKeyWit::cmp`],
+/// which has the same behavior as the [`Ord`] impl of the key type.
+pub trait MapKey: Copy + Ord {
+    /// Type witness of which key type `Self` is.
+    const MAP_KEY_WIT: MapKeyWit<Self>;
+}
+
+macro_rules! declare_map_keys {
+    ($(($variant:ident, $ty:ty, $cmp_as:ty))*) => {
+        /// Type witness over the types that implement [`MapKey`].
+        #[non_exhaustive]
+        pub enum MapKeyWit<K> {
+            $(
+                #[doc = concat!("Type witness of `K == ", stringify!($ty), "`")]
+                $variant(TypeEq<K, $ty>),
+            )*
+            /// Type witness of `K == &'static str`
+            Str(TypeEq<K, &'static str>),
+        }
+
+        $(
+            impl MapKey for $ty {
+                const MAP_KEY_WIT: MapKeyWit<Self> = MapKeyWit::$variant(TypeEq::NEW);
+            }
+        )*
+
+        impl<K> MapKeyWit<K> {
+            /// Compares two keys, equivalent to [`Ord::cmp`].
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// use nlist::nmap::{MapKey, MapKeyWit};
+            ///
+            /// use std::cmp::Ordering;
+            ///
+            /// const fn cmp_keys<K: MapKey>(l: &K, r: &K) -> Ordering {
+            ///     K::MAP_KEY_WIT.cmp(l, r)
+            /// }
+            ///
+            /// assert_eq!(cmp_keys(&3u8, &5), Ordering::Less);
+            /// assert_eq!(cmp_keys(&-3i64, &-5), Ordering::Greater);
+            /// assert_eq!(cmp_keys(&"foo", &"foo"), Ordering::Equal);
+            /// assert_eq!(cmp_keys(&"bar", &"baz"), Ordering::Less);
+            ///
+            /// ```
+            pub const fn cmp(self, l: &K, r: &K) -> Ordering {
+                match self {
+                    $(
+                        Self::$variant(te) => cmp_ints!(
+                            *te.in_ref().to_right(l) as $cmp_as,
+                            *te.in_ref().to_right(r) as $cmp_as,
+                        ),
+                    )*
+                    Self::Str(te) => cmp_str(te.in_ref().to_right(l), te.in_ref().to_right(r)),
+                }
+            }
+        }
+    };
+}
+
+macro_rules! cmp_ints {
+    ($l:expr, $r:expr $(,)?) => {{
+        let (l, r) = ($l, $r);
+
+        if l < r {
+            Ordering::Less
+        } else if l > r {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }};
+}
+
+declare_map_keys! {
+    (Bool, bool, u8)
+    (Char, char, u32)
+    (U8, u8, u8)
+    (U16, u16, u16)
+    (U32, u32, u32)
+    (U64, u64, u64)
+    (U128, u128, u128)
+    (Usize, usize, usize)
+    (I8, i8, i8)
+    (I16, i16, i16)
+    (I32, i32, i32)
+    (I64, i64, i64)
+    (I128, i128, i128)
+    (Isize, isize, isize)
+}
+
+impl MapKey for &'static str {
+    const MAP_KEY_WIT: MapKeyWit<Self> = MapKeyWit::Str(TypeEq::NEW);
+}
+
+const fn cmp_str(l: &str, r: &str) -> Ordering {
+    let (l, r) = (l.as_bytes(), r.as_bytes());
+
+    let mut i = 0;
+    while i < l.len() && i < r.len() {
+        if l[i] != r[i] {
+            return cmp_ints!(l[i], r[i]);
+        }
+        i += 1;
+    }
+
+    cmp_ints!(l.len(), r.len())
+}
+
+impl<K> Copy for MapKeyWit<K> {}
+
+impl<K> Clone for MapKeyWit<K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+// binary search over sorted entries,
+// `$cmp` compares the key of an entry (`$mid_key`) with the searched-for key
+macro_rules! binary_search {
+    ($entries:expr, |$mid_key:ident| $cmp:expr) => {{
+        let entries = &$entries;
+        let mut start = 0;
+        let mut end = entries.len();
+        let mut found = None;
+
+        while start < end {
+            let mid = start + (end - start) / 2;
+
+            let Some(($mid_key, _)) = entries.get(mid) else {
+                panic!("BUG: index is out of bounds")
+            };
+
+            match $cmp {
+                Ordering::Less => start = mid + 1,
+                Ordering::Greater => end = mid,
+                Ordering::Equal => {
+                    found = Some(mid);
+                    break
+                }
+            }
+        }
+
+        found
+    }};
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// A map with `L` entries sorted by key, stored inline.
+///
+/// # Const usage
+///
+/// [`from_nlist`](Self::from_nlist) panics on duplicate keys,
+/// which causes a compile-time error when the map is constructed in a `const`.
+///
+/// # Example
+///
+/// ```rust
+/// use nlist::{NMap, Peano, nlist};
+///
+/// const ERRORS: NMap<&str, u16, Peano!(3)> = NMap::from_nlist(nlist![
+///     ("not found", 404),
+///     ("ok", 200),
+///     ("forbidden", 403),
+/// ]);
+///
+/// assert_eq!(ERRORS.get(&"ok"), Some(&200));
+/// assert_eq!(ERRORS.get(&"teapot"), None);
+///
+/// // entries are sorted by key
+/// assert_eq!(
+///     ERRORS.into_nlist(),
+///     nlist![("forbidden", 403), ("not found", 404), ("ok", 200)],
+/// );
+///
+/// ```
+pub struct NMap<K: MapKey, V, L: PeanoInt> {
+    entries: NList<(K, V), L>,
+}
+
+impl<K: MapKey, V, L: PeanoInt> NMap<K, V, L> {
+    /// Constructs a map from a list of key-value pairs, sorting them by key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any key is repeated.
+    pub const fn from_nlist(entries: NList<(K, V), L>) -> Self {
+        let entries = sort_entries(entries);
+
+        assert_unique_keys(&entries, None);
+
+        Self { entries }
+    }
+
+    /// Returns the amount of entries in the map, which is always `L::USIZE`.
+    pub const fn len(&self) -> usize {
+        L::USIZE
+    }
+
+    /// Whether the map has no entries.
+    pub const fn is_empty(&self) -> bool {
+        L::USIZE == 0
+    }
+
+    /// Gets the key-value pair for `key`, returns `None` if it's not in the map.
+    pub const fn get_key_value(&self, key: &K) -> Option<&(K, V)> {
+        match self.index_of(key) {
+            Some(index) => self.entries.get(index),
+            None => None,
+        }
+    }
+
+    /// Gets the value for `key`, returns `None` if it's not in the map.
+    pub const fn get(&self, key: &K) -> Option<&V> {
+        match self.get_key_value(key) {
+            Some((_, value)) => Some(value),
+            None => None,
+        }
+    }
+
+    /// Gets a mutable reference to the value for `key`,
+    /// returns `None` if it's not in the map.
+    pub const fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        match self.index_of(key) {
+            Some(index) => match self.entries.get_mut(index) {
+                Some((_, value)) => Some(value),
+                None => None,
+            },
+            None => None,
+        }
+    }
+
+    /// Whether `key` is in the map.
+    pub const fn contains_key(&self, key: &K) -> bool {
+        self.index_of(key).is_some()
+    }
+
+    /// Gets the entries of the map, sorted by key.
+    pub const fn as_nlist(&self) -> &NList<(K, V), L> {
+        &self.entries
+    }
+
+    /// Converts the map into its entries, sorted by key.
+    pub const fn into_nlist(self) -> NList<(K, V), L> {
+        destructure!{NMap { entries } = self}
+
+        entries
+    }
+
+    const fn index_of(&self, key: &K) -> Option<usize> {
+        binary_search!(self.entries, |mid_key| K::MAP_KEY_WIT.cmp(mid_key, key))
+    }
+}
+
+impl<V, L: PeanoInt> NMap<&'static str, V, L> {
+    /// Gets the value for `key`, returns `None` if it's not in the map.
+    ///
+    /// Unlike [`get`](Self::get), this takes a `&str` of any lifetime,
+    /// so it can be used to look up keys borrowed from a `String`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{NMap, Peano, nlist};
+    ///
+    /// const COLORS: NMap<&str, u32, Peano!(3)> = NMap::from_nlist(nlist![
+    ///     ("red", 0xFF0000),
+    ///     ("green", 0x00FF00),
+    ///     ("blue", 0x0000FF),
+    /// ]);
+    ///
+    /// let key = String::from("green");
+    ///
+    /// assert_eq!(COLORS.get_str(&key), Some(&0x00FF00));
+    /// assert_eq!(COLORS.get_str("yellow"), None);
+    ///
+    /// ```
+    pub const fn get_str(&self, key: &str) -> Option<&V> {
+        match self.index_of_str(key) {
+            Some(index) => match self.entries.get(index) {
+                Some((_, value)) => Some(value),
+                None => None,
+            },
+            None => None,
+        }
+    }
+
+    /// Whether `key` is in the map.
+    ///
+    /// Unlike [`contains_key`](Self::contains_key), this takes a `&str` of any lifetime.
+    pub const fn contains_key_str(&self, key: &str) -> bool {
+        self.index_of_str(key).is_some()
+    }
+
+    const fn index_of_str(&self, key: &str) -> Option<usize> {
+        binary_search!(self.entries, |mid_key| cmp_str(mid_key, key))
+    }
+}
+
+// sorts the entries by key, by repeatedly taking out the entry with the smallest key
+const fn sort_entries<K, V, L>(entries: NList<(K, V), L>) -> NList<(K, V), L>
+where
+    K: MapKey,
+    L: PeanoInt,
+{
+    match L::PEANO_WIT {
+        PeanoWit::Zero(_) => entries,
+        PeanoWit::PlusOne(len_te) => {
+            destructure!{(min, rest) = take_min(entries.coerce_len(len_te))}
+
+            NList::cons_sub(min, sort_entries(rest), len_te)
+        }
+    }
+}
+
+// returns the entry with the smallest key, and the other entries
+const fn take_min<K, V, L>(entries: NList<(K, V), PlusOne<L>>) -> ((K, V), NList<(K, V), L>)
+where
+    K: MapKey,
+    L: PeanoInt,
+{
+    destructure!{(head, next) = entries.into_split_head()}
+
+    match L::PEANO_WIT {
+        PeanoWit::Zero(_) => (head, next),
+        PeanoWit::PlusOne(len_te) => {
+            destructure!{(min, rest) = take_min(next.coerce_len(len_te))}
+
+            if let Ordering::Less = K::MAP_KEY_WIT.cmp(&head.0, &min.0) {
+                (head, NList::cons_sub(min, rest, len_te))
+            } else {
+                (min, NList::cons_sub(head, rest, len_te))
+            }
+        }
+    }
+}
+
+// panics if any entry has the same key as the entry before it,
+// `prev` is the key of the entry before `entries`
+const fn assert_unique_keys<K, V, L>(entries: &NList<(K, V), L>, prev: Option<&K>)
+where
+    K: MapKey,
+    L: PeanoInt,
+{
+    if let PeanoWit::PlusOne(len_te) = L::PEANO_WIT {
+        let ((key, _), next) = entries.as_coerce_len(len_te).split_head();
+
+        if let Some(prev) = prev {
+            if let Ordering::Equal = K::MAP_KEY_WIT.cmp(prev, key) {
+                panic!("found duplicate key");
+            }
+        }
+
+        assert_unique_keys(next, Some(key))
+    }
+}
+
+impl<K, V, L> Clone for NMap<K, V, L>
+where
+    K: MapKey,
+    V: Clone,
+    L: PeanoInt,
+{
+    fn clone(&self) -> Self {
+        Self { entries: self.entries.clone() }
+    }
+}
+
+impl<K, V, L> Debug for NMap<K, V, L>
+where
+    K: MapKey + Debug,
+    V: Debug,
+    L: PeanoInt,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut fmt = fmt.debug_map();
+
+        self.entries.each_ref().for_each(|_, (key, value)| {
+            _ = fmt.entry(key, value);
+        });
+
+        fmt.finish()
+    }
+}
+
+impl<K, V, L> PartialEq for NMap<K, V, L>
+where
+    K: MapKey,
+    V: PartialEq,
+    L: PeanoInt,
+{
+    fn eq(&self, rhs: &Self) -> bool {
+        self.entries == rhs.entries
+    }
+}
+
+impl<K, V, L> Eq for NMap<K, V, L>
+where
+    K: MapKey,
+    V: Eq,
+    L: PeanoInt,
+{}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// A set with `L` sorted keys, stored inline.
+///
+/// # Const usage
+///
+/// [`from_nlist`](Self::from_nlist) panics on duplicate keys,
+/// which causes a compile-time error when the set is constructed in a `const`.
+///
+/// # Example
+///
+/// ```rust
+/// use nlist::{NSet, Peano, nlist};
+///
+/// const KEYWORDS: NSet<&str, Peano!(4)> = NSet::from_nlist(nlist!["let", "fn", "if", "else"]);
+///
+/// const HAS_FN: bool = KEYWORDS.contains(&"fn");
+///
+/// assert!(HAS_FN);
+/// assert!(!KEYWORDS.contains(&"struct"));
+///
+/// assert_eq!(KEYWORDS.to_nlist(), nlist!["else", "fn", "if", "let"]);
+///
+/// ```
+pub struct NSet<K: MapKey, L: PeanoInt> {
+    map: NMap<K, (), L>,
+}
+
+impl<K: MapKey, L: PeanoInt> NSet<K, L> {
+    /// Constructs a set from a list of keys, sorting them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any key is repeated.
+    pub const fn from_nlist(keys: NList<K, L>) -> Self {
+        const fn inner<K: MapKey, L: PeanoInt>(keys: &NList<K, L>) -> NList<(K, ()), L> {
+            match L::PEANO_WIT {
+                PeanoWit::Zero(len_te) => NList::nil_sub(len_te),
+                PeanoWit::PlusOne(len_te) => {
+                    let (key, next) = keys.as_coerce_len(len_te).split_head();
+                    NList::cons_sub((*key, ()), inner(next), len_te)
+                }
+            }
+        }
+
+        let entries = inner(&keys);
+        keys.assert_copy_drop();
+
+        Self { map: NMap::from_nlist(entries) }
+    }
+
+    /// Returns the amount of keys in the set, which is always `L::USIZE`.
+    pub const fn len(&self) -> usize {
+        L::USIZE
+    }
+
+    /// Whether the set has no keys.
+    pub const fn is_empty(&self) -> bool {
+        L::USIZE == 0
+    }
+
+    /// Whether `key` is in the set.
+    pub const fn contains(&self, key: &K) -> bool {
+        self.map.contains_key(key)
+    }
+
+    /// Returns the keys of the set, sorted.
+    pub const fn to_nlist(&self) -> NList<K, L> {
+        const fn inner<K: MapKey, L: PeanoInt>(entries: &NList<(K, ()), L>) -> NList<K, L> {
+            match L::PEANO_WIT {
+                PeanoWit::Zero(len_te) => NList::nil_sub(len_te),
+                PeanoWit::PlusOne(len_te) => {
+                    let ((key, ()), next) = entries.as_coerce_len(len_te).split_head();
+                    NList::cons_sub(*key, inner(next), len_te)
+                }
+            }
+        }
+
+        inner(self.map.as_nlist())
+    }
+}
+
+impl<L: PeanoInt> NSet<&'static str, L> {
+    /// Whether `key` is in the set.
+    ///
+    /// Unlike [`contains`](Self::contains), this takes a `&str` of any lifetime,
+    /// so it can be used to look up keys borrowed from a `String`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nlist::{NSet, Peano, nlist};
+    ///
+    /// const SET: NSet<&str, Peano!(3)> = NSet::from_nlist(nlist!["foo", "bar", "baz"]);
+    ///
+    /// assert!(SET.contains_str(&String::from("bar")));
+    /// assert!(!SET.contains_str("qux"));
+    ///
+    /// ```
+    pub const fn contains_str(&self, key: &str) -> bool {
+        self.map.contains_key_str(key)
+    }
+}
+
+impl<K: MapKey, L: PeanoInt> Clone for NSet<K, L> {
+    fn clone(&self) -> Self {
+        Self { map: self.map.clone() }
+    }
+}
+
+impl<K, L> Debug for NSet<K, L>
+where
+    K: MapKey + Debug,
+    L: PeanoInt,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut fmt = fmt.debug_set();
+
+        self.map.as_nlist().each_ref().for_each(|_, (key, ())| {
+            _ = fmt.entry(key);
+        });
+
+        fmt.finish()
+    }
+}
+
+impl<K: MapKey, L: PeanoInt> PartialEq for NSet<K, L> {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.map == rhs.map
+    }
+}
+
+impl<K: MapKey, L: PeanoInt> Eq for NSet<K, L> {}
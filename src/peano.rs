@@ -49,7 +49,9 @@ use core::{
 use typewit::{TypeCmp, TypeEq, TypeNe};
 
 
-use crate::boolean::{Bool, BoolWitG, Boolean, And, Not};
+use crate::boolean::{Bool, Boolean, And, Not};
+
+use crate::nlist::BitWords;
 
 ///////////////////////////////////////////////////////////////////////////////
 
@@ -144,6 +146,24 @@ pub type IsLe<Lhs, Rhs> = <Lhs as PeanoInt>::IsLe<Rhs>;
 /// Type alias form of [`PeanoInt::IsLt`]
 pub type IsLt<Lhs, Rhs> = <Lhs as PeanoInt>::IsLt<Rhs>;
 
+/// Type alias form of [`PeanoInt::IsEq`]
+pub type IsEq<Lhs, Rhs> = <Lhs as PeanoInt>::IsEq<Rhs>;
+
+/// Type alias form of [`PeanoInt::IsGt`]
+pub type IsGt<Lhs, Rhs> = <Lhs as PeanoInt>::IsGt<Rhs>;
+
+/// Type alias form of [`PeanoInt::IsGe`]
+pub type IsGe<Lhs, Rhs> = <Lhs as PeanoInt>::IsGe<Rhs>;
+
+/// Type alias form of [`PeanoInt::IsEven`]
+pub type IsEven<Lhs> = <Lhs as PeanoInt>::IsEven;
+
+/// Type alias form of [`PeanoInt::IsOdd`]
+pub type IsOdd<Lhs> = <Lhs as PeanoInt>::IsOdd;
+
+/// Type alias form of [`PeanoInt::AbsDiff`]
+pub type AbsDiff<Lhs, Rhs> = <Lhs as PeanoInt>::AbsDiff<Rhs>;
+
 
 
 /// Trait for a type-level unary encoding of unsigned integers.
@@ -209,7 +229,7 @@ pub trait PeanoInt:
     Sized + Copy + Default + Hash + Sync + Send +
     Eq + Ord + PartialEq + PartialEq<usize> + PartialOrd + PartialOrd<usize> +
     fmt::Binary + fmt::Debug + fmt::Display + fmt::LowerHex + fmt::Octal + fmt::UpperHex +
-    'static 
+    BitWords + 'static 
 {
     /// Type level equivalent of `.saturating_sub(1)`
     /// 
@@ -393,6 +413,138 @@ pub trait PeanoInt:
     /// ```
     type IsLe<Rhs: PeanoInt>: Boolean;
 
+    /// Whether `Self == Rhs`
+    /// 
+    /// [`proofs::is_eq_to_eq`] converts a proof of 
+    /// `IsEq<Self, Rhs> == Bool<true>` into a proof of `Self == Rhs`.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use nlist::{Peano, peano};
+    /// use nlist::boolean::Bool;
+    /// 
+    /// let _: peano::IsEq<Peano!(0), Peano!(0)> = Bool::<true>;
+    /// let _: peano::IsEq<Peano!(0), Peano!(1)> = Bool::<false>;
+    /// 
+    /// let _: peano::IsEq<Peano!(1), Peano!(0)> = Bool::<false>;
+    /// let _: peano::IsEq<Peano!(1), Peano!(1)> = Bool::<true>;
+    /// let _: peano::IsEq<Peano!(1), Peano!(2)> = Bool::<false>;
+    /// 
+    /// let _: peano::IsEq<Peano!(2), Peano!(1)> = Bool::<false>;
+    /// let _: peano::IsEq<Peano!(2), Peano!(2)> = Bool::<true>;
+    /// 
+    /// ```
+    type IsEq<Rhs: PeanoInt>: Boolean;
+
+    /// Whether `Self > Rhs`
+    /// 
+    /// [`proofs::gt_is_flipped_lt`] proves that this is equivalent to 
+    /// [`IsLt<Rhs, Self>`](IsLt).
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use nlist::{Peano, peano};
+    /// use nlist::boolean::Bool;
+    /// 
+    /// let _: peano::IsGt<Peano!(0), Peano!(0)> = Bool::<false>;
+    /// let _: peano::IsGt<Peano!(0), Peano!(1)> = Bool::<false>;
+    /// 
+    /// let _: peano::IsGt<Peano!(1), Peano!(0)> = Bool::<true>;
+    /// let _: peano::IsGt<Peano!(1), Peano!(1)> = Bool::<false>;
+    /// let _: peano::IsGt<Peano!(1), Peano!(2)> = Bool::<false>;
+    /// 
+    /// let _: peano::IsGt<Peano!(2), Peano!(1)> = Bool::<true>;
+    /// let _: peano::IsGt<Peano!(2), Peano!(2)> = Bool::<false>;
+    /// 
+    /// ```
+    type IsGt<Rhs: PeanoInt>: Boolean;
+
+    /// Whether `Self >= Rhs`
+    /// 
+    /// [`proofs::ge_is_flipped_le`] proves that this is equivalent to 
+    /// [`IsLe<Rhs, Self>`](IsLe).
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use nlist::{Peano, peano};
+    /// use nlist::boolean::Bool;
+    /// 
+    /// let _: peano::IsGe<Peano!(0), Peano!(0)> = Bool::<true>;
+    /// let _: peano::IsGe<Peano!(0), Peano!(1)> = Bool::<false>;
+    /// 
+    /// let _: peano::IsGe<Peano!(1), Peano!(0)> = Bool::<true>;
+    /// let _: peano::IsGe<Peano!(1), Peano!(1)> = Bool::<true>;
+    /// let _: peano::IsGe<Peano!(1), Peano!(2)> = Bool::<false>;
+    /// 
+    /// let _: peano::IsGe<Peano!(2), Peano!(1)> = Bool::<true>;
+    /// let _: peano::IsGe<Peano!(2), Peano!(2)> = Bool::<true>;
+    /// 
+    /// ```
+    type IsGe<Rhs: PeanoInt>: Boolean;
+
+    /// Whether `Self` is even
+    /// 
+    /// [`proofs::even_wit`] converts a proof of `IsEven<Self> == Bool<true>`
+    /// into an [`EvenWit`](proofs::EvenWit).
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use nlist::{Peano, peano};
+    /// use nlist::boolean::Bool;
+    /// 
+    /// let _: peano::IsEven<Peano!(0)> = Bool::<true>;
+    /// let _: peano::IsEven<Peano!(1)> = Bool::<false>;
+    /// let _: peano::IsEven<Peano!(2)> = Bool::<true>;
+    /// let _: peano::IsEven<Peano!(3)> = Bool::<false>;
+    /// 
+    /// ```
+    type IsEven: Boolean;
+
+    /// Whether `Self` is odd
+    /// 
+    /// [`proofs::odd_wit`] converts a proof of `IsOdd<Self> == Bool<true>`
+    /// into a proof that `Self` is an even number plus one.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use nlist::{Peano, peano};
+    /// use nlist::boolean::Bool;
+    /// 
+    /// let _: peano::IsOdd<Peano!(0)> = Bool::<false>;
+    /// let _: peano::IsOdd<Peano!(1)> = Bool::<true>;
+    /// let _: peano::IsOdd<Peano!(2)> = Bool::<false>;
+    /// let _: peano::IsOdd<Peano!(3)> = Bool::<true>;
+    /// 
+    /// ```
+    type IsOdd: Boolean;
+
+    /// Computes the absolute difference between `Self` and `Rhs`
+    /// 
+    /// [`proofs::abs_diff_of_le`] proves that this is equal to 
+    /// `SubSat<Rhs, Self>` when `Self <= Rhs`.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use nlist::{PeanoInt, Peano, peano};
+    /// 
+    /// assert_eq!(peano::AbsDiff::<Peano!(0), Peano!(0)>::NEW, 0);
+    /// assert_eq!(peano::AbsDiff::<Peano!(0), Peano!(2)>::NEW, 2);
+    /// 
+    /// assert_eq!(peano::AbsDiff::<Peano!(1), Peano!(0)>::NEW, 1);
+    /// assert_eq!(peano::AbsDiff::<Peano!(1), Peano!(3)>::NEW, 2);
+    /// 
+    /// assert_eq!(peano::AbsDiff::<Peano!(3), Peano!(1)>::NEW, 2);
+    /// assert_eq!(peano::AbsDiff::<Peano!(3), Peano!(3)>::NEW, 0);
+    /// 
+    /// ```
+    type AbsDiff<Rhs: PeanoInt>: PeanoInt;
+
     /// Constructs this type
     /// 
     /// # Example
@@ -447,6 +599,18 @@ impl PeanoInt for Zero {
     
     type IsLe<Rhs: PeanoInt> = Bool<true>;
 
+    type IsEq<Rhs: PeanoInt> = Rhs::IsZero;
+
+    type IsGt<Rhs: PeanoInt> = Rhs::IsLt<Self>;
+
+    type IsGe<Rhs: PeanoInt> = Rhs::IsLe<Self>;
+
+    type IsEven = Bool<true>;
+
+    type IsOdd = Bool<false>;
+
+    type AbsDiff<Rhs: PeanoInt> = Rhs;
+
     const NEW: Self = Zero;
 
     const USIZE: usize = 0;
@@ -479,6 +643,18 @@ where
 
     type IsLe<Rhs: PeanoInt> = And<Not<Rhs::IsZero>, T::IsLe<Rhs::SubOneSat>>;
 
+    type IsEq<Rhs: PeanoInt> = And<Not<Rhs::IsZero>, T::IsEq<Rhs::SubOneSat>>;
+
+    type IsGt<Rhs: PeanoInt> = Rhs::IsLt<Self>;
+
+    type IsGe<Rhs: PeanoInt> = Rhs::IsLe<Self>;
+
+    type IsEven = T::IsOdd;
+
+    type IsOdd = T::IsEven;
+
+    type AbsDiff<Rhs: PeanoInt> = IfZeroPI<Rhs, Self, T::AbsDiff<Rhs::SubOneSat>>;
+
     const NEW: Self = PlusOne { sub_one: T::NEW };
 
     const USIZE: usize = 1 + T::USIZE;
@@ -562,14 +738,6 @@ const fn zero_one_inequality<L: PeanoInt>() -> TypeNe<Zero, PlusOne<L>> {
 
 
 
-typewit::inj_type_fn! {
-    struct PlusOneFn;
-
-    impl<L: PeanoInt> L => PlusOne<L>;
-}
-
-
-
 /// Diverges when given a proof of `PlusOne<L> == Zero`
 /// (which is a contradiction, because they're different types).
 pub const fn contradiction<L>(length_te: TypeEq<PlusOne<L>, Zero>) -> ! {
@@ -3,6 +3,9 @@
 //! These properties are useful in generic contexts, where the compiler does no
 //! reasoning WRT the arithmetic properties of [`PeanoInt`]s. 
 //! 
+//! Equalities that hold for all [`PeanoInt`]s can be proven 
+//! with the [`induction`] function, by implementing [`InductiveEq`].
+//! 
 //! # Alternative
 //! 
 //! An easier approach is to use [`peano::eq`]`::<foo, bar>().unwrap_eq()`,
@@ -51,8 +54,182 @@
 //! ```
 //! [`peano::eq`]: crate::peano::eq
 
+// proofs are all about spelling out complex types
+#![allow(clippy::type_complexity)]
+
 use super::*;
 
+use typewit::{CallFn, TypeFn};
+
+
+/// A proof by induction that 
+/// [`Lhs<N>`](InductiveEq::Lhs) == [`Rhs<N>`](InductiveEq::Rhs)
+/// for every `N: PeanoInt`.
+/// 
+/// This is consumed by [`induction`],
+/// which uses it to construct a proof for any specific `N`.
+/// 
+/// # Example
+/// 
+/// Proving that `N * 1 == N`
+/// 
+/// ```rust
+/// use nlist::peano::{self, PeanoInt, PlusOne, PlusOneFn, Zero};
+/// use nlist::peano::proofs::{self, InductionStep, InductiveEq};
+/// use nlist::typewit::TypeEq;
+/// 
+/// const fn mul_one<N: PeanoInt>() -> TypeEq<peano::Mul<N, PlusOne<Zero>>, N> {
+///     proofs::induction::<MulOne, N>()
+/// }
+/// 
+/// struct MulOne;
+/// 
+/// impl InductiveEq for MulOne {
+///     type Lhs<N: PeanoInt> = peano::Mul<N, PlusOne<Zero>>;
+///     type Rhs<N: PeanoInt> = N;
+/// 
+///     // `0 * 1` evaluates to `0`
+///     const BASE: TypeEq<Zero, Zero> = TypeEq::NEW;
+/// 
+///     type Step<N: PeanoInt> = Self;
+/// }
+/// 
+/// impl<N: PeanoInt> InductionStep<N, MulOne> for MulOne {
+///     type Map = PlusOneFn;
+///     
+///     // `(N + 1) * 1` evaluates to `N * 1 + 1`
+///     const LHS_STEP: TypeEq<
+///         peano::Add<peano::Mul<N, PlusOne<Zero>>, PlusOne<Zero>>, 
+///         PlusOne<peano::Mul<N, PlusOne<Zero>>>,
+///     > = proofs::add_plus_one::<peano::Mul<N, PlusOne<Zero>>, Zero>()
+///         .join(proofs::add_identity::<peano::Mul<N, PlusOne<Zero>>>().map(PlusOneFn));
+/// 
+///     const RHS_STEP: TypeEq<PlusOne<N>, PlusOne<N>> = TypeEq::NEW;
+/// }
+/// 
+/// ```
+pub trait InductiveEq {
+    /// The left-hand side of the equality, as a function of `N`
+    type Lhs<N: PeanoInt>: ?Sized;
+
+    /// The right-hand side of the equality, as a function of `N`
+    type Rhs<N: PeanoInt>: ?Sized;
+
+    /// The base case, proof that `Lhs<0> == Rhs<0>`
+    const BASE: TypeEq<Self::Lhs<Zero>, Self::Rhs<Zero>>;
+
+    /// The inductive step from `N` to `N + 1`.
+    /// 
+    /// This is usually `Self`, 
+    /// with [`InductionStep`] implemented for all `N`.
+    type Step<N: PeanoInt>: InductionStep<N, Self>;
+}
+
+/// The inductive step of the `P` proof, 
+/// going from `Lhs<N> == Rhs<N>` to `Lhs<N + 1> == Rhs<N + 1>`.
+/// 
+/// The step is expressed as the [`Map`](Self::Map) type-level function,
+/// which is applied to both sides of `Lhs<N> == Rhs<N>`,
+/// then rewritten into `Lhs<N + 1> == Rhs<N + 1>` with the 
+/// [`LHS_STEP`](Self::LHS_STEP) and [`RHS_STEP`](Self::RHS_STEP) proofs.
+/// 
+/// # Example
+/// 
+/// Examples of implementing this trait are in [`InductiveEq`]
+pub trait InductionStep<N: PeanoInt, P: InductiveEq + ?Sized> {
+    /// Type-level function applied to both sides of `Lhs<N> == Rhs<N>`
+    type Map: TypeFn<P::Lhs<N>> + TypeFn<P::Rhs<N>>;
+
+    /// Proof that `Lhs<N + 1> == Map(Lhs<N>)`
+    const LHS_STEP: TypeEq<P::Lhs<PlusOne<N>>, CallFn<Self::Map, P::Lhs<N>>>;
+
+    /// Proof that `Rhs<N + 1> == Map(Rhs<N>)`
+    const RHS_STEP: TypeEq<P::Rhs<PlusOne<N>>, CallFn<Self::Map, P::Rhs<N>>>;
+}
+
+/// Proves that `P::Lhs<L> == P::Rhs<L>` by induction over `L`.
+/// 
+/// # Example
+/// 
+/// Examples of using this function are in [`InductiveEq`]
+pub const fn induction<P, L>() -> TypeEq<P::Lhs<L>, P::Rhs<L>>
+where
+    P: InductiveEq + ?Sized,
+    L: PeanoInt,
+{
+    typewit::type_fn! {
+        struct LhsFn<P: ?Sized + InductiveEq>;
+        impl<N: PeanoInt> N => P::Lhs<N>
+    }
+    typewit::type_fn! {
+        struct RhsFn<P: ?Sized + InductiveEq>;
+        impl<N: PeanoInt> N => P::Rhs<N>
+    }
+
+    match L::PEANO_WIT {
+        PeanoWit::Zero(l_te) => l_te.project::<LhsFn<P>>()
+            .join(P::BASE)
+            .join(l_te.project::<RhsFn<P>>().flip()),
+        PeanoWit::PlusOne(l_te) => {
+            type Pred<L> = <L as PeanoInt>::SubOneSat;
+
+            let prev = induction::<P, Pred<L>>();
+
+            l_te.project::<LhsFn<P>>()
+                .join(<P::Step<Pred<L>> as InductionStep<Pred<L>, P>>::LHS_STEP)
+                .join(prev.project::<<P::Step<Pred<L>> as InductionStep<Pred<L>, P>>::Map>())
+                .join(<P::Step<Pred<L>> as InductionStep<Pred<L>, P>>::RHS_STEP.flip())
+                .join(l_te.project::<RhsFn<P>>().flip())
+        }
+    }
+}
+
+// declares an `InductiveEq` impl where the step is the same for all `N`
+macro_rules! declare_inductive_eq {
+    (
+        $(#[$attr:meta])*
+        struct $name:ident $(<$($gen:ident),*>)?;
+
+        lhs<$n:ident> = $lhs:ty;
+        rhs<$n2:ident> = $rhs:ty;
+
+        base = $base:expr;
+        map = $map:ty;
+        lhs_step = $lhs_step:expr;
+        rhs_step = $rhs_step:expr;
+    ) => {
+        $(#[$attr])*
+        struct $name $(<$($gen),*>)? (PhantomData<($($($gen,)*)?)>);
+
+        impl $(<$($gen: PeanoInt),*>)? InductiveEq for $name $(<$($gen),*>)? {
+            type Lhs<$n: PeanoInt> = $lhs;
+            type Rhs<$n2: PeanoInt> = $rhs;
+
+            const BASE: TypeEq<Self::Lhs<Zero>, Self::Rhs<Zero>> = $base;
+
+            type Step<N: PeanoInt> = Self;
+        }
+
+        impl<$n: PeanoInt $($(, $gen: PeanoInt)*)?> InductionStep<$n, Self> 
+        for $name $(<$($gen),*>)? 
+        {
+            type Map = $map;
+
+            const LHS_STEP: TypeEq<<Self as InductiveEq>::Lhs<PlusOne<$n>>, CallFn<$map, <Self as InductiveEq>::Lhs<$n>>> = 
+                $lhs_step;
+
+            const RHS_STEP: TypeEq<<Self as InductiveEq>::Rhs<PlusOne<$n>>, CallFn<$map, <Self as InductiveEq>::Rhs<$n>>> = 
+                $rhs_step;
+        }
+    };
+}
+
+// Type-level function from `L` to `L + R`
+typewit::type_fn! {
+    struct AddRhsFn<R: PeanoInt>;
+    impl<L: PeanoInt> L => Add<L, R>
+}
+
 
 /// Proof that `L + R` == `R + L`
 pub const fn commutative_add<L, R>() -> TypeEq<Add<L, R>, Add<R, L>>
@@ -60,10 +237,19 @@ where
     L: PeanoInt,
     R: PeanoInt,
 {
-    // axiom
-    const {
-        super::eq::<Add<L, R>, Add<R, L>>().unwrap_eq()        
+    declare_inductive_eq! {
+        struct CommutativeAdd<R>;
+
+        lhs<N> = Add<N, R>;
+        rhs<N> = Add<R, N>;
+
+        base = add_identity::<R>().flip();
+        map = PlusOneFn;
+        lhs_step = TypeEq::NEW;
+        rhs_step = add_plus_one::<R, N>();
     }
+
+    induction::<CommutativeAdd<R>, L>()
 }
 
 /// Proof that `L * R` == `R * L`
@@ -72,10 +258,19 @@ where
     L: PeanoInt,
     R: PeanoInt,
 {
-    // axiom
-    const {
-        super::eq::<Mul<L, R>, Mul<R, L>>().unwrap_eq()        
+    declare_inductive_eq! {
+        struct CommutativeMul<R>;
+
+        lhs<N> = Mul<N, R>;
+        rhs<N> = Mul<R, N>;
+
+        base = mul_zero::<R>().flip();
+        map = AddRhsFn<R>;
+        lhs_step = TypeEq::NEW;
+        rhs_step = mul_plus_one::<R, N>();
     }
+
+    induction::<CommutativeMul<R>, L>()
 }
 
 /// Proof that `L + 0` == `L`
@@ -83,10 +278,19 @@ pub const fn add_identity<L>() -> TypeEq<Add<L, Zero>, L>
 where
     L: PeanoInt,
 {
-    // axiom
-    const {
-        super::eq::<Add<L, Zero>, L>().unwrap_eq()        
+    declare_inductive_eq! {
+        struct AddIdentity;
+
+        lhs<N> = Add<N, Zero>;
+        rhs<N> = N;
+
+        base = TypeEq::NEW;
+        map = PlusOneFn;
+        lhs_step = TypeEq::NEW;
+        rhs_step = TypeEq::NEW;
     }
+
+    induction::<AddIdentity, L>()
 }
 
 /// Proof that `SubSat<L, 0>` == `L`
@@ -94,28 +298,1142 @@ pub const fn sub_identity<L>() -> TypeEq<SubSat<L, Zero>, L>
 where
     L: PeanoInt,
 {
-    // axiom
-    const {
-        super::eq::<SubSat<L, Zero>, L>().unwrap_eq()        
+    typewit::type_fn! {
+        struct LhsFn;
+        impl<L: PeanoInt> L => SubSat<L, Zero>
+    }
+
+    // no induction necessary, `SubSat<L, 0>` evaluates to `L` 
+    // once `L` is known to be either `0` or `L' + 1`
+    match L::PEANO_WIT {
+        PeanoWit::Zero(l_te) => l_te.map(LhsFn).join(l_te.flip()),
+        PeanoWit::PlusOne(l_te) => l_te.map(LhsFn).join(l_te.flip()),
     }
 }
 
 /// Proof that, if A < C, then`SubSat<A, B> < C`
 /// 
 pub const fn compose_sub_lt<A, B, C>(
-    _a_is_lt_b: TypeEq<IsLt<A, C>, Bool<true>>
+    a_is_lt_c: TypeEq<IsLt<A, C>, Bool<true>>
 ) -> TypeEq<IsLt<SubSat<A, B>, C>, Bool<true>>
 where
     A: PeanoInt,
     B: PeanoInt,
     C: PeanoInt,
 {
-    // not in a const block because if this function is monomorphized
-    // in a context where A >= C, it could cause a compilation error.
-    match Boolean::BOOL_WIT {
-        BoolWitG::True(x) => x,
-        BoolWitG::False(_) => panic!("axiom"),
+    le_lt_trans::<SubSat<A, B>, A, C>(sub_sat_le::<A, B>(), a_is_lt_c)
+}
+
+/// Proof that `A + (B + 1)` == `(A + B) + 1`
+pub const fn add_plus_one<A, B>() -> TypeEq<Add<A, PlusOne<B>>, PlusOne<Add<A, B>>>
+where
+    A: PeanoInt,
+    B: PeanoInt,
+{
+    declare_inductive_eq! {
+        struct AddPlusOne<B>;
+
+        lhs<N> = Add<N, PlusOne<B>>;
+        rhs<N> = PlusOne<Add<N, B>>;
+
+        base = TypeEq::NEW;
+        map = PlusOneFn;
+        lhs_step = TypeEq::NEW;
+        rhs_step = TypeEq::NEW;
+    }
+
+    induction::<AddPlusOne<B>, A>()
+}
+
+/// Proof that `(A + B) + C` == `A + (B + C)`
+pub const fn associative_add<A, B, C>() -> TypeEq<Add<Add<A, B>, C>, Add<A, Add<B, C>>>
+where
+    A: PeanoInt,
+    B: PeanoInt,
+    C: PeanoInt,
+{
+    declare_inductive_eq! {
+        struct AssociativeAdd<B, C>;
+
+        lhs<N> = Add<Add<N, B>, C>;
+        rhs<N> = Add<N, Add<B, C>>;
+
+        base = TypeEq::NEW;
+        map = PlusOneFn;
+        lhs_step = TypeEq::NEW;
+        rhs_step = TypeEq::NEW;
+    }
+
+    induction::<AssociativeAdd<B, C>, A>()
+}
+
+/// Proof that `A * 0` == `0`
+pub const fn mul_zero<A>() -> TypeEq<Mul<A, Zero>, Zero>
+where
+    A: PeanoInt,
+{
+    declare_inductive_eq! {
+        struct MulZero;
+
+        lhs<N> = Mul<N, Zero>;
+        rhs<N> = Zero;
+
+        base = TypeEq::NEW;
+        map = typewit::type_fn::FnIdentity;
+        lhs_step = add_identity::<Mul<N, Zero>>();
+        rhs_step = TypeEq::NEW;
     }
+
+    induction::<MulZero, A>()
 }
 
+/// Proof that `A * (B + 1)` == `A * B + A`
+pub const fn mul_plus_one<A, B>() -> TypeEq<Mul<A, PlusOne<B>>, Add<Mul<A, B>, A>>
+where
+    A: PeanoInt,
+    B: PeanoInt,
+{
+    declare_inductive_eq! {
+        struct MulPlusOne<B>;
+
+        lhs<N> = Mul<N, PlusOne<B>>;
+        rhs<N> = Add<Mul<N, B>, N>;
+
+        base = TypeEq::NEW;
+        map = AddRhsFn<PlusOne<B>>;
+        lhs_step = TypeEq::NEW;
+        rhs_step = mul_plus_one_step::<N, B>();
+    }
 
+    induction::<MulPlusOne<B>, A>()
+}
+
+// Proof that `(N * B + B) + (N + 1)` == `(N * B + N) + (B + 1)`,
+// the inductive step of `mul_plus_one`
+const fn mul_plus_one_step<N, B>() 
+-> TypeEq<Add<Add<Mul<N, B>, B>, PlusOne<N>>, Add<Add<Mul<N, B>, N>, PlusOne<B>>>
+where
+    N: PeanoInt,
+    B: PeanoInt,
+{
+    // `(N * B + B) + (N + 1)`
+    // == `((N * B + B) + N) + 1`
+    // == `(N * B + (B + N)) + 1`
+    // == `(N * B + (N + B)) + 1`
+    // == `((N * B + N) + B) + 1`
+    // == `(N * B + N) + (B + 1)`
+    add_plus_one::<Add<Mul<N, B>, B>, N>()
+        .join(associative_add::<Mul<N, B>, B, N>().map(PlusOneFn))
+        .join(
+            TypeEq::new::<Mul<N, B>>()
+                .zip(commutative_add::<B, N>())
+                .map(AddFn::NEW)
+                .map(PlusOneFn),
+        )
+        .join(associative_add::<Mul<N, B>, N, B>().flip().map(PlusOneFn))
+        .join(add_plus_one::<Add<Mul<N, B>, N>, B>().flip())
+}
+
+/// Proof that `(A + B) * C` == `A * C + B * C`
+pub const fn right_distributive_mul<A, B, C>() 
+-> TypeEq<Mul<Add<A, B>, C>, Add<Mul<A, C>, Mul<B, C>>>
+where
+    A: PeanoInt,
+    B: PeanoInt,
+    C: PeanoInt,
+{
+    declare_inductive_eq! {
+        struct RightDistributiveMul<B, C>;
+
+        lhs<N> = Mul<Add<N, B>, C>;
+        rhs<N> = Add<Mul<N, C>, Mul<B, C>>;
+
+        base = TypeEq::NEW;
+        map = AddRhsFn<C>;
+        lhs_step = TypeEq::NEW;
+        rhs_step = right_distributive_mul_step::<N, B, C>();
+    }
+
+    induction::<RightDistributiveMul<B, C>, A>()
+}
+
+// Proof that `(N * C + C) + B * C` == `(N * C + B * C) + C`,
+// the inductive step of `right_distributive_mul`
+const fn right_distributive_mul_step<N, B, C>() 
+-> TypeEq<Add<Add<Mul<N, C>, C>, Mul<B, C>>, Add<Add<Mul<N, C>, Mul<B, C>>, C>>
+where
+    N: PeanoInt,
+    B: PeanoInt,
+    C: PeanoInt,
+{
+    // `(N * C + C) + B * C`
+    // == `N * C + (C + B * C)`
+    // == `N * C + (B * C + C)`
+    // == `(N * C + B * C) + C`
+    associative_add::<Mul<N, C>, C, Mul<B, C>>()
+        .join(
+            TypeEq::new::<Mul<N, C>>()
+                .zip(commutative_add::<C, Mul<B, C>>())
+                .map(AddFn::NEW)
+        )
+        .join(associative_add::<Mul<N, C>, Mul<B, C>, C>().flip())
+}
+
+/// Proof that `A * (B + C)` == `A * B + A * C`
+pub const fn left_distributive_mul<A, B, C>() 
+-> TypeEq<Mul<A, Add<B, C>>, Add<Mul<A, B>, Mul<A, C>>>
+where
+    A: PeanoInt,
+    B: PeanoInt,
+    C: PeanoInt,
+{
+    commutative_mul::<A, Add<B, C>>()
+        .join(right_distributive_mul::<B, C, A>())
+        .join(commutative_mul::<B, A>().zip(commutative_mul::<C, A>()).map(AddFn::NEW))
+}
+
+/// Proof that `(A * B) * C` == `A * (B * C)`
+pub const fn associative_mul<A, B, C>() -> TypeEq<Mul<Mul<A, B>, C>, Mul<A, Mul<B, C>>>
+where
+    A: PeanoInt,
+    B: PeanoInt,
+    C: PeanoInt,
+{
+    declare_inductive_eq! {
+        struct AssociativeMul<B, C>;
+
+        lhs<N> = Mul<Mul<N, B>, C>;
+        rhs<N> = Mul<N, Mul<B, C>>;
+
+        base = TypeEq::NEW;
+        map = AddRhsFn<Mul<B, C>>;
+        lhs_step = right_distributive_mul::<Mul<N, B>, B, C>();
+        rhs_step = TypeEq::NEW;
+    }
+
+    induction::<AssociativeMul<B, C>, A>()
+}
+
+/// Proof that, if `L <= R`, then `L + SubSat<R, L>` == `R`
+pub const fn add_sub_le<L, R>(
+    l_le_r: TypeEq<IsLe<L, R>, Bool<true>>
+) -> TypeEq<Add<L, SubSat<R, L>>, R>
+where
+    L: PeanoInt,
+    R: PeanoInt,
+{
+    typewit::type_fn! {
+        struct LhsFn;
+        impl<L: PeanoInt, R: PeanoInt> (L, R) => Add<L, SubSat<R, L>>
+    }
+
+    match (L::PEANO_WIT, R::PEANO_WIT) {
+        (PeanoWit::Zero(l_te), _) => {
+            l_te.zip(TypeEq::new::<R>()).map(LhsFn).join(sub_identity::<R>())
+        }
+        (PeanoWit::PlusOne(l_te), PeanoWit::Zero(r_te)) => {
+            let false_te = l_te.zip(r_te).map(IsLeFn::NEW);
+            crate::boolean::contradiction(l_le_r.flip().join(false_te))
+        }
+        (PeanoWit::PlusOne(l_te), PeanoWit::PlusOne(r_te)) => {
+            let lr_te = l_te.zip(r_te);
+            let pred_le = lr_te.map(IsLeFn::NEW).flip().join(l_le_r);
+
+            lr_te.map(LhsFn)
+                .join(add_sub_le::<L::SubOneSat, R::SubOneSat>(pred_le).map(PlusOneFn))
+                .join(r_te.flip())
+        }
+    }
+}
+
+/// Proof that `SubSat<A + B, B>` == `A`
+pub const fn add_sub_cancel<A, B>() -> TypeEq<SubSat<Add<A, B>, B>, A>
+where
+    A: PeanoInt,
+    B: PeanoInt,
+{
+    declare_inductive_eq! {
+        struct AddSubCancel<A>;
+
+        lhs<N> = SubSat<Add<A, N>, N>;
+        rhs<N> = A;
+
+        base = sub_identity::<Add<A, Zero>>().join(add_identity::<A>());
+        map = typewit::type_fn::FnIdentity;
+        lhs_step = add_plus_one::<A, N>()
+            .zip(TypeEq::new::<PlusOne<N>>())
+            .map(SubSatFn::NEW);
+        rhs_step = TypeEq::NEW;
+    }
+
+    induction::<AddSubCancel<A>, B>()
+}
+
+/// Proof that `L <= L`
+pub const fn le_refl<L>() -> TypeEq<IsLe<L, L>, Bool<true>>
+where
+    L: PeanoInt,
+{
+    typewit::type_fn! {
+        struct LhsFn;
+        impl<L: PeanoInt> L => IsLe<L, L>
+    }
+
+    match L::PEANO_WIT {
+        PeanoWit::Zero(l_te) => l_te.map(LhsFn),
+        PeanoWit::PlusOne(l_te) => l_te.map(LhsFn).join(le_refl::<L::SubOneSat>()),
+    }
+}
+
+/// Proof that `L < L + 1`
+pub const fn lt_plus_one<L>() -> TypeEq<IsLt<L, PlusOne<L>>, Bool<true>>
+where
+    L: PeanoInt,
+{
+    typewit::type_fn! {
+        struct LhsFn;
+        impl<L: PeanoInt> L => IsLt<L, PlusOne<L>>
+    }
+
+    match L::PEANO_WIT {
+        PeanoWit::Zero(l_te) => l_te.map(LhsFn),
+        PeanoWit::PlusOne(l_te) => l_te.map(LhsFn).join(lt_plus_one::<L::SubOneSat>()),
+    }
+}
+
+/// Proof that, if `A < B`, then `A <= B`
+pub const fn lt_to_le<A, B>(
+    a_lt_b: TypeEq<IsLt<A, B>, Bool<true>>
+) -> TypeEq<IsLe<A, B>, Bool<true>>
+where
+    A: PeanoInt,
+    B: PeanoInt,
+{
+    match (A::PEANO_WIT, B::PEANO_WIT) {
+        (PeanoWit::Zero(a_te), _) => a_te.zip(TypeEq::new::<B>()).map(IsLeFn::NEW),
+        (PeanoWit::PlusOne(_), PeanoWit::Zero(b_te)) => {
+            crate::boolean::contradiction(a_lt_b.flip().join(not_lt_zero::<A, B>(b_te)))
+        }
+        (PeanoWit::PlusOne(a_te), PeanoWit::PlusOne(b_te)) => {
+            let ab_te = a_te.zip(b_te);
+            let pred_lt = ab_te.map(IsLtFn::NEW).flip().join(a_lt_b);
+
+            ab_te.map(IsLeFn::NEW)
+                .join(lt_to_le::<A::SubOneSat, B::SubOneSat>(pred_lt))
+        }
+    }
+}
+
+/// Proof that, if `A < B` and `B < C`, then `A < C`
+pub const fn lt_trans<A, B, C>(
+    a_lt_b: TypeEq<IsLt<A, B>, Bool<true>>,
+    b_lt_c: TypeEq<IsLt<B, C>, Bool<true>>,
+) -> TypeEq<IsLt<A, C>, Bool<true>>
+where
+    A: PeanoInt,
+    B: PeanoInt,
+    C: PeanoInt,
+{
+    lt_le_trans::<A, B, C>(a_lt_b, lt_to_le::<B, C>(b_lt_c))
+}
+
+/// Proof that, if `A <= B` and `B <= C`, then `A <= C`
+pub const fn le_trans<A, B, C>(
+    a_le_b: TypeEq<IsLe<A, B>, Bool<true>>,
+    b_le_c: TypeEq<IsLe<B, C>, Bool<true>>,
+) -> TypeEq<IsLe<A, C>, Bool<true>>
+where
+    A: PeanoInt,
+    B: PeanoInt,
+    C: PeanoInt,
+{
+    match (A::PEANO_WIT, B::PEANO_WIT, C::PEANO_WIT) {
+        (PeanoWit::Zero(a_te), _, _) => a_te.zip(TypeEq::new::<C>()).map(IsLeFn::NEW),
+        (PeanoWit::PlusOne(a_te), PeanoWit::Zero(b_te), _) => {
+            crate::boolean::contradiction(a_le_b.flip().join(not_le_zero(a_te, b_te)))
+        }
+        (PeanoWit::PlusOne(_), PeanoWit::PlusOne(b_te), PeanoWit::Zero(c_te)) => {
+            crate::boolean::contradiction(b_le_c.flip().join(not_le_zero(b_te, c_te)))
+        }
+        (PeanoWit::PlusOne(a_te), PeanoWit::PlusOne(b_te), PeanoWit::PlusOne(c_te)) => {
+            let pred_ab = a_te.zip(b_te).map(IsLeFn::NEW).flip().join(a_le_b);
+            let pred_bc = b_te.zip(c_te).map(IsLeFn::NEW).flip().join(b_le_c);
+
+            a_te.zip(c_te).map(IsLeFn::NEW)
+                .join(le_trans::<A::SubOneSat, B::SubOneSat, C::SubOneSat>(pred_ab, pred_bc))
+        }
+    }
+}
+
+/// Proof that, if `A < B` and `B <= C`, then `A < C`
+pub const fn lt_le_trans<A, B, C>(
+    a_lt_b: TypeEq<IsLt<A, B>, Bool<true>>,
+    b_le_c: TypeEq<IsLe<B, C>, Bool<true>>,
+) -> TypeEq<IsLt<A, C>, Bool<true>>
+where
+    A: PeanoInt,
+    B: PeanoInt,
+    C: PeanoInt,
+{
+    match (A::PEANO_WIT, B::PEANO_WIT, C::PEANO_WIT) {
+        (_, PeanoWit::Zero(b_te), _) => {
+            crate::boolean::contradiction(a_lt_b.flip().join(not_lt_zero::<A, B>(b_te)))
+        }
+        (_, PeanoWit::PlusOne(b_te), PeanoWit::Zero(c_te)) => {
+            crate::boolean::contradiction(b_le_c.flip().join(not_le_zero(b_te, c_te)))
+        }
+        (PeanoWit::Zero(a_te), PeanoWit::PlusOne(_), PeanoWit::PlusOne(c_te)) => {
+            a_te.zip(c_te).map(IsLtFn::NEW)
+        }
+        (PeanoWit::PlusOne(a_te), PeanoWit::PlusOne(b_te), PeanoWit::PlusOne(c_te)) => {
+            let pred_ab = a_te.zip(b_te).map(IsLtFn::NEW).flip().join(a_lt_b);
+            let pred_bc = b_te.zip(c_te).map(IsLeFn::NEW).flip().join(b_le_c);
+
+            a_te.zip(c_te).map(IsLtFn::NEW)
+                .join(lt_le_trans::<A::SubOneSat, B::SubOneSat, C::SubOneSat>(pred_ab, pred_bc))
+        }
+    }
+}
+
+/// Proof that, if `A <= B` and `B < C`, then `A < C`
+pub const fn le_lt_trans<A, B, C>(
+    a_le_b: TypeEq<IsLe<A, B>, Bool<true>>,
+    b_lt_c: TypeEq<IsLt<B, C>, Bool<true>>,
+) -> TypeEq<IsLt<A, C>, Bool<true>>
+where
+    A: PeanoInt,
+    B: PeanoInt,
+    C: PeanoInt,
+{
+    match (A::PEANO_WIT, B::PEANO_WIT, C::PEANO_WIT) {
+        (_, _, PeanoWit::Zero(c_te)) => {
+            crate::boolean::contradiction(b_lt_c.flip().join(not_lt_zero::<B, C>(c_te)))
+        }
+        (PeanoWit::Zero(a_te), _, PeanoWit::PlusOne(c_te)) => {
+            a_te.zip(c_te).map(IsLtFn::NEW)
+        }
+        (PeanoWit::PlusOne(a_te), PeanoWit::Zero(b_te), PeanoWit::PlusOne(_)) => {
+            crate::boolean::contradiction(a_le_b.flip().join(not_le_zero(a_te, b_te)))
+        }
+        (PeanoWit::PlusOne(a_te), PeanoWit::PlusOne(b_te), PeanoWit::PlusOne(c_te)) => {
+            let pred_ab = a_te.zip(b_te).map(IsLeFn::NEW).flip().join(a_le_b);
+            let pred_bc = b_te.zip(c_te).map(IsLtFn::NEW).flip().join(b_lt_c);
+
+            a_te.zip(c_te).map(IsLtFn::NEW)
+                .join(le_lt_trans::<A::SubOneSat, B::SubOneSat, C::SubOneSat>(pred_ab, pred_bc))
+        }
+    }
+}
+
+// Proof that `A < 0` is false
+const fn not_lt_zero<A, B>(b_te: TypeEq<B, Zero>) -> TypeEq<IsLt<A, B>, Bool<false>>
+where
+    A: PeanoInt,
+    B: PeanoInt,
+{
+    let te = TypeEq::new::<A>().zip(b_te).map(IsLtFn::NEW);
+
+    match A::PEANO_WIT {
+        PeanoWit::Zero(a_te) => te.join(a_te.zip(TypeEq::NEW).map(IsLtFn::NEW)),
+        PeanoWit::PlusOne(a_te) => te.join(a_te.zip(TypeEq::NEW).map(IsLtFn::NEW)),
+    }
+}
+
+// Proof that `A <= 0` is false when `A` is nonzero
+const fn not_le_zero<A, APred, B>(
+    a_te: TypeEq<A, PlusOne<APred>>,
+    b_te: TypeEq<B, Zero>,
+) -> TypeEq<IsLe<A, B>, Bool<false>>
+where
+    A: PeanoInt,
+    APred: PeanoInt,
+    B: PeanoInt,
+{
+    a_te.zip(b_te).map(IsLeFn::NEW)
+}
+
+/// Proof that `A <= A + B`
+pub const fn le_add<A, B>() -> TypeEq<IsLe<A, Add<A, B>>, Bool<true>>
+where
+    A: PeanoInt,
+    B: PeanoInt,
+{
+    typewit::type_fn! {
+        struct LhsFn<B: PeanoInt>;
+        impl<A: PeanoInt> A => IsLe<A, Add<A, B>>
+    }
+
+    match A::PEANO_WIT {
+        PeanoWit::Zero(a_te) => a_te.map(LhsFn::NEW),
+        PeanoWit::PlusOne(a_te) => a_te.map(LhsFn::NEW).join(le_add::<A::SubOneSat, B>()),
+    }
+}
+
+/// Proof that `SubSat<A, B> <= A`
+pub const fn sub_sat_le<A, B>() -> TypeEq<IsLe<SubSat<A, B>, A>, Bool<true>>
+where
+    A: PeanoInt,
+    B: PeanoInt,
+{
+    typewit::type_fn! {
+        struct LhsFn;
+        impl<A: PeanoInt, B: PeanoInt> (A, B) => IsLe<SubSat<A, B>, A>
+    }
+
+    match (A::PEANO_WIT, B::PEANO_WIT) {
+        (PeanoWit::Zero(a_te), _) => a_te.zip(TypeEq::new::<B>()).map(LhsFn),
+        (PeanoWit::PlusOne(a_te), PeanoWit::Zero(b_te)) => {
+            a_te.zip(b_te).map(LhsFn).join(le_refl::<A::SubOneSat>())
+        }
+        (PeanoWit::PlusOne(a_te), PeanoWit::PlusOne(b_te)) => {
+            type Pred<A> = <A as PeanoInt>::SubOneSat;
+
+            let le_pred = sub_sat_le::<Pred<A>, Pred<B>>();
+            let pred_le = lt_to_le::<Pred<A>, PlusOne<Pred<A>>>(lt_plus_one::<Pred<A>>());
+
+            a_te.zip(b_te).map(LhsFn)
+                .join(le_trans::<SubSat<Pred<A>, Pred<B>>, Pred<A>, _>(le_pred, pred_le))
+        }
+    }
+}
+
+/// Proof that, if `A <= B`, then `C + A <= C + B`
+pub const fn add_le_mono<A, B, C>(
+    a_le_b: TypeEq<IsLe<A, B>, Bool<true>>
+) -> TypeEq<IsLe<Add<C, A>, Add<C, B>>, Bool<true>>
+where
+    A: PeanoInt,
+    B: PeanoInt,
+    C: PeanoInt,
+{
+    typewit::type_fn! {
+        struct LhsFn<A: PeanoInt, B: PeanoInt>;
+        impl<C: PeanoInt> C => IsLe<Add<C, A>, Add<C, B>>
+    }
+
+    match C::PEANO_WIT {
+        PeanoWit::Zero(c_te) => c_te.map(LhsFn::<A, B>::NEW).join(a_le_b),
+        PeanoWit::PlusOne(c_te) => c_te.map(LhsFn::<A, B>::NEW)
+            .join(add_le_mono::<A, B, C::SubOneSat>(a_le_b)),
+    }
+}
+
+/// Proof that, if `A < B`, then `C + A < C + B`
+pub const fn add_lt_mono<A, B, C>(
+    a_lt_b: TypeEq<IsLt<A, B>, Bool<true>>
+) -> TypeEq<IsLt<Add<C, A>, Add<C, B>>, Bool<true>>
+where
+    A: PeanoInt,
+    B: PeanoInt,
+    C: PeanoInt,
+{
+    typewit::type_fn! {
+        struct LhsFn<A: PeanoInt, B: PeanoInt>;
+        impl<C: PeanoInt> C => IsLt<Add<C, A>, Add<C, B>>
+    }
+
+    match C::PEANO_WIT {
+        PeanoWit::Zero(c_te) => c_te.map(LhsFn::<A, B>::NEW).join(a_lt_b),
+        PeanoWit::PlusOne(c_te) => c_te.map(LhsFn::<A, B>::NEW)
+            .join(add_lt_mono::<A, B, C::SubOneSat>(a_lt_b)),
+    }
+}
+
+/// Proof that `Min<A, B>` == `Min<B, A>`
+pub const fn commutative_min<A, B>() -> TypeEq<Min<A, B>, Min<B, A>>
+where
+    A: PeanoInt,
+    B: PeanoInt,
+{
+    match (A::PEANO_WIT, B::PEANO_WIT) {
+        (PeanoWit::Zero(a_te), PeanoWit::Zero(b_te)) => {
+            a_te.zip(b_te).map(MinFn::NEW).join(b_te.zip(a_te).map(MinFn::NEW).flip())
+        }
+        (PeanoWit::Zero(a_te), PeanoWit::PlusOne(b_te)) => {
+            a_te.zip(b_te).map(MinFn::NEW).join(b_te.zip(a_te).map(MinFn::NEW).flip())
+        }
+        (PeanoWit::PlusOne(a_te), PeanoWit::Zero(b_te)) => {
+            a_te.zip(b_te).map(MinFn::NEW).join(b_te.zip(a_te).map(MinFn::NEW).flip())
+        }
+        (PeanoWit::PlusOne(a_te), PeanoWit::PlusOne(b_te)) => {
+            a_te.zip(b_te).map(MinFn::NEW)
+                .join(commutative_min::<A::SubOneSat, B::SubOneSat>().map(PlusOneFn))
+                .join(b_te.zip(a_te).map(MinFn::NEW).flip())
+        }
+    }
+}
+
+/// Proof that `Max<A, B>` == `Max<B, A>`
+pub const fn commutative_max<A, B>() -> TypeEq<Max<A, B>, Max<B, A>>
+where
+    A: PeanoInt,
+    B: PeanoInt,
+{
+    match (A::PEANO_WIT, B::PEANO_WIT) {
+        (PeanoWit::Zero(a_te), PeanoWit::Zero(b_te)) => {
+            a_te.zip(b_te).map(MaxFn::NEW).join(b_te.zip(a_te).map(MaxFn::NEW).flip())
+        }
+        (PeanoWit::Zero(a_te), PeanoWit::PlusOne(b_te)) => {
+            a_te.zip(b_te).map(MaxFn::NEW).join(b_te.zip(a_te).map(MaxFn::NEW).flip())
+        }
+        (PeanoWit::PlusOne(a_te), PeanoWit::Zero(b_te)) => {
+            a_te.zip(b_te).map(MaxFn::NEW).join(b_te.zip(a_te).map(MaxFn::NEW).flip())
+        }
+        (PeanoWit::PlusOne(a_te), PeanoWit::PlusOne(b_te)) => {
+            a_te.zip(b_te).map(MaxFn::NEW)
+                .join(commutative_max::<A::SubOneSat, B::SubOneSat>().map(PlusOneFn))
+                .join(b_te.zip(a_te).map(MaxFn::NEW).flip())
+        }
+    }
+}
+
+/// Proof that `Min<A, B> <= A`
+pub const fn min_le<A, B>() -> TypeEq<IsLe<Min<A, B>, A>, Bool<true>>
+where
+    A: PeanoInt,
+    B: PeanoInt,
+{
+    typewit::type_fn! {
+        struct LhsFn;
+        impl<A: PeanoInt, B: PeanoInt> (A, B) => IsLe<Min<A, B>, A>
+    }
+
+    match (A::PEANO_WIT, B::PEANO_WIT) {
+        (PeanoWit::Zero(a_te), _) => a_te.zip(TypeEq::new::<B>()).map(LhsFn),
+        (PeanoWit::PlusOne(a_te), PeanoWit::Zero(b_te)) => a_te.zip(b_te).map(LhsFn),
+        (PeanoWit::PlusOne(a_te), PeanoWit::PlusOne(b_te)) => {
+            a_te.zip(b_te).map(LhsFn).join(min_le::<A::SubOneSat, B::SubOneSat>())
+        }
+    }
+}
+
+/// Proof that `A <= Max<A, B>`
+pub const fn le_max<A, B>() -> TypeEq<IsLe<A, Max<A, B>>, Bool<true>>
+where
+    A: PeanoInt,
+    B: PeanoInt,
+{
+    typewit::type_fn! {
+        struct LhsFn;
+        impl<A: PeanoInt, B: PeanoInt> (A, B) => IsLe<A, Max<A, B>>
+    }
+
+    match (A::PEANO_WIT, B::PEANO_WIT) {
+        (PeanoWit::Zero(a_te), _) => a_te.zip(TypeEq::new::<B>()).map(LhsFn),
+        (PeanoWit::PlusOne(a_te), PeanoWit::Zero(b_te)) => {
+            a_te.zip(b_te).map(LhsFn).join(le_refl::<A::SubOneSat>())
+        }
+        (PeanoWit::PlusOne(a_te), PeanoWit::PlusOne(b_te)) => {
+            a_te.zip(b_te).map(LhsFn).join(le_max::<A::SubOneSat, B::SubOneSat>())
+        }
+    }
+}
+
+/// Proof that, if `A <= B`, then `Min<A, B>` == `A`
+pub const fn min_of_le<A, B>(
+    a_le_b: TypeEq<IsLe<A, B>, Bool<true>>
+) -> TypeEq<Min<A, B>, A>
+where
+    A: PeanoInt,
+    B: PeanoInt,
+{
+    match (A::PEANO_WIT, B::PEANO_WIT) {
+        (PeanoWit::Zero(a_te), _) => {
+            a_te.zip(TypeEq::new::<B>()).map(MinFn::NEW).join(a_te.flip())
+        }
+        (PeanoWit::PlusOne(a_te), PeanoWit::Zero(b_te)) => {
+            crate::boolean::contradiction(a_le_b.flip().join(not_le_zero(a_te, b_te)))
+        }
+        (PeanoWit::PlusOne(a_te), PeanoWit::PlusOne(b_te)) => {
+            let ab_te = a_te.zip(b_te);
+            let pred_le = ab_te.map(IsLeFn::NEW).flip().join(a_le_b);
+
+            ab_te.map(MinFn::NEW)
+                .join(min_of_le::<A::SubOneSat, B::SubOneSat>(pred_le).map(PlusOneFn))
+                .join(a_te.flip())
+        }
+    }
+}
+
+/// Proof that, if `A <= B`, then `Max<A, B>` == `B`
+pub const fn max_of_le<A, B>(
+    a_le_b: TypeEq<IsLe<A, B>, Bool<true>>
+) -> TypeEq<Max<A, B>, B>
+where
+    A: PeanoInt,
+    B: PeanoInt,
+{
+    match (A::PEANO_WIT, B::PEANO_WIT) {
+        (PeanoWit::Zero(a_te), _) => a_te.zip(TypeEq::new::<B>()).map(MaxFn::NEW),
+        (PeanoWit::PlusOne(a_te), PeanoWit::Zero(b_te)) => {
+            crate::boolean::contradiction(a_le_b.flip().join(not_le_zero(a_te, b_te)))
+        }
+        (PeanoWit::PlusOne(a_te), PeanoWit::PlusOne(b_te)) => {
+            let ab_te = a_te.zip(b_te);
+            let pred_le = ab_te.map(IsLeFn::NEW).flip().join(a_le_b);
+
+            ab_te.map(MaxFn::NEW)
+                .join(max_of_le::<A::SubOneSat, B::SubOneSat>(pred_le).map(PlusOneFn))
+                .join(b_te.flip())
+        }
+    }
+}
+
+/// Proof that `Min<A, B> + Max<A, B>` == `A + B`
+pub const fn add_min_max<A, B>() -> TypeEq<Add<Min<A, B>, Max<A, B>>, Add<A, B>>
+where
+    A: PeanoInt,
+    B: PeanoInt,
+{
+    typewit::type_fn! {
+        struct LhsFn;
+        impl<A: PeanoInt, B: PeanoInt> (A, B) => Add<Min<A, B>, Max<A, B>>
+    }
+
+    match (A::PEANO_WIT, B::PEANO_WIT) {
+        (PeanoWit::Zero(a_te), _) => {
+            let ab_te = a_te.zip(TypeEq::new::<B>());
+            ab_te.map(LhsFn).join(ab_te.map(AddFn::NEW).flip())
+        }
+        (PeanoWit::PlusOne(a_te), PeanoWit::Zero(b_te)) => {
+            let ab_te = a_te.zip(b_te);
+
+            ab_te.map(LhsFn)
+                .join(add_identity::<A::SubOneSat>().map(PlusOneFn).flip())
+                .join(ab_te.map(AddFn::NEW).flip())
+        }
+        (PeanoWit::PlusOne(a_te), PeanoWit::PlusOne(b_te)) => {
+            type Pred<A> = <A as PeanoInt>::SubOneSat;
+
+            let ab_te = a_te.zip(b_te);
+
+            // `(Min<A, B> + 1) + (Max<A, B> + 1)`
+            // == `(Min<A, B> + Max<A, B>) + 2`
+            // == `(A + B) + 2`
+            // == `(A + 1) + (B + 1)`
+            ab_te.map(LhsFn)
+                .join(
+                    add_plus_one::<Min<Pred<A>, Pred<B>>, Max<Pred<A>, Pred<B>>>()
+                        .map(PlusOneFn)
+                )
+                .join(add_min_max::<Pred<A>, Pred<B>>().map(PlusOneFn).map(PlusOneFn))
+                .join(add_plus_one::<Pred<A>, Pred<B>>().map(PlusOneFn).flip())
+                .join(ab_te.map(AddFn::NEW).flip())
+        }
+    }
+}
+
+/// Proof that `IsLt<A, B>` == `Not<IsLe<B, A>>`
+pub const fn lt_is_not_flipped_le<A, B>() -> TypeEq<IsLt<A, B>, Not<IsLe<B, A>>>
+where
+    A: PeanoInt,
+    B: PeanoInt,
+{
+    typewit::type_fn! {
+        struct RhsFn;
+        impl<A: PeanoInt, B: PeanoInt> (A, B) => Not<IsLe<B, A>>
+    }
+
+    match (A::PEANO_WIT, B::PEANO_WIT) {
+        (PeanoWit::Zero(a_te), PeanoWit::Zero(b_te)) => {
+            a_te.zip(b_te).map(IsLtFn::NEW).join(a_te.zip(b_te).map(RhsFn).flip())
+        }
+        (PeanoWit::Zero(a_te), PeanoWit::PlusOne(b_te)) => {
+            a_te.zip(b_te).map(IsLtFn::NEW).join(a_te.zip(b_te).map(RhsFn).flip())
+        }
+        (PeanoWit::PlusOne(a_te), PeanoWit::Zero(b_te)) => {
+            a_te.zip(b_te).map(IsLtFn::NEW).join(a_te.zip(b_te).map(RhsFn).flip())
+        }
+        (PeanoWit::PlusOne(a_te), PeanoWit::PlusOne(b_te)) => {
+            a_te.zip(b_te).map(IsLtFn::NEW)
+                .join(lt_is_not_flipped_le::<A::SubOneSat, B::SubOneSat>())
+                .join(a_te.zip(b_te).map(RhsFn).flip())
+        }
+    }
+}
+
+/// Proof that `IsLe<A, B>` == `Not<IsLt<B, A>>`
+pub const fn le_is_not_flipped_lt<A, B>() -> TypeEq<IsLe<A, B>, Not<IsLt<B, A>>>
+where
+    A: PeanoInt,
+    B: PeanoInt,
+{
+    // `Not<Not<IsLe<A, B>>>` == `IsLe<A, B>`
+    lt_is_not_flipped_le::<B, A>().map(crate::boolean::NotFn::NEW).flip()
+}
+
+/// Proof that `IsEq<L, L>` == `true`
+pub const fn is_eq_refl<L>() -> TypeEq<IsEq<L, L>, Bool<true>>
+where
+    L: PeanoInt,
+{
+    typewit::type_fn! {
+        struct LhsFn;
+        impl<L: PeanoInt> L => IsEq<L, L>
+    }
+
+    match L::PEANO_WIT {
+        PeanoWit::Zero(l_te) => l_te.map(LhsFn),
+        PeanoWit::PlusOne(l_te) => l_te.map(LhsFn).join(is_eq_refl::<L::SubOneSat>()),
+    }
+}
+
+/// Converts a proof of `L == R` into a proof of `IsEq<L, R>` == `true`
+pub const fn eq_to_is_eq<L, R>(
+    l_eq_r: TypeEq<L, R>
+) -> TypeEq<IsEq<L, R>, Bool<true>>
+where
+    L: PeanoInt,
+    R: PeanoInt,
+{
+    TypeEq::new::<L>().zip(l_eq_r).map(IsEqFn::NEW).flip().join(is_eq_refl::<L>())
+}
+
+/// Converts a proof of `IsEq<L, R>` == `true` into a proof of `L == R`
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use nlist::{NList, PeanoInt, nlist, peano};
+/// use nlist::boolean::{BoolWitG, Boolean};
+/// use nlist::peano::proofs;
+/// 
+/// assert_eq!(zip_if_same_len(nlist![3, 5], nlist!["a", "b"]), Some(nlist![(3, "a"), (5, "b")]));
+/// assert_eq!(zip_if_same_len(nlist![3, 5], nlist!["a"]), None);
+/// 
+/// fn zip_if_same_len<T, U, L, R>(
+///     left: NList<T, L>,
+///     right: NList<U, R>,
+/// ) -> Option<NList<(T, U), L>>
+/// where
+///     L: PeanoInt,
+///     R: PeanoInt,
+/// {
+///     match peano::IsEq::<L, R>::BOOL_WIT {
+///         BoolWitG::True(te) => {
+///             let right = right.coerce_len(proofs::is_eq_to_eq(te).flip());
+///             Some(left.zip(right))
+///         }
+///         BoolWitG::False(_) => None,
+///     }
+/// }
+/// ```
+pub const fn is_eq_to_eq<L, R>(
+    is_eq: TypeEq<IsEq<L, R>, Bool<true>>
+) -> TypeEq<L, R>
+where
+    L: PeanoInt,
+    R: PeanoInt,
+{
+    match (L::PEANO_WIT, R::PEANO_WIT) {
+        (PeanoWit::Zero(l_te), PeanoWit::Zero(r_te)) => l_te.join(r_te.flip()),
+        (PeanoWit::Zero(l_te), PeanoWit::PlusOne(r_te)) => {
+            crate::boolean::contradiction(is_eq.flip().join(l_te.zip(r_te).map(IsEqFn::NEW)))
+        }
+        (PeanoWit::PlusOne(l_te), PeanoWit::Zero(r_te)) => {
+            crate::boolean::contradiction(is_eq.flip().join(l_te.zip(r_te).map(IsEqFn::NEW)))
+        }
+        (PeanoWit::PlusOne(l_te), PeanoWit::PlusOne(r_te)) => {
+            let pred_is_eq = l_te.zip(r_te).map(IsEqFn::NEW).flip().join(is_eq);
+
+            l_te
+                .join(is_eq_to_eq::<L::SubOneSat, R::SubOneSat>(pred_is_eq).map(PlusOneFn))
+                .join(r_te.flip())
+        }
+    }
+}
+
+/// Converts a proof of `IsEq<L, R>` == `false` into a proof of `L != R`
+pub const fn is_ne_to_ne<L, R>(
+    is_eq: TypeEq<IsEq<L, R>, Bool<false>>
+) -> TypeNe<L, R>
+where
+    L: PeanoInt,
+    R: PeanoInt,
+{
+    match (L::PEANO_WIT, R::PEANO_WIT) {
+        (PeanoWit::Zero(l_te), PeanoWit::Zero(r_te)) => {
+            crate::boolean::contradiction(l_te.zip(r_te).map(IsEqFn::NEW).flip().join(is_eq))
+        }
+        (PeanoWit::Zero(l_te), PeanoWit::PlusOne(r_te)) => {
+            zero_one_inequality::<R::SubOneSat>().join_left(l_te).join_right(r_te.flip())
+        }
+        (PeanoWit::PlusOne(l_te), PeanoWit::Zero(r_te)) => {
+            zero_one_inequality::<L::SubOneSat>().flip().join_left(l_te).join_right(r_te.flip())
+        }
+        (PeanoWit::PlusOne(l_te), PeanoWit::PlusOne(r_te)) => {
+            let pred_is_eq = l_te.zip(r_te).map(IsEqFn::NEW).flip().join(is_eq);
+
+            is_ne_to_ne::<L::SubOneSat, R::SubOneSat>(pred_is_eq)
+                .map(PlusOneFn)
+                .join_left(l_te)
+                .join_right(r_te.flip())
+        }
+    }
+}
+
+/// Proof that `IsGt<L, R>` == `IsLt<R, L>`
+pub const fn gt_is_flipped_lt<L, R>() -> TypeEq<IsGt<L, R>, IsLt<R, L>>
+where
+    L: PeanoInt,
+    R: PeanoInt,
+{
+    typewit::type_fn! {
+        struct RhsFn<R: PeanoInt>;
+        impl<L: PeanoInt> L => IsLt<R, L>
+    }
+
+    let r_te = TypeEq::new::<R>();
+
+    // `IsGt` is defined as the flipped `IsLt` once `L` is known to be `0` or `L' + 1`
+    match L::PEANO_WIT {
+        PeanoWit::Zero(l_te) => {
+            l_te.zip(r_te).map(IsGtFn::NEW).join(l_te.map(RhsFn::<R>::NEW).flip())
+        }
+        PeanoWit::PlusOne(l_te) => {
+            l_te.zip(r_te).map(IsGtFn::NEW).join(l_te.map(RhsFn::<R>::NEW).flip())
+        }
+    }
+}
+
+/// Proof that `IsGe<L, R>` == `IsLe<R, L>`
+pub const fn ge_is_flipped_le<L, R>() -> TypeEq<IsGe<L, R>, IsLe<R, L>>
+where
+    L: PeanoInt,
+    R: PeanoInt,
+{
+    typewit::type_fn! {
+        struct RhsFn<R: PeanoInt>;
+        impl<L: PeanoInt> L => IsLe<R, L>
+    }
+
+    let r_te = TypeEq::new::<R>();
+
+    match L::PEANO_WIT {
+        PeanoWit::Zero(l_te) => {
+            l_te.zip(r_te).map(IsGeFn::NEW).join(l_te.map(RhsFn::<R>::NEW).flip())
+        }
+        PeanoWit::PlusOne(l_te) => {
+            l_te.zip(r_te).map(IsGeFn::NEW).join(l_te.map(RhsFn::<R>::NEW).flip())
+        }
+    }
+}
+
+/// Witness that `L` is an even number, 
+/// produced by [`even_wit`] from a proof of `IsEven<L>` == `true`.
+pub enum EvenWit<L: PeanoInt> {
+    /// Proof that `L == 0`
+    Zero(TypeEq<L, Zero>),
+    /// Proof that `L` is `2` plus another even number
+    PlusTwo {
+        /// Proof that `L == L'' + 2`
+        te: TypeEq<L, PlusOne<PlusOne<SubOneSat<SubOneSat<L>>>>>,
+        /// Proof that `L''` is even
+        pred_is_even: TypeEq<IsEven<SubOneSat<SubOneSat<L>>>, Bool<true>>,
+    },
+}
+
+impl<L: PeanoInt> Copy for EvenWit<L> {}
+
+impl<L: PeanoInt> Clone for EvenWit<L> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<L: PeanoInt> core::fmt::Debug for EvenWit<L> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Zero(te) => f.debug_tuple("Zero").field(te).finish(),
+            Self::PlusTwo { te, pred_is_even } => f
+                .debug_struct("PlusTwo")
+                .field("te", te)
+                .field("pred_is_even", pred_is_even)
+                .finish(),
+        }
+    }
+}
+
+/// Converts a proof of `IsEven<L>` == `true` into an [`EvenWit`]
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use nlist::{NList, PeanoInt, nlist, peano};
+/// use nlist::boolean::Bool;
+/// use nlist::peano::proofs::{self, EvenWit};
+/// 
+/// use typewit::TypeEq;
+/// 
+/// assert_eq!(pairs(nlist![3, 5, 8, 13]), vec![(3, 5), (8, 13)]);
+/// 
+/// fn pairs<T, L>(list: NList<T, L>) -> Vec<(T, T)>
+/// where
+///     L: PeanoInt<IsEven = Bool<true>>,
+/// {
+///     pairs_inner(list, TypeEq::NEW)
+/// }
+/// 
+/// fn pairs_inner<T, L>(list: NList<T, L>, even: TypeEq<peano::IsEven<L>, Bool<true>>) -> Vec<(T, T)>
+/// where
+///     L: PeanoInt,
+/// {
+///     match proofs::even_wit(even) {
+///         EvenWit::Zero(_) => Vec::new(),
+///         EvenWit::PlusTwo { te, pred_is_even } => {
+///             let (a, tail) = list.coerce_len(te).into_split_head();
+///             let (b, tail) = tail.into_split_head();
+///             let mut out = vec![(a, b)];
+///             out.extend(pairs_inner(tail, pred_is_even));
+///             out
+///         }
+///     }
+/// }
+/// ```
+pub const fn even_wit<L>(is_even: TypeEq<IsEven<L>, Bool<true>>) -> EvenWit<L>
+where
+    L: PeanoInt,
+{
+    match L::PEANO_WIT {
+        PeanoWit::Zero(l_te) => EvenWit::Zero(l_te),
+        PeanoWit::PlusOne(l_te) => {
+            // `IsEven<L' + 1>` == `IsOdd<L'>`
+            let is_odd_pred = l_te.map(IsEvenFn::NEW).flip().join(is_even);
+
+            match <L::SubOneSat as PeanoInt>::PEANO_WIT {
+                PeanoWit::Zero(p_te) => {
+                    crate::boolean::contradiction(
+                        is_odd_pred.flip().join(p_te.map(IsOddFn::NEW))
+                    )
+                }
+                PeanoWit::PlusOne(p_te) => EvenWit::PlusTwo {
+                    te: l_te.join(p_te.map(PlusOneFn)),
+                    // `IsOdd<L'' + 1>` == `IsEven<L''>`
+                    pred_is_even: p_te.map(IsOddFn::NEW).flip().join(is_odd_pred),
+                },
+            }
+        }
+    }
+}
+
+/// Converts a proof of `IsOdd<L>` == `true` into 
+/// a proof that `L` is an even number plus one.
+pub const fn odd_wit<L>(
+    is_odd: TypeEq<IsOdd<L>, Bool<true>>
+) -> (TypeEq<L, PlusOne<SubOneSat<L>>>, TypeEq<IsEven<SubOneSat<L>>, Bool<true>>)
+where
+    L: PeanoInt,
+{
+    match L::PEANO_WIT {
+        PeanoWit::Zero(l_te) => {
+            crate::boolean::contradiction(is_odd.flip().join(l_te.map(IsOddFn::NEW)))
+        }
+        PeanoWit::PlusOne(l_te) => (l_te, l_te.map(IsOddFn::NEW).flip().join(is_odd)),
+    }
+}
+
+/// Proof that `IsOdd<L>` == `Not<IsEven<L>>`
+pub const fn odd_is_not_even<L>() -> TypeEq<IsOdd<L>, Not<IsEven<L>>>
+where
+    L: PeanoInt,
+{
+    typewit::type_fn! {
+        struct RhsFn;
+        impl<L: PeanoInt> L => Not<IsEven<L>>
+    }
+
+    match L::PEANO_WIT {
+        PeanoWit::Zero(l_te) => l_te.map(IsOddFn::NEW).join(l_te.map(RhsFn).flip()),
+        PeanoWit::PlusOne(l_te) => {
+            // `IsEven<L'>` == `Not<Not<IsEven<L'>>>` == `Not<IsOdd<L'>>`
+            let pred = odd_is_not_even::<L::SubOneSat>().map(crate::boolean::NotFn::NEW).flip();
+
+            l_te.map(IsOddFn::NEW).join(pred).join(l_te.map(RhsFn).flip())
+        }
+    }
+}
+
+/// Proof that `AbsDiff<L, R>` == `AbsDiff<R, L>`
+pub const fn commutative_abs_diff<L, R>() -> TypeEq<AbsDiff<L, R>, AbsDiff<R, L>>
+where
+    L: PeanoInt,
+    R: PeanoInt,
+{
+    typewit::type_fn! {
+        struct RhsFn;
+        impl<L: PeanoInt, R: PeanoInt> (L, R) => AbsDiff<R, L>
+    }
+
+    match (L::PEANO_WIT, R::PEANO_WIT) {
+        (PeanoWit::Zero(l_te), PeanoWit::Zero(r_te)) => {
+            l_te.zip(r_te).map(AbsDiffFn::NEW).join(l_te.zip(r_te).map(RhsFn).flip())
+        }
+        (PeanoWit::Zero(l_te), PeanoWit::PlusOne(r_te)) => {
+            l_te.zip(r_te).map(AbsDiffFn::NEW).join(l_te.zip(r_te).map(RhsFn).flip())
+        }
+        (PeanoWit::PlusOne(l_te), PeanoWit::Zero(r_te)) => {
+            l_te.zip(r_te).map(AbsDiffFn::NEW).join(l_te.zip(r_te).map(RhsFn).flip())
+        }
+        (PeanoWit::PlusOne(l_te), PeanoWit::PlusOne(r_te)) => {
+            l_te.zip(r_te).map(AbsDiffFn::NEW)
+                .join(commutative_abs_diff::<L::SubOneSat, R::SubOneSat>())
+                .join(l_te.zip(r_te).map(RhsFn).flip())
+        }
+    }
+}
+
+/// Proof that `AbsDiff<L, R>` == `SubSat<R, L>` when `L <= R`
+pub const fn abs_diff_of_le<L, R>(
+    l_le_r: TypeEq<IsLe<L, R>, Bool<true>>
+) -> TypeEq<AbsDiff<L, R>, SubSat<R, L>>
+where
+    L: PeanoInt,
+    R: PeanoInt,
+{
+    match (L::PEANO_WIT, R::PEANO_WIT) {
+        (PeanoWit::Zero(l_te), _) => {
+            let r_te = TypeEq::new::<R>();
+
+            // `AbsDiff<0, R>` == `R` == `SubSat<R, 0>`
+            l_te.zip(r_te).map(AbsDiffFn::NEW)
+                .join(sub_identity::<R>().flip())
+                .join(r_te.zip(l_te).map(SubSatFn::NEW).flip())
+        }
+        (PeanoWit::PlusOne(l_te), PeanoWit::Zero(r_te)) => {
+            crate::boolean::contradiction(l_le_r.flip().join(not_le_zero(l_te, r_te)))
+        }
+        (PeanoWit::PlusOne(l_te), PeanoWit::PlusOne(r_te)) => {
+            let pred_le = l_te.zip(r_te).map(IsLeFn::NEW).flip().join(l_le_r);
+
+            l_te.zip(r_te).map(AbsDiffFn::NEW)
+                .join(abs_diff_of_le::<L::SubOneSat, R::SubOneSat>(pred_le))
+                .join(r_te.zip(l_te).map(SubSatFn::NEW).flip())
+        }
+    }
+}
+
+/// Converts a proof of `AbsDiff<L, R>` == `0` into a proof of `L == R`
+pub const fn abs_diff_zero_to_eq<L, R>(
+    diff_te: TypeEq<AbsDiff<L, R>, Zero>
+) -> TypeEq<L, R>
+where
+    L: PeanoInt,
+    R: PeanoInt,
+{
+    match (L::PEANO_WIT, R::PEANO_WIT) {
+        (PeanoWit::Zero(l_te), PeanoWit::Zero(r_te)) => l_te.join(r_te.flip()),
+        (PeanoWit::Zero(l_te), PeanoWit::PlusOne(r_te)) => {
+            contradiction(l_te.zip(r_te).map(AbsDiffFn::NEW).flip().join(diff_te))
+        }
+        (PeanoWit::PlusOne(l_te), PeanoWit::Zero(r_te)) => {
+            contradiction(l_te.zip(r_te).map(AbsDiffFn::NEW).flip().join(diff_te))
+        }
+        (PeanoWit::PlusOne(l_te), PeanoWit::PlusOne(r_te)) => {
+            let pred_te = l_te.zip(r_te).map(AbsDiffFn::NEW).flip().join(diff_te);
+
+            l_te
+                .join(abs_diff_zero_to_eq::<L::SubOneSat, R::SubOneSat>(pred_te).map(PlusOneFn))
+                .join(r_te.flip())
+        }
+    }
+}
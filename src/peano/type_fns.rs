@@ -13,6 +13,20 @@ declare_type_fn!{ AddFn, peano, "Add", PeanoInt::Add<R>, PeanoInt }
 declare_type_fn!{ MulFn, peano, "Mul", PeanoInt::Mul<R>, PeanoInt }
 declare_type_fn!{ MinFn, peano, "Min", PeanoInt::Min<R>, PeanoInt }
 declare_type_fn!{ MaxFn, peano, "Max", PeanoInt::Max<R>, PeanoInt }
+declare_type_fn!{ IsEqFn, peano, "IsEq", PeanoInt::IsEq<R>, PeanoInt }
+declare_type_fn!{ IsGtFn, peano, "IsGt", PeanoInt::IsGt<R>, PeanoInt }
+declare_type_fn!{ IsGeFn, peano, "IsGe", PeanoInt::IsGe<R>, PeanoInt }
+declare_type_fn!{ IsEvenFn, peano, "IsEven", PeanoInt::IsEven, PeanoInt }
+declare_type_fn!{ IsOddFn, peano, "IsOdd", PeanoInt::IsOdd, PeanoInt }
+declare_type_fn!{ AbsDiffFn, peano, "AbsDiff", PeanoInt::AbsDiff<R>, PeanoInt }
+
+typewit::inj_type_fn!{
+    /// Type-level function ([`TypeFn`](typewit::TypeFn) implementor) form of 
+    /// [`PlusOne`](crate::PlusOne)
+    pub struct PlusOneFn;
+
+    impl<L: PeanoInt> L => peano::PlusOne<L>
+}
 
 mod nobound {
     pub trait __NoBound {}
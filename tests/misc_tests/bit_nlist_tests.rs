@@ -0,0 +1,194 @@
+use nlist::{BitNList, Fin, NList, Peano, PeanoInt, nlist, peano};
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+
+// `Peano!` only supports integers up to 64
+type Over64<const N: usize> = peano::Add<Peano!(64), Peano!(N)>;
+
+fn hash_of<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+// constructed without `NList<bool, L>`, which is too deeply nested for long lengths
+fn alternating<L: PeanoInt>(offset: usize) -> BitNList<L> {
+    let mut bits = BitNList::zeros();
+    for i in 0..L::USIZE {
+        bits.set_fin(Fin::new(i).unwrap(), (i + offset) % 3 == 0);
+    }
+    bits
+}
+
+#[test]
+fn zeros_ones_test() {
+    fn assert_len<L: PeanoInt>() {
+        let zeros = BitNList::<L>::zeros();
+        let ones = BitNList::<L>::ones();
+
+        assert_eq!(zeros.len(), L::USIZE);
+        assert_eq!(zeros.is_empty(), L::USIZE == 0);
+        assert_eq!(zeros.count_ones(), 0);
+        assert_eq!(zeros.count_zeros(), L::USIZE);
+        assert_eq!(ones.count_ones(), L::USIZE);
+        assert_eq!(ones.count_zeros(), 0);
+
+        assert!(zeros.iter().all(|b| !b));
+        assert!(ones.iter().all(|b| b));
+
+        assert_eq!(zeros.complement(), ones);
+        assert_eq!(!ones.clone(), zeros);
+        assert_eq!(BitNList::<L>::default(), zeros);
+    }
+
+    assert_len::<Peano!(0)>();
+    assert_len::<Peano!(1)>();
+    assert_len::<Peano!(5)>();
+    assert_len::<Peano!(63)>();
+    assert_len::<Peano!(64)>();
+    assert_len::<Over64<1>>();
+    assert_len::<Over64<36>>();
+}
+
+#[test]
+fn nlist_conversion_test() {
+    fn assert_roundtrip<L: PeanoInt>() {
+        for offset in 0..3 {
+            let list = NList::<bool, L>::from_fn(|i| (i + offset) % 3 == 0);
+            let bits = BitNList::from(list.clone());
+
+            assert_eq!(bits.iter().collect::<Vec<bool>>(), list.clone().into_vec());
+            assert_eq!(bits.iter().rev().collect::<Vec<bool>>(), list.clone().reverse().into_vec());
+            assert_eq!(bits.iter().len(), L::USIZE);
+            assert_eq!(NList::from(bits), list);
+        }
+    }
+
+    assert_roundtrip::<Peano!(0)>();
+    assert_roundtrip::<Peano!(4)>();
+    assert_roundtrip::<Peano!(20)>();
+}
+
+#[test]
+fn get_set_test() {
+    const BITS: BitNList<Over64<6>> = {
+        let mut bits = BitNList::zeros();
+        bits.set::<Peano!(0)>(true);
+        bits.set::<Peano!(63)>(true);
+        bits.set::<Peano!(64)>(true);
+        bits.set::<Over64<5>>(true);
+        bits.set::<Over64<5>>(false);
+        bits.set::<Over64<4>>(true);
+        bits
+    };
+
+    assert!(BITS.get::<Peano!(0)>());
+    assert!(!BITS.get::<Peano!(1)>());
+    assert!(!BITS.get::<Peano!(62)>());
+    assert!(BITS.get::<Peano!(63)>());
+    assert!(BITS.get::<Peano!(64)>());
+    assert!(!BITS.get::<Over64<1>>());
+    assert!(BITS.get::<Over64<4>>());
+    assert!(!BITS.get::<Over64<5>>());
+    assert_eq!(BITS.count_ones(), 4);
+
+    let set_indices = BITS.iter()
+        .enumerate()
+        .filter_map(|(i, bit)| bit.then_some(i))
+        .collect::<Vec<usize>>();
+    assert_eq!(set_indices, [0, 63, 64, 68]);
+
+    let mut bits = BITS.clone();
+    for index in (0..70).map(|i| Fin::<Over64<6>>::new(i).unwrap()) {
+        assert_eq!(bits.get_fin(index), BITS.iter().nth(index.get()).unwrap());
+        bits.set_fin(index, !bits.get_fin(index));
+    }
+    assert_eq!(bits, !BITS);
+}
+
+#[test]
+fn bitwise_ops_test() {
+    fn assert_ops<L: PeanoInt>() {
+        let lhs = alternating::<L>(0);
+        let rhs = alternating::<L>(1) | alternating::<L>(0);
+
+        let zip_with = |f: fn(bool, bool) -> bool| -> Vec<bool> {
+            lhs.iter().zip(rhs.iter()).map(|(l, r)| f(l, r)).collect()
+        };
+
+        assert_eq!(lhs.and(&rhs).iter().collect::<Vec<_>>(), zip_with(|l, r| l & r));
+        assert_eq!(lhs.or(&rhs).iter().collect::<Vec<_>>(), zip_with(|l, r| l | r));
+        assert_eq!(lhs.xor(&rhs).iter().collect::<Vec<_>>(), zip_with(|l, r| l ^ r));
+        assert_eq!(lhs.complement().iter().collect::<Vec<_>>(), zip_with(|l, _| !l));
+
+        assert_eq!(lhs.clone() & rhs.clone(), lhs.and(&rhs));
+        assert_eq!(lhs.clone() | rhs.clone(), lhs.or(&rhs));
+        assert_eq!(lhs.clone() ^ rhs.clone(), lhs.xor(&rhs));
+        assert_eq!(!lhs.clone(), lhs.complement());
+
+        // operators on references don't consume the operands
+        assert_eq!(&lhs & &rhs, lhs.and(&rhs));
+        assert_eq!(&lhs | &rhs, lhs.or(&rhs));
+        assert_eq!(&lhs ^ &rhs, lhs.xor(&rhs));
+        assert_eq!(!&lhs, lhs.complement());
+
+        assert_eq!(lhs.clone() & &rhs, lhs.and(&rhs));
+        assert_eq!(&lhs | rhs.clone(), lhs.or(&rhs));
+
+        let mut assigned = lhs.clone();
+        assigned &= rhs.clone();
+        assert_eq!(assigned, lhs.and(&rhs));
+
+        let mut assigned = lhs.clone();
+        assigned |= &rhs;
+        assert_eq!(assigned, lhs.or(&rhs));
+
+        let mut assigned = lhs.clone();
+        assigned ^= &rhs;
+        assert_eq!(assigned, lhs.xor(&rhs));
+
+        // bits past the length stay unset
+        assert_eq!((!&lhs).count_ones() + lhs.count_ones(), L::USIZE);
+        assert_eq!((&lhs ^ !&lhs).count_ones(), L::USIZE);
+    }
+
+    assert_ops::<Peano!(0)>();
+    assert_ops::<Peano!(7)>();
+    assert_ops::<Peano!(64)>();
+    assert_ops::<Over64<2>>();
+}
+
+#[test]
+fn const_ops_test() {
+    type L = Peano!(4);
+
+    const LHS: BitNList<L> = BitNList::from_nlist(nlist![false, false, true, true]);
+    const RHS: BitNList<L> = BitNList::from_nlist(nlist![false, true, false, true]);
+
+    const AND: NList<bool, L> = LHS.and(&RHS).to_nlist();
+    const OR: NList<bool, L> = LHS.or(&RHS).to_nlist();
+    const XOR: NList<bool, L> = LHS.xor(&RHS).to_nlist();
+    const NOT: NList<bool, L> = LHS.complement().to_nlist();
+    const ONES: usize = BitNList::<L>::ones().count_ones();
+
+    assert_eq!(AND, nlist![false, false, false, true]);
+    assert_eq!(OR, nlist![false, true, true, true]);
+    assert_eq!(XOR, nlist![false, true, true, false]);
+    assert_eq!(NOT, nlist![true, true, false, false]);
+    assert_eq!(ONES, 4);
+}
+
+#[test]
+fn trait_impls_test() {
+    let bits = BitNList::from_nlist(nlist![true, false, true]);
+
+    assert_eq!(format!("{bits:?}"), "[true, false, true]");
+
+    assert_eq!(bits, bits.clone());
+    assert_ne!(bits, !bits.clone());
+
+    assert_eq!(hash_of(&bits), hash_of(&bits.clone()));
+    assert_ne!(hash_of(&bits), hash_of(&!bits.clone()));
+}
@@ -1 +1,2 @@
-mod boolean_ops_tests;
\ No newline at end of file
+mod boolean_ops_tests;
+mod boolean_proofs_tests;
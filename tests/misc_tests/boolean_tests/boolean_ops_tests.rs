@@ -1,8 +1,8 @@
 use nlist::{Peano, PeanoInt};
-use nlist::boolean::{self, Bool, Boolean};
-use nlist::typewit::Identity;
+use nlist::boolean::{self, Bool, Boolean, BooleanList, BoolWitG};
+use nlist::typewit::{CallFn, Identity};
 
-use crate::misc_tests::test_utils::{assert_type_eq, test_op};
+use crate::misc_tests::test_utils::{assert_type_eq, test_nonassoc_op, test_op};
 
 
 
@@ -48,6 +48,171 @@ fn xor_test() {
     }
 }
 
+#[test]
+fn implies_test() {
+    test_op! {
+        Boolean::Implies<Rhs> Implies ImpliesFn, Boolean -> Boolean, boolean =>
+        (Bool<false>, Bool<false> => Bool<true>)
+        (Bool<false>, Bool<true> => Bool<true>)
+        (Bool<true>, Bool<false> => Bool<false>)
+        (Bool<true>, Bool<true> => Bool<true>)
+    }
+}
+
+#[test]
+fn nand_test() {
+    test_op! {
+        Boolean::Nand<Rhs> Nand NandFn, Boolean -> Boolean, boolean =>
+        (Bool<false>, Bool<false> => Bool<true>)
+        (Bool<false>, Bool<true> => Bool<true>)
+        (Bool<true>, Bool<false> => Bool<true>)
+        (Bool<true>, Bool<true> => Bool<false>)
+    }
+}
+
+#[test]
+fn nor_test() {
+    test_op! {
+        Boolean::Nor<Rhs> Nor NorFn, Boolean -> Boolean, boolean =>
+        (Bool<false>, Bool<false> => Bool<true>)
+        (Bool<false>, Bool<true> => Bool<false>)
+        (Bool<true>, Bool<false> => Bool<false>)
+        (Bool<true>, Bool<true> => Bool<false>)
+    }
+}
+
+#[test]
+fn booleq_test() {
+    test_op! {
+        Boolean::BoolEq<Rhs> BoolEq BoolEqFn, Boolean -> Boolean, boolean =>
+        (Bool<false>, Bool<false> => Bool<true>)
+        (Bool<false>, Bool<true> => Bool<false>)
+        (Bool<true>, Bool<false> => Bool<false>)
+        (Bool<true>, Bool<true> => Bool<true>)
+    }
+}
+
+#[test]
+fn all_test() {
+    type F = Bool<false>;
+    type T = Bool<true>;
+
+    test_nonassoc_op! {
+        BooleanList All<> AllFn, Boolean -> Boolean, boolean =>
+        (() => T)
+        ((F,) => F)
+        ((T,) => T)
+        ((T, T) => T)
+        ((T, F) => F)
+        ((F, T) => F)
+        ((T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T) => T)
+        ((T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, F) => F)
+    }
+}
+
+#[test]
+fn any_test() {
+    type F = Bool<false>;
+    type T = Bool<true>;
+
+    test_nonassoc_op! {
+        BooleanList Any<> AnyFn, Boolean -> Boolean, boolean =>
+        (() => F)
+        ((F,) => F)
+        ((T,) => T)
+        ((F, F) => F)
+        ((T, F) => T)
+        ((F, T) => T)
+        ((F, F, F, F, F, F, F, F, F, F, F, F, F, F, F, F) => F)
+        ((F, F, F, F, F, F, F, F, F, F, F, F, F, F, F, T) => T)
+    }
+}
+
+#[test]
+fn bool_macro_test() {
+    type F = Bool<false>;
+    type T = Bool<true>;
+
+    assert_type_eq::<nlist::Bool!(true), T>();
+    assert_type_eq::<nlist::Bool!(false), F>();
+    assert_type_eq::<nlist::Bool!(!true), F>();
+    assert_type_eq::<nlist::Bool!(!!true), T>();
+    assert_type_eq::<nlist::Bool!(T), T>();
+    assert_type_eq::<nlist::Bool!({boolean::Not<F>}), T>();
+
+    // operators
+    assert_type_eq::<nlist::Bool!(true & false), F>();
+    assert_type_eq::<nlist::Bool!(true && true), T>();
+    assert_type_eq::<nlist::Bool!(true | false), T>();
+    assert_type_eq::<nlist::Bool!(false || false), F>();
+    assert_type_eq::<nlist::Bool!(true ^ true), F>();
+    assert_type_eq::<nlist::Bool!(true == false), F>();
+    assert_type_eq::<nlist::Bool!(false == false), T>();
+    assert_type_eq::<nlist::Bool!(true != false), T>();
+    assert_type_eq::<nlist::Bool!(true => false), F>();
+    assert_type_eq::<nlist::Bool!(false => false), T>();
+
+    // precedence
+    assert_type_eq::<nlist::Bool!(!false & false), F>();
+    assert_type_eq::<nlist::Bool!(false & true | true), T>();
+    assert_type_eq::<nlist::Bool!(true | true & false), T>();
+    assert_type_eq::<nlist::Bool!((true | true) & false), F>();
+    assert_type_eq::<nlist::Bool!(true ^ true & false), T>();
+    assert_type_eq::<nlist::Bool!(true | false ^ true), T>();
+    assert_type_eq::<nlist::Bool!(false | true == true), T>();
+    assert_type_eq::<nlist::Bool!(false == false => false), F>();
+
+    // associativity
+    assert_type_eq::<nlist::Bool!(false => false => false), T>();
+    assert_type_eq::<nlist::Bool!((false => false) => false), F>();
+    assert_type_eq::<nlist::Bool!(false == false == false), F>();
+    assert_type_eq::<nlist::Bool!(false == (false == false)), F>();
+    assert_type_eq::<nlist::Bool!(true != true == false), T>();
+
+    fn generic<A: Boolean, B: Boolean>() {
+        assert_type_eq::<
+            nlist::Bool!(!A | B => {boolean::Xor<A, B>}), 
+            boolean::Implies<boolean::Or<boolean::Not<A>, B>, boolean::Xor<A, B>>,
+        >();
+    }
+    generic::<F, T>();
+}
+
+#[test]
+#[allow(clippy::nonminimal_bool, clippy::overly_complex_bool_expr, clippy::bool_comparison)]
+fn bool_macro_rust_precedence_test() {
+    // asserts that `Bool!` evaluates the expression the same as Rust does
+    macro_rules! assert_same_as_rust {
+        ($($expr:tt)*) => {
+            assert_eq!(
+                matches!(<nlist::Bool!($($expr)*) as Boolean>::BOOL_WIT, BoolWitG::True(_)),
+                $($expr)*,
+                "{}",
+                stringify!($($expr)*),
+            );
+        };
+    }
+
+    assert_same_as_rust!(true || true == false);
+    assert_same_as_rust!(true == false || true);
+    assert_same_as_rust!(false && false == false);
+    assert_same_as_rust!(false == false && false);
+    assert_same_as_rust!(true != false && false);
+    assert_same_as_rust!(true ^ true && false);
+    assert_same_as_rust!(false && false | true);
+    assert_same_as_rust!(true | false && false);
+    assert_same_as_rust!(true || false && false);
+    assert_same_as_rust!(false && true || true);
+    assert_same_as_rust!(true || true & false);
+    assert_same_as_rust!(false & true || true);
+    assert_same_as_rust!(true | true == false);
+    assert_same_as_rust!(false == false | true);
+    assert_same_as_rust!(true ^ true == false);
+    assert_same_as_rust!(true & false != true);
+    assert_same_as_rust!(!false && false == false || false);
+    assert_same_as_rust!(true == false && true != false || false | true & false);
+}
+
 #[test]
 fn iftrue_test() {
     fn _alt_fn_is_equivalent<B, Then, Else>() 
@@ -0,0 +1,129 @@
+use nlist::boolean::{self, Bool, Boolean, BoolWitG, proofs};
+use nlist::typewit::TypeEq;
+
+use crate::misc_tests::test_utils::assert_type;
+
+
+macro_rules! __call_with_callback {
+    ($callback:ident $(($($b:tt)*))*) => {
+        $(let _ = $callback::<$(Bool<$b>),*>();)*
+    }
+}
+macro_rules! call_with_unary {
+    ($callback:ident) => (
+        __call_with_callback!{$callback (false) (true)}
+    )
+}
+macro_rules! call_with_binary {
+    ($callback:ident) => (
+        __call_with_callback!{$callback
+            (false false) (false true) (true false) (true true)
+        }
+    )
+}
+macro_rules! call_with_ternary {
+    ($callback:ident) => (
+        __call_with_callback!{$callback
+            (false false false) (false false true) (false true false) (false true true)
+            (true false false) (true false true) (true true false) (true true true)
+        }
+    )
+}
+
+
+#[test]
+fn double_negation_test() {
+    fn inner<A: Boolean>() {
+        let ret = const { proofs::double_negation::<A>() };
+        assert_type::<TypeEq<boolean::Not<boolean::Not<A>>, A>>(ret);
+    }
+
+    call_with_unary!{inner}
+}
+
+#[test]
+fn de_morgan_test() {
+    fn inner<A: Boolean, B: Boolean>() {
+        let ret = const { proofs::de_morgan_and::<A, B>() };
+        assert_type::<TypeEq<
+            boolean::Not<boolean::And<A, B>>, 
+            boolean::Or<boolean::Not<A>, boolean::Not<B>>,
+        >>(ret);
+
+        let ret = const { proofs::de_morgan_or::<A, B>() };
+        assert_type::<TypeEq<
+            boolean::Not<boolean::Or<A, B>>, 
+            boolean::And<boolean::Not<A>, boolean::Not<B>>,
+        >>(ret);
+    }
+
+    call_with_binary!{inner}
+}
+
+#[test]
+fn commutative_test() {
+    fn inner<A: Boolean, B: Boolean>() {
+        let ret = const { proofs::commutative_and::<A, B>() };
+        assert_type::<TypeEq<boolean::And<A, B>, boolean::And<B, A>>>(ret);
+
+        let ret = const { proofs::commutative_or::<A, B>() };
+        assert_type::<TypeEq<boolean::Or<A, B>, boolean::Or<B, A>>>(ret);
+    }
+
+    call_with_binary!{inner}
+}
+
+#[test]
+fn associative_test() {
+    fn inner<A: Boolean, B: Boolean, C: Boolean>() {
+        let ret = const { proofs::associative_and::<A, B, C>() };
+        assert_type::<TypeEq<
+            boolean::And<boolean::And<A, B>, C>, 
+            boolean::And<A, boolean::And<B, C>>,
+        >>(ret);
+
+        let ret = const { proofs::associative_or::<A, B, C>() };
+        assert_type::<TypeEq<
+            boolean::Or<boolean::Or<A, B>, C>, 
+            boolean::Or<A, boolean::Or<B, C>>,
+        >>(ret);
+    }
+
+    call_with_ternary!{inner}
+}
+
+#[test]
+fn and_true_test() {
+    fn inner<A: Boolean, B: Boolean>() {
+        let ret = const {
+            match boolean::And::<A, B>::BOOL_WIT {
+                BoolWitG::True(te) => Some((
+                    proofs::and_true_left::<A, B>(te),
+                    proofs::and_true_right::<A, B>(te),
+                )),
+                BoolWitG::False(_) => None,
+            }
+        };
+        
+        let both = matches!(
+            (A::BOOL_WIT, B::BOOL_WIT), 
+            (BoolWitG::True(_), BoolWitG::True(_)),
+        );
+        assert_eq!(both, ret.is_some());
+
+        assert_type::<Option<(TypeEq<A, Bool<true>>, TypeEq<B, Bool<true>>)>>(ret);
+
+        let ret = const {
+            match (A::BOOL_WIT, B::BOOL_WIT) {
+                (BoolWitG::True(a), BoolWitG::True(b)) => 
+                    Some(proofs::and_true_intro::<A, B>(a, b)),
+                _ => None,
+            }
+        };
+        assert_eq!(both, ret.is_some());
+
+        assert_type::<Option<TypeEq<boolean::And<A, B>, Bool<true>>>>(ret);
+    }
+
+    call_with_binary!{inner}
+}
@@ -0,0 +1,250 @@
+use nlist::{BoundedNList, NList, Peano, PeanoInt, nlist};
+
+use nlist::typewit::TypeEq;
+
+use std::cell::Cell;
+
+use crate::misc_tests::test_utils::CountDrops;
+
+
+#[test]
+fn new_test() {
+    const EMPTY: BoundedNList<u8, Peano!(3)> = BoundedNList::new();
+
+    assert_eq!(EMPTY.len(), 0);
+    assert_eq!(EMPTY.capacity(), 3);
+    assert!(EMPTY.is_empty());
+    assert!(!EMPTY.is_full());
+    assert_eq!(EMPTY.get(0), None);
+    assert_eq!(EMPTY.last(), None);
+
+    let zero_cap = BoundedNList::<u8, Peano!(0)>::default();
+    assert!(zero_cap.is_empty());
+    assert!(zero_cap.is_full());
+}
+
+#[test]
+fn getters_test() {
+    let mut list = nlist![3, 5, 8, 13].filter(|x| *x != 5);
+
+    assert_eq!(list.get(0), Some(&3));
+    assert_eq!(list.get(1), Some(&8));
+    assert_eq!(list.get(2), Some(&13));
+    assert_eq!(list.get(3), None);
+    assert_eq!(list.last(), Some(&13));
+
+    *list.get_mut(1).unwrap() += 100;
+    assert_eq!(list.get_mut(3), None);
+
+    assert_eq!(list.into_vec(), [3, 108, 13]);
+}
+
+#[test]
+fn try_into_nlist_test() {
+    let list = nlist![3, 5, 8, 13].filter(|x| *x % 2 == 1);
+
+    let list = list.try_into_nlist::<Peano!(2)>().unwrap_err();
+    let list = list.try_into_nlist::<Peano!(4)>().unwrap_err();
+    let list: NList<u32, Peano!(3)> = list.try_into_nlist().unwrap();
+
+    assert_eq!(list, nlist![3, 5, 13]);
+
+    assert_eq!(BoundedNList::<u8, Peano!(2)>::new().try_into_nlist::<Peano!(0)>().unwrap(), nlist![0u8; 0]);
+}
+
+#[test]
+fn drop_test() {
+    let drops = Cell::new(0);
+
+    let list = NList::<_, Peano!(5)>::from_fn(|i| CountDrops(&drops, i as u8))
+        .filter(|x| x.1 % 2 == 0);
+    assert_eq!(drops.get(), 2);
+
+    drop(list);
+    assert_eq!(drops.get(), 5);
+
+    drops.set(0);
+    let list = NList::<_, Peano!(4)>::from_fn(|i| CountDrops(&drops, i as u8))
+        .take_while(|x| x.1 < 2);
+    assert_eq!(drops.get(), 2);
+
+    let list: NList<_, Peano!(2)> = list.try_into_nlist().ok().unwrap();
+    assert_eq!(drops.get(), 2);
+    assert_eq!(list.map(|x| x.1), nlist![0, 1]);
+    assert_eq!(drops.get(), 4);
+}
+
+#[test]
+fn traits_test() {
+    let list = nlist![3, 5, 8, 13].filter(|x| *x > 4);
+
+    assert_eq!(format!("{:?}", list), "[5, 8, 13]");
+    assert_eq!(list.clone(), list);
+
+    assert_eq!(list, nlist![5, 8, 13, 21, 34].take_while(|x| *x < 20));
+    assert_ne!(list, nlist![5, 8].filter(|_| true));
+    assert_ne!(list, nlist![5, 8, 14].filter(|_| true));
+
+    // comparing lists with different capacities, where one of them is full
+    assert_eq!(nlist![5, 8, 13].filter(|_| true), list);
+    assert_eq!(list, nlist![5, 8, 13].filter(|_| true));
+    assert_ne!(nlist![5, 8, 21].filter(|_| true), list);
+}
+
+#[test]
+fn from_nlist_test() {
+    const LIST: BoundedNList<u8, Peano!(4)> = BoundedNList::from_nlist(nlist![3, 5]);
+    assert_eq!(LIST.len(), 2);
+    assert_eq!(LIST.into_vec(), [3, 5]);
+
+    let full: BoundedNList<u8, Peano!(3)> = BoundedNList::from_nlist(nlist![3, 5, 8]);
+    assert!(full.is_full());
+    assert_eq!(full.into_vec(), [3, 5, 8]);
+
+    let from: BoundedNList<u8, Peano!(3)> = nlist![3].into();
+    assert_eq!(from.into_vec(), [3]);
+
+    let alt = BoundedNList::<u8, Peano!(3)>::from_nlist_alt(nlist![3, 5], TypeEq::NEW);
+    assert_eq!(alt.into_vec(), [3, 5]);
+}
+
+#[test]
+fn push_pop_test() {
+    let mut list = BoundedNList::<u8, Peano!(2)>::new();
+
+    list.push(3);
+    assert_eq!(list.try_push(5), Ok(()));
+    assert_eq!(list.try_push(8), Err(8));
+    assert!(list.is_full());
+    assert_eq!(list.last(), Some(&5));
+
+    assert_eq!(list.pop(), Some(5));
+    assert_eq!(list.pop(), Some(3));
+    assert_eq!(list.pop(), None);
+    assert!(list.is_empty());
+
+    list.push(13);
+    assert_eq!(list.into_vec(), [13]);
+}
+
+#[test]
+#[should_panic]
+fn push_full_test() {
+    let mut list = BoundedNList::<u8, Peano!(1)>::new();
+    list.push(3);
+    list.push(5);
+}
+
+#[test]
+fn insert_remove_test() {
+    let mut list = BoundedNList::<u8, Peano!(5)>::new();
+
+    list.insert(0, 8);
+    list.insert(0, 3);
+    list.insert(2, 21);
+    list.insert(1, 5);
+    list.insert(3, 13);
+    assert_eq!(list.clone().into_vec(), [3, 5, 8, 13, 21]);
+
+    assert_eq!(list.remove(4), 21);
+    assert_eq!(list.remove(0), 3);
+    assert_eq!(list.remove(1), 8);
+    assert_eq!(list.clone().into_vec(), [5, 13]);
+
+    list.push(34);
+    assert_eq!(list.into_vec(), [5, 13, 34]);
+}
+
+#[test]
+#[should_panic]
+fn insert_out_of_bounds_test() {
+    let mut list = BoundedNList::<u8, Peano!(3)>::new();
+    list.push(3);
+    list.insert(2, 5);
+}
+
+#[test]
+#[should_panic]
+fn insert_full_test() {
+    let mut list = BoundedNList::<u8, Peano!(1)>::new();
+    list.push(3);
+    list.insert(0, 5);
+}
+
+#[test]
+#[should_panic]
+fn remove_out_of_bounds_test() {
+    let mut list = BoundedNList::<u8, Peano!(3)>::from_nlist(nlist![3, 5]);
+    list.remove(2);
+}
+
+#[test]
+fn truncate_test() {
+    let drops = Cell::new(0);
+
+    let mut list: BoundedNList<_, Peano!(6)> = 
+        BoundedNList::from_nlist(NList::<_, Peano!(4)>::from_fn(|i| CountDrops(&drops, i as u8)));
+
+    list.truncate(5);
+    assert_eq!(drops.get(), 0);
+
+    list.truncate(2);
+    assert_eq!(drops.get(), 2);
+    assert_eq!(list.len(), 2);
+    assert_eq!(list.last().unwrap().1, 1);
+
+    list.truncate(0);
+    assert_eq!(drops.get(), 4);
+    assert!(list.is_empty());
+}
+
+#[test]
+fn extend_from_nlist_test() {
+    let mut list = BoundedNList::<u8, Peano!(5)>::new();
+
+    list.extend_from_nlist(nlist![3, 5]);
+    list.extend_from_nlist(NList::nil());
+    list.extend_from_nlist(nlist![8, 13, 21]);
+    assert_eq!(list.into_vec(), [3, 5, 8, 13, 21]);
+}
+
+#[test]
+#[should_panic]
+fn extend_from_nlist_overflow_test() {
+    let mut list = BoundedNList::<u8, Peano!(3)>::from_nlist(nlist![3, 5]);
+    list.extend_from_nlist(nlist![8, 13]);
+}
+
+#[test]
+fn const_construction_test() {
+    const fn sum_all<Cap: PeanoInt>(mut list: BoundedNList<u32, Cap>) -> u32 {
+        let mut sum = 0;
+        while let Some(x) = list.pop() {
+            sum += x;
+        }
+        list.assert_empty_drop();
+        sum
+    }
+
+    const LIST: BoundedNList<u32, Peano!(6)> = {
+        let mut list = BoundedNList::from_nlist(nlist![3, 13]);
+        list.insert(1, 5);
+        list.extend_from_nlist(nlist![21, 34]);
+        list.insert(2, 8);
+        assert!(list.try_push(55).is_err());
+        _ = list.remove(5);
+        list.push(89);
+        list
+    };
+
+    const SUM: u32 = sum_all(LIST);
+
+    assert_eq!(LIST.into_vec(), [3, 5, 8, 13, 21, 89]);
+    assert_eq!(SUM, 139);
+}
+
+#[test]
+#[should_panic]
+fn assert_empty_drop_test() {
+    BoundedNList::<u8, Peano!(1)>::from_nlist(nlist![3]).assert_empty_drop();
+}
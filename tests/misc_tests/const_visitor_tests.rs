@@ -0,0 +1,161 @@
+use nlist::{ConstFolder, ConstMapper, ConstVisitor, NList, Peano, PeanoInt, nlist};
+
+
+nlist::const_visitor! {
+    struct ByValue;
+
+    map_const = |elem: u8| -> u16 { elem as u16 * 300 };
+
+    fold_const = |accum: u32, elem: u8| -> u32 { accum * 10 + elem as u32 };
+
+    for_each_const = |elem: u8| { let _ = elem; };
+}
+
+nlist::const_visitor! {
+    pub(crate) struct ByRef;
+
+    map_const = |elem: &u8| -> (u8, bool) { (*elem, *elem % 2 == 0) };
+
+    fold_const = |accum: u32, elem: &u8| { accum * 10 + *elem as u32 };
+}
+
+nlist::const_visitor! {
+    /// Only has a `for_each_const` function
+    pub struct ByMut;
+
+    for_each_const = |elem: &mut u8,| { *elem += 1 };
+}
+
+nlist::const_visitor! {
+    struct MutMap;
+
+    map_const = |elem: &mut u8| -> u8 {
+        let prev = *elem;
+        *elem *= 2;
+        prev
+    };
+
+    fold_const = |accum: u8, elem: &mut u8| {
+        *elem = accum;
+        accum + 1
+    };
+}
+
+#[derive(Debug, PartialEq)]
+struct NonCopy(u32);
+
+nlist::const_visitor! {
+    struct NonCopyVisitor;
+
+    map_const = |elem: NonCopy| -> (u32, u32) {
+        let NonCopy(x) = elem;
+        (x, x * 2)
+    };
+
+    fold_const = |accum: u32, elem: &NonCopy| { accum + elem.0 };
+}
+
+
+#[test]
+fn by_value_test() {
+    const MAPPED: NList<u16, Peano!(3)> = ByValue::map_const(nlist![3, 5, 8]);
+    assert_eq!(MAPPED, nlist![900, 1500, 2400]);
+
+    const FOLDED: u32 = ByValue::fold_const(nlist![3, 5, 8], 1);
+    assert_eq!(FOLDED, 1358);
+
+    const EMPTY: u32 = ByValue::fold_const(nlist![], 1);
+    assert_eq!(EMPTY, 1);
+
+    const { ByValue::for_each_const(nlist![3, 5, 8]) };
+}
+
+#[test]
+fn by_ref_test() {
+    const MAPPED: NList<(u8, bool), Peano!(3)> = ByRef::map_const(&nlist![3, 5, 8]);
+    assert_eq!(MAPPED, nlist![(3, false), (5, false), (8, true)]);
+
+    const FOLDED: u32 = ByRef::fold_const(&nlist![3, 5, 8], 2);
+    assert_eq!(FOLDED, 2358);
+
+}
+
+#[test]
+fn non_copy_test() {
+    const MAPPED: NList<(u32, u32), Peano!(2)> =
+        NonCopyVisitor::map_const(nlist![NonCopy(3), NonCopy(5)]);
+    assert_eq!(MAPPED, nlist![(3, 6), (5, 10)]);
+
+    let list = nlist![NonCopy(3), NonCopy(5), NonCopy(8)];
+    assert_eq!(NonCopyVisitor::fold_const(&list, 0), 16);
+    assert_eq!(list, nlist![NonCopy(3), NonCopy(5), NonCopy(8)]);
+}
+
+#[test]
+fn by_mut_test() {
+    const INCREMENTED: NList<u8, Peano!(3)> = {
+        let mut list = nlist![3, 5, 8];
+        ByMut::for_each_const(&mut list);
+        list
+    };
+    assert_eq!(INCREMENTED, nlist![4, 6, 9]);
+
+    const MAPPED: (NList<u8, Peano!(3)>, NList<u8, Peano!(3)>) = {
+        let mut list = nlist![3, 5, 8];
+        let mapped = MutMap::map_const(&mut list);
+        (mapped, list)
+    };
+    assert_eq!(MAPPED, (nlist![3, 5, 8], nlist![6, 10, 16]));
+
+    const FOLDED: (u8, NList<u8, Peano!(4)>) = {
+        let mut list = nlist![0; 4];
+        let folded = MutMap::fold_const(&mut list, 10);
+        (folded, list)
+    };
+    assert_eq!(FOLDED, (14, nlist![10, 11, 12, 13]));
+}
+
+#[test]
+fn trait_test() {
+    assert_eq!(nlist![3u8, 5, 8].map_const::<ByValue>(), nlist![900, 1500, 2400]);
+    assert_eq!(nlist![3u8, 5, 8].fold_const::<ByValue>(1), 1358);
+    nlist![3u8, 5, 8].for_each_const::<ByValue>();
+
+    let mut list = nlist![3u8, 5, 8];
+
+    assert_eq!(list.each_ref().map_const::<ByRef>(), nlist![(3, false), (5, false), (8, true)]);
+    assert_eq!(list.each_ref().fold_const::<ByRef>(2), 2358);
+
+    list.each_mut().for_each_const::<ByMut>();
+    assert_eq!(list, nlist![4, 6, 9]);
+
+    assert_eq!(list.each_mut().map_const::<MutMap>(), nlist![4, 6, 9]);
+    assert_eq!(list, nlist![8, 12, 18]);
+
+    assert_eq!(list.each_mut().fold_const::<MutMap>(10), 13);
+    assert_eq!(list, nlist![10, 11, 12]);
+
+    let non_copy = nlist![NonCopy(3), NonCopy(5)];
+    assert_eq!(non_copy.each_ref().fold_const::<NonCopyVisitor>(0), 8);
+    assert_eq!(non_copy.map_const::<NonCopyVisitor>(), nlist![(3, 6), (5, 10)]);
+}
+
+#[test]
+fn trait_generic_test() {
+    fn map_fold<V, L>(list: NList<u8, L>) -> (NList<(u8, bool), L>, u32)
+    where
+        V: for<'a> ConstMapper<&'a u8, Output = (u8, bool)>,
+        V: for<'a> ConstFolder<&'a u8, Accum = u32>,
+        L: PeanoInt,
+    {
+        (list.each_ref().map_const::<V>(), list.each_ref().fold_const::<V>(0))
+    }
+
+    fn visit<V: ConstVisitor<u8>, L: PeanoInt>(list: NList<u8, L>) {
+        list.for_each_const::<V>()
+    }
+
+    assert_eq!(map_fold::<ByRef, _>(nlist![3, 8]), (nlist![(3, false), (8, true)], 38));
+
+    visit::<ByValue, _>(nlist![3, 5]);
+}
@@ -1,4 +1,4 @@
-use nlist::{NList, Peano, PeanoInt, nlist, peano, nlist_pat};
+use nlist::{NList, NList2D, Peano, PeanoInt, nlist, peano, nlist_pat};
 
 // ensures that fixed-length `nlist_pat`s infer the length of the NList
 #[test]
@@ -90,6 +90,37 @@ fn nlist_pat_pattern_matching() {
     assert_eq!(res, 5);
 }
 
+#[test]
+fn nlist_pat_nested_test() {
+    let list: NList2D<u32, Peano!(2), Peano!(2)> = nlist![nlist![3, 5], nlist![8, 13]];
+
+    {
+        let nlist_pat![nlist_pat![a, b], nlist_pat![c, d]] = list.clone();
+        assert_eq!([a, b, c, d], [3, 5, 8, 13]);
+    }
+    {
+        let nlist_pat![nlist_pat![a, b], nlist::nlist_pat!(c, d)] = &list;
+        assert_eq!([a, b, c, d], [&3, &5, &8, &13]);
+    }
+    {
+        let mut list = list.clone();
+        let nlist_pat![nlist_pat![a, ..], nlist_pat![_, rest @ ..]] = &mut list;
+        *a += 100;
+        *rest.head_mut() += 200;
+        assert_eq!(list, nlist![nlist![103, 5], nlist![8, 213]]);
+    }
+}
+
+#[test]
+fn nlist_pat_nested_pattern_matching() {
+    const fn first_is_zero(list: &NList2D<u8, Peano!(2), Peano!(2)>) -> bool {
+        matches!(list, nlist_pat![nlist_pat![0, _], ..])
+    }
+
+    assert!(first_is_zero(&nlist![nlist![0, 1], nlist![2, 3]]));
+    assert!(!first_is_zero(&nlist![nlist![1, 0], nlist![0, 3]]));
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 
 #[test]
@@ -230,3 +261,128 @@ fn unlist_split2() {
 
 
 
+
+#[test]
+fn unlist_rem_at_start() {
+    const fn inner<T, L>(list: NList<T, peano::Add<Peano!(2), L>>) -> (NList<T, L>, T, T)
+    where
+        L: PeanoInt
+    {
+        nlist::unlist!{[rem @ .., a, b] = list}
+        
+        (rem.coerce_len(peano::proofs::sub_identity()), a, b)
+    }
+
+    assert_eq!(inner(nlist![3, 5]), (nlist![], 3, 5));
+    assert_eq!(inner(nlist![3, 5, 8, 13]), (nlist![3, 5], 8, 13));
+}
+
+#[test]
+fn unlist_rem_in_middle() {
+    const fn inner<T, L>(list: NList<T, peano::Add<Peano!(3), L>>) -> (T, NList<T, L>, T, T)
+    where
+        L: PeanoInt
+    {
+        nlist::unlist!{[a, rem @ .., b, c] = list}
+        
+        (a, rem.coerce_len(peano::proofs::sub_identity()), b, c)
+    }
+
+    assert_eq!(inner(nlist![3, 5, 8]), (3, nlist![], 5, 8));
+    assert_eq!(inner(nlist![3, 5, 8, 13, 21]), (3, nlist![5, 8], 13, 21));
+}
+
+#[test]
+fn unlist_rem_variants() {
+    macro_rules! test_case {
+        ($($rest:tt)*) => ({
+            const fn inner<T: Copy>(list: NList<T, Peano!(4)>) -> (T, T) {
+                nlist::unlist!{[a, $($rest)* b] = list}
+                
+                (a, b)
+            }
+
+            assert_eq!(inner(nlist![3, 5, 8, 13]), (3, 13));
+        })
+    }
+
+    test_case!{.., }
+    test_case!{_ @ .., }
+    test_case!{_, .., }
+    test_case!{.., _, }
+    test_case!{_, _, .., }
+    test_case!{.., _, _, }
+}
+
+#[test]
+fn unlist_rem_fixed_len() {
+    let list = nlist![3, 5, 8, 13, 21];
+
+    {
+        nlist::unlist!{[a, rem @ .., b] = list.clone()}
+        assert_eq!(a, 3);
+        assert_eq!(rem, nlist![5, 8, 13]);
+        assert_eq!(b, 21);
+    }
+    {
+        nlist::unlist!{[rem @ .., a, b, c, d, e,] = list.clone()}
+        assert_eq!(rem, nlist![0; 0]);
+        assert_eq!([a, b, c, d, e], [3, 5, 8, 13, 21]);
+    }
+    {
+        nlist::unlist!{[a, b, mut rem @ .., c, d] = list.clone()}
+        *rem.head_mut() += 100;
+        assert_eq!([a, b, c, d], [3, 5, 13, 21]);
+        assert_eq!(rem, nlist![108]);
+    }
+}
+
+#[test]
+fn unlist_nested() {
+    const fn inner<T>(list: NList2D<T, Peano!(2), Peano!(2)>) -> [T; 4] {
+        nlist::unlist!{[nlist_pat![a, b], nlist::nlist_pat![c, d]] = list}
+
+        [a, b, c, d]
+    }
+
+    assert_eq!(inner(nlist![nlist![3, 5], nlist![8, 13]]), [3, 5, 8, 13]);
+}
+
+#[test]
+fn unlist_nested_rem() {
+    const fn inner<T, L>(
+        list: NList2D<T, Peano!(3), peano::Add<Peano!(1), L>>,
+    ) -> (T, NList<T, L>, NList2D<T, Peano!(1), peano::Add<Peano!(1), L>>, T, NList<T, L>)
+    where
+        L: PeanoInt
+    {
+        nlist::unlist!{[nlist_pat![a, rest @ ..], middle @ .., nlist_pat!{b, c @ ..}] = list}
+
+        (a, rest, middle, b, c)
+    }
+
+    assert_eq!(
+        inner(nlist![nlist![3, 5], nlist![8, 13], nlist![21, 34]]),
+        (3, nlist![5], nlist![nlist![8, 13]], 21, nlist![34]),
+    );
+}
+
+#[test]
+fn unlist_nested_rem_not_at_end() {
+    let list: NList2D<u32, Peano!(2), Peano!(4)> = nlist![nlist![3, 5, 8, 13], nlist![21, 34, 55, 89]];
+
+    nlist::unlist!{[nlist_pat![a, middle @ .., b], nlist_pat![.., c, d]] = list}
+
+    assert_eq!((a, middle, b), (3, nlist![5, 8], 13));
+    assert_eq!((c, d), (55, 89));
+}
+
+#[test]
+fn unlist_nested_len_inference() {
+    nlist::unlist!{
+        [nlist_pat![a, b], nlist_pat![c, d]] = 
+            NList::from_fn(|i| NList::from_fn(|j| i * 10 + j))
+    }
+
+    assert_eq!([a, b, c, d], [0, 1, 10, 11]);
+}
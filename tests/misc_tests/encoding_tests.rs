@@ -0,0 +1,138 @@
+use nlist::{NList, Peano, PeanoInt, nlist};
+use nlist::encoding::{Be, Decode, Encode, Le};
+
+use std::fmt::Debug;
+
+
+fn assert_roundtrip<T>(value: T, expected: &[u8])
+where
+    T: Decode + Debug + PartialEq,
+{
+    let bytes = value.encode();
+
+    assert_eq!(<T::Size as PeanoInt>::USIZE, expected.len());
+    assert_eq!(bytes.clone().into_vec(), expected, "{value:?}");
+    assert_eq!(T::decode(bytes), value);
+}
+
+#[test]
+fn integer_test() {
+    assert_roundtrip(0xABu8, &[0xAB]);
+    assert_roundtrip(-2i8, &[0xFE]);
+
+    assert_roundtrip(Le(0xABu8), &[0xAB]);
+    assert_roundtrip(Be(0xABu8), &[0xAB]);
+    assert_roundtrip(Le(-2i8), &[0xFE]);
+    assert_roundtrip(Be(-2i8), &[0xFE]);
+
+    assert_roundtrip(Le(0x0102u16), &[2, 1]);
+    assert_roundtrip(Be(0x0102u16), &[1, 2]);
+    assert_roundtrip(Le(-2i16), &[0xFE, 0xFF]);
+    assert_roundtrip(Be(-2i16), &[0xFF, 0xFE]);
+
+    assert_roundtrip(Le(0x01020304u32), &[4, 3, 2, 1]);
+    assert_roundtrip(Be(0x01020304u32), &[1, 2, 3, 4]);
+    assert_roundtrip(Le(-2i32), &[0xFE, 0xFF, 0xFF, 0xFF]);
+    assert_roundtrip(Be(-2i32), &[0xFF, 0xFF, 0xFF, 0xFE]);
+
+    assert_roundtrip(Le(0x0102030405060708u64), &[8, 7, 6, 5, 4, 3, 2, 1]);
+    assert_roundtrip(Be(0x0102030405060708u64), &[1, 2, 3, 4, 5, 6, 7, 8]);
+    assert_roundtrip(Le(i64::MIN), &[0, 0, 0, 0, 0, 0, 0, 0x80]);
+    assert_roundtrip(Be(i64::MIN), &[0x80, 0, 0, 0, 0, 0, 0, 0]);
+
+    let ascending: Vec<u8> = (1..=16).collect();
+    let descending: Vec<u8> = (1..=16).rev().collect();
+    assert_roundtrip(Le(0x0102030405060708090A0B0C0D0E0F10u128), &descending);
+    assert_roundtrip(Be(0x0102030405060708090A0B0C0D0E0F10u128), &ascending);
+    assert_roundtrip(Le(-1i128), &[0xFF; 16]);
+    assert_roundtrip(Be(i128::MAX), &[0x7F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]);
+}
+
+#[test]
+fn const_integer_test() {
+    const LE_BYTES: NList<u8, Peano!(2)> = Le(0x0102u16).encode();
+    const BE_BYTES: NList<u8, Peano!(8)> = Be(-2i64).encode();
+
+    const LE_DECODED: Le<u16> = Le::<u16>::decode(LE_BYTES);
+    const BE_DECODED: Be<i64> = Be::<i64>::decode(BE_BYTES);
+
+    assert_eq!(LE_BYTES, nlist![2, 1]);
+    assert_eq!(BE_BYTES, nlist![0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE]);
+    assert_eq!(LE_DECODED, Le(0x0102));
+    assert_eq!(BE_DECODED, Be(-2));
+}
+
+#[test]
+fn array_test() {
+    assert_roundtrip::<[Be<u32>; 0]>([], &[]);
+    assert_roundtrip([Be(0x0102u16), Be(0x0304)], &[1, 2, 3, 4]);
+    assert_roundtrip([Le(0x0102u16), Le(0x0304)], &[2, 1, 4, 3]);
+    assert_roundtrip([[1u8, 2], [3, 4], [5, 6]], &[1, 2, 3, 4, 5, 6]);
+    assert_roundtrip([[Le(1u16); 0]; 3], &[]);
+}
+
+#[test]
+fn nlist_test() {
+    assert_roundtrip(NList::nil::<Be<u64>>(), &[]);
+    assert_roundtrip(nlist![Be(0x0102u16), Be(0x0304), Be(0x0506)], &[1, 2, 3, 4, 5, 6]);
+    assert_roundtrip(nlist![Le(0x0102u16), Le(0x0304), Le(0x0506)], &[2, 1, 4, 3, 6, 5]);
+    assert_roundtrip(nlist![nlist![1u8, 2], nlist![3, 4]], &[1, 2, 3, 4]);
+    assert_roundtrip(nlist![[Be(1i16), Be(-1)], [Be(2), Be(-2)]], &[0, 1, 0xFF, 0xFF, 0, 2, 0xFF, 0xFE]);
+}
+
+#[test]
+fn tuple_test() {
+    assert_roundtrip((), &[]);
+    assert_roundtrip((1u8,), &[1]);
+    assert_roundtrip((1u8, Be(0x0203u16)), &[1, 2, 3]);
+    assert_roundtrip((Le(0x0102u16), (), Be(0x0304u16)), &[2, 1, 3, 4]);
+    assert_roundtrip((1u8, 2i8, 3u8, Le(4u8)), &[1, 2, 3, 4]);
+    assert_roundtrip((1u8, 2u8, 3u8, 4u8, 5u8), &[1, 2, 3, 4, 5]);
+    assert_roundtrip((1u8, 2u8, 3u8, 4u8, 5u8, 6u8), &[1, 2, 3, 4, 5, 6]);
+    assert_roundtrip((1u8, 2u8, 3u8, 4u8, 5u8, 6u8, 7u8), &[1, 2, 3, 4, 5, 6, 7]);
+    assert_roundtrip(
+        (1u8, 2u8, 3u8, 4u8, 5u8, 6u8, 7u8, [Be(0x0809u16)]),
+        &[1, 2, 3, 4, 5, 6, 7, 8, 9],
+    );
+    assert_roundtrip(
+        ((1u8, Be(0x0203u16)), nlist![(4u8, 5u8)], [Le(0x0706u16)]),
+        &[1, 2, 3, 4, 5, 6, 7],
+    );
+}
+
+#[test]
+fn user_type_test() {
+    #[derive(Debug, PartialEq)]
+    struct Packet {
+        tag: u8,
+        payload: [Be<u16>; 2],
+    }
+
+    type PacketFields = (u8, [Be<u16>; 2]);
+
+    impl Encode for Packet {
+        type Size = <PacketFields as Encode>::Size;
+
+        fn encode(&self) -> NList<u8, Self::Size> {
+            (self.tag, self.payload).encode()
+        }
+    }
+
+    impl Decode for Packet {
+        fn decode(bytes: NList<u8, Self::Size>) -> Self {
+            let (tag, payload) = PacketFields::decode(bytes);
+            Packet { tag, payload }
+        }
+    }
+
+    let packet = Packet { tag: 7, payload: [Be(0x0102), Be(0x0304)] };
+    let bytes: NList<u8, Peano!(5)> = packet.encode();
+
+    assert_eq!(bytes, nlist![7, 1, 2, 3, 4]);
+    assert_eq!(Packet::decode(bytes), packet);
+
+    assert_roundtrip(
+        nlist![Packet { tag: 1, payload: [Be(2), Be(3)] }, Packet { tag: 4, payload: [Be(5), Be(6)] }],
+        &[1, 0, 2, 0, 3, 4, 0, 5, 0, 6],
+    );
+}
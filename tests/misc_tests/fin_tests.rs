@@ -0,0 +1,100 @@
+use nlist::{Fin, NList, Peano, PeanoInt, nlist};
+
+
+fn fin<L: PeanoInt>(index: usize) -> Fin<L> {
+    Fin::new(index).unwrap()
+}
+
+#[test]
+fn new_test() {
+    assert_eq!(Fin::<Peano!(0)>::new(0), None);
+
+    assert_eq!(Fin::<Peano!(3)>::new(0).map(Fin::get), Some(0));
+    assert_eq!(Fin::<Peano!(3)>::new(2).map(Fin::get), Some(2));
+    assert_eq!(Fin::<Peano!(3)>::new(3), None);
+    assert_eq!(Fin::<Peano!(3)>::new(usize::MAX), None);
+
+    const FROM_PEANO: Fin<Peano!(3)> = Fin::from_peano::<Peano!(2)>();
+    assert_eq!(FROM_PEANO.get(), 2);
+    assert_eq!(usize::from(FROM_PEANO), 2);
+}
+
+#[test]
+fn all_test() {
+    assert_eq!(Fin::<Peano!(0)>::all(), NList::nil());
+    assert_eq!(Fin::<Peano!(1)>::all().map(Fin::get), nlist![0]);
+
+    const ALL: NList<Fin<Peano!(4)>, Peano!(4)> = Fin::all();
+    assert_eq!(ALL.map(Fin::get), nlist![0, 1, 2, 3]);
+}
+
+#[test]
+fn wrapping_arithmetic_test() {
+    type L = Peano!(4);
+
+    assert_eq!(fin::<L>(0).wrapping_add(0).get(), 0);
+    assert_eq!(fin::<L>(1).wrapping_add(2).get(), 3);
+    assert_eq!(fin::<L>(3).wrapping_add(1).get(), 0);
+    assert_eq!(fin::<L>(3).wrapping_add(6).get(), 1);
+    assert_eq!(fin::<L>(3).wrapping_add(usize::MAX).get(), (3 + usize::MAX % 4) % 4);
+
+    assert_eq!(fin::<L>(3).wrapping_sub(0).get(), 3);
+    assert_eq!(fin::<L>(3).wrapping_sub(2).get(), 1);
+    assert_eq!(fin::<L>(0).wrapping_sub(1).get(), 3);
+    assert_eq!(fin::<L>(1).wrapping_sub(8).get(), 1);
+    assert_eq!(fin::<L>(1).wrapping_sub(usize::MAX).get(), 2);
+
+    assert_eq!(fin::<Peano!(1)>(0).wrapping_add(5).get(), 0);
+    assert_eq!(fin::<Peano!(1)>(0).wrapping_sub(5).get(), 0);
+
+    const WRAPPED: Fin<Peano!(3)> = Fin::from_peano::<Peano!(2)>().wrapping_add(2);
+    assert_eq!(WRAPPED.get(), 1);
+}
+
+#[test]
+fn checked_arithmetic_test() {
+    type L = Peano!(4);
+
+    assert_eq!(fin::<L>(1).checked_add(2), Some(fin(3)));
+    assert_eq!(fin::<L>(1).checked_add(3), None);
+    assert_eq!(fin::<L>(1).checked_add(usize::MAX), None);
+
+    assert_eq!(fin::<L>(3).checked_sub(3), Some(fin(0)));
+    assert_eq!(fin::<L>(3).checked_sub(4), None);
+}
+
+#[test]
+fn widen_test() {
+    let widened: Fin<Peano!(5)> = fin::<Peano!(3)>(2).widen();
+    assert_eq!(widened.get(), 2);
+
+    let same: Fin<Peano!(3)> = fin::<Peano!(3)>(2).widen();
+    assert_eq!(same.get(), 2);
+}
+
+#[test]
+fn traits_test() {
+    type L = Peano!(4);
+
+    assert_eq!(format!("{:?}", fin::<L>(3)), "3");
+    assert_eq!(format!("{}", fin::<L>(3)), "3");
+
+    assert!(fin::<L>(1) < fin::<L>(2));
+    assert_eq!(fin::<L>(2).cmp(&fin(2)), core::cmp::Ordering::Equal);
+    assert_ne!(fin::<L>(1), fin(2));
+}
+
+#[test]
+fn get_fin_test() {
+    const LIST: NList<u8, Peano!(3)> = nlist![3, 5, 8];
+    const MIDDLE: &u8 = LIST.get_fin(Fin::from_peano::<Peano!(1)>());
+    assert_eq!(*MIDDLE, 5);
+
+    assert_eq!(Fin::all().map(|i| LIST.get_fin(i)), nlist![&3, &5, &8]);
+
+    let mut list = LIST;
+    for i in Fin::<Peano!(3)>::all().into_vec() {
+        *list.get_fin_mut(i) += i.get() as u8 * 100;
+    }
+    assert_eq!(list, nlist![3, 105, 208]);
+}
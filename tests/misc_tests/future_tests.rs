@@ -0,0 +1,204 @@
+use nlist::{Fin, NList, Peano, nlist};
+
+use std::{
+    cell::Cell,
+    future::{pending, ready, Future},
+    pin::{pin, Pin},
+    sync::Arc,
+    task::{Context, Poll, Wake, Waker},
+};
+
+
+struct NoopWake;
+
+impl Wake for NoopWake {
+    fn wake(self: Arc<Self>) {}
+}
+
+// polls `fut` until it completes, returning its output and how many times it was polled
+fn block_on<F: Future>(fut: F) -> (F::Output, usize) {
+    let waker = Waker::from(Arc::new(NoopWake));
+    let mut cx = Context::from_waker(&waker);
+    let mut fut = pin!(fut);
+
+    for polls in 1.. {
+        if let Poll::Ready(x) = fut.as_mut().poll(&mut cx) {
+            return (x, polls);
+        }
+    }
+    unreachable!()
+}
+
+fn poll_once<F: Future + Unpin>(fut: &mut F) -> Poll<F::Output> {
+    let waker = Waker::from(Arc::new(NoopWake));
+    Pin::new(fut).poll(&mut Context::from_waker(&waker))
+}
+
+// future that is pending `remaining` times before returning `value`
+struct Countdown<'a, T> {
+    remaining: usize,
+    value: Option<T>,
+    polls: &'a Cell<usize>,
+}
+
+impl<T: Unpin> Future for Countdown<'_, T> {
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        self.polls.set(self.polls.get() + 1);
+
+        if self.remaining == 0 {
+            Poll::Ready(self.value.take().expect("polled after completion"))
+        } else {
+            self.remaining -= 1;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+fn countdown<T>(remaining: usize, value: T, polls: &Cell<usize>) -> Countdown<'_, T> {
+    Countdown { remaining, value: Some(value), polls }
+}
+
+
+#[test]
+fn join_empty_test() {
+    let list: NList<core::future::Ready<u8>, Peano!(0)> = nlist![];
+
+    assert_eq!(block_on(list.join()), (nlist![], 1));
+}
+
+#[test]
+fn join_test() {
+    let polls = [const { Cell::new(0) }; 3];
+
+    let list = nlist![
+        countdown(2, 'a', &polls[0]),
+        countdown(0, 'b', &polls[1]),
+        countdown(4, 'c', &polls[2]),
+    ];
+
+    assert_eq!(block_on(list.join()), (nlist!['a', 'b', 'c'], 5));
+
+    // completed futures are not polled again
+    assert_eq!(polls.each_ref().map(Cell::get), [3, 1, 5]);
+}
+
+#[test]
+fn join_pinned_test() {
+    async fn double(x: u32) -> u32 {
+        x * 2
+    }
+
+    let (a, b, c) = (pin!(double(3)), pin!(double(5)), pin!(double(8)));
+    let list = nlist![a, b, c];
+
+    assert_eq!(block_on(list.join()).0, nlist![6, 10, 16]);
+}
+
+#[test]
+#[should_panic(expected = "`Join` polled after completion")]
+fn join_polled_after_completion_test() {
+    let mut fut = nlist![ready(3)].join();
+
+    assert_eq!(poll_once(&mut fut), Poll::Ready(nlist![3]));
+    let _ = poll_once(&mut fut);
+}
+
+#[test]
+fn try_join_ok_test() {
+    let polls = [const { Cell::new(0) }; 3];
+
+    let list = nlist![
+        countdown(1, Ok::<_, u8>(3), &polls[0]),
+        countdown(3, Ok(5), &polls[1]),
+        countdown(0, Ok(8), &polls[2]),
+    ];
+
+    assert_eq!(block_on(list.try_join()), (Ok(nlist![3, 5, 8]), 4));
+    assert_eq!(polls.each_ref().map(Cell::get), [2, 4, 1]);
+}
+
+#[test]
+fn try_join_err_test() {
+    let polls = [const { Cell::new(0) }; 4];
+
+    let list = nlist![
+        countdown(1, Ok(3), &polls[0]),
+        countdown(2, Err("first"), &polls[1]),
+        countdown(2, Err("second"), &polls[2]),
+        countdown(5, Ok(8), &polls[3]),
+    ];
+
+    assert_eq!(block_on(list.try_join()), (Err::<NList<u32, _>, _>("first"), 3));
+
+    // the futures after the one that errored aren't polled in the same call
+    assert_eq!(polls.each_ref().map(Cell::get), [2, 3, 2, 2]);
+}
+
+#[test]
+fn try_join_empty_test() {
+    let list: NList<core::future::Ready<Result<u8, ()>>, Peano!(0)> = nlist![];
+
+    assert_eq!(block_on(list.try_join()), (Ok(nlist![]), 1));
+}
+
+#[test]
+fn select_test() {
+    let polls = [const { Cell::new(0) }; 3];
+
+    let list = nlist![
+        countdown(5, 'a', &polls[0]),
+        countdown(2, 'b', &polls[1]),
+        countdown(3, 'c', &polls[2]),
+    ];
+
+    let ((index, output), poll_count) = block_on(list.select());
+
+    assert_eq!(index, Fin::new(1).unwrap());
+    assert_eq!(output, 'b');
+    assert_eq!(poll_count, 3);
+    assert_eq!(polls.each_ref().map(Cell::get), [3, 3, 3]);
+}
+
+#[test]
+fn select_single_test() {
+    let ((index, output), _) = block_on(nlist![ready(13)].select());
+
+    assert_eq!(index.get(), 0);
+    assert_eq!(output, 13);
+}
+
+#[test]
+fn select_fairness_test() {
+    fn select_nth_poll(n: usize) -> usize {
+        let polls = [const { Cell::new(0) }; 3];
+
+        let list = nlist![
+            countdown(n, 0, &polls[0]),
+            countdown(n, 1, &polls[1]),
+            countdown(n, 2, &polls[2]),
+        ];
+
+        let ((index, output), _) = block_on(list.select());
+        assert_eq!(index.get(), output);
+        output
+    }
+
+    // when all the futures become ready in the same poll,
+    // the first one polled rotates across calls to `poll`
+    assert_eq!(select_nth_poll(0), 0);
+    assert_eq!(select_nth_poll(1), 1);
+    assert_eq!(select_nth_poll(2), 2);
+    assert_eq!(select_nth_poll(3), 0);
+}
+
+#[test]
+fn select_pending_test() {
+    let mut fut = nlist![pending::<u8>(), pending()].select();
+
+    for _ in 0..4 {
+        assert_eq!(poll_once(&mut fut), Poll::Pending);
+    }
+}
@@ -1,7 +1,10 @@
+mod nlist_builder_tests;
 mod nlist_comparison_tests;
 mod nlist_constructor_tests;
 mod nlist_getters_tests;
 mod nlist_indexing_and_recombining_tests;
+mod nlist_int_bytes_tests;
 mod nlist_iterator_methods_tests;
 mod nlist_len_methods_tests;
-mod nlist_misc_tests;
\ No newline at end of file
+mod nlist_misc_tests;
+mod nlist_zipper_tests;
\ No newline at end of file
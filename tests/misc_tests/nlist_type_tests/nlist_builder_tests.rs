@@ -0,0 +1,73 @@
+use nlist::{NList, NListBuilder, Peano, nlist};
+
+use std::cell::Cell;
+
+use crate::misc_tests::test_utils::CountDrops;
+
+
+#[test]
+fn builder_empty_test() {
+    const EMPTY: NList<u8, Peano!(0)> = NList::builder().finish();
+    assert_eq!(EMPTY, nlist![0u8; 0]);
+
+    let builder = NListBuilder::<String, _, Peano!(0)>::default();
+    assert_eq!(builder.filled(), 0);
+    assert_eq!(builder.remaining(), 0);
+    assert_eq!(builder.finish(), NList::nil::<String>());
+}
+
+#[test]
+fn builder_push_test() {
+    const LIST: NList<u32, Peano!(4)> = NList::builder()
+        .push(3)
+        .push(5)
+        .push(8)
+        .push(13)
+        .finish();
+
+    assert_eq!(LIST, nlist![3, 5, 8, 13]);
+
+    let builder = NListBuilder::<_, _, Peano!(3)>::new();
+    assert_eq!((builder.filled(), builder.remaining()), (0, 3));
+
+    let builder = builder.push(String::from("foo"));
+    assert_eq!((builder.filled(), builder.remaining()), (1, 2));
+    assert_eq!(format!("{builder:?}"), r#"NListBuilder { filled: ["foo"], remaining: 2 }"#);
+
+    let builder = builder.push(String::from("bar"));
+    assert_eq!(format!("{builder:?}"), r#"NListBuilder { filled: ["foo", "bar"], remaining: 1 }"#);
+
+    let clone = builder.clone().push(String::from("qux"));
+
+    let builder = builder.push(String::from("baz"));
+    assert_eq!((builder.filled(), builder.remaining()), (3, 0));
+
+    assert_eq!(builder.finish(), nlist!["foo", "bar", "baz"]);
+    assert_eq!(clone.finish(), nlist!["foo", "bar", "qux"]);
+}
+
+#[test]
+fn builder_const_fn_test() {
+    const fn from_bytes(bytes: &[u8; 3]) -> NList<u8, Peano!(3)> {
+        let builder = NList::builder();
+        let builder = builder.push(bytes[0]);
+        let builder = builder.push(bytes[1]);
+        builder.push(bytes[2]).finish()
+    }
+
+    const LIST: NList<u8, Peano!(3)> = from_bytes(b"abc");
+    assert_eq!(LIST, nlist![b'a', b'b', b'c']);
+}
+
+#[test]
+fn builder_drop_test() {
+    let drops = Cell::new(0);
+
+    let builder = NListBuilder::<_, _, Peano!(3)>::new()
+        .push(CountDrops(&drops, 0))
+        .push(CountDrops(&drops, 1));
+    assert_eq!(drops.get(), 0);
+
+    drop(builder);
+    assert_eq!(drops.get(), 2);
+}
@@ -1,6 +1,6 @@
-use nlist::{Cons, Peano, PeanoInt, Nil, NList, nlist};
+use nlist::{Cons, Peano, PeanoInt, Nil, NList, nlist, peano};
 
-use crate::misc_tests::test_utils::{assertm, assert_type};
+use crate::misc_tests::test_utils::{CountDrops, assertm, assert_type};
 
 
 #[test]
@@ -124,6 +124,88 @@ fn nlist_macro_repeat_test() {
     assert_eq!(REPEATED, nlist!["h", "h", "h", "h", "h", "h", "h"]);
 }
 
+#[test]
+fn nlist_macro_splice_test() {
+    const fn prepend<T, L>(a: T, b: T, rest: NList<T, L>) -> NList<T, peano::Add<Peano!(2), L>>
+    where
+        L: PeanoInt,
+    {
+        nlist![a, b; ..rest]
+    }
+
+    assert_type::<NList<u8, Peano!(2)>>(prepend(3u8, 5, nlist![]));
+    assert_type::<NList<u8, Peano!(4)>>(prepend(3u8, 5, nlist![8, 13]));
+
+    const LIST: NList<u8, Peano!(5)> = prepend(3, 5, nlist![8, 13, 21]);
+    assert_eq!(LIST, nlist![3, 5, 8, 13, 21]);
+
+    assert_eq!(nlist![3u8; ..nlist![5, 8],], nlist![3, 5, 8]);
+    assert_eq!(nlist![3u8, 5; ..nlist![]], nlist![3, 5]);
+    assert_eq!(nlist![nlist![3u8]; ..nlist![nlist![5], nlist![8]]], nlist![nlist![3], nlist![5], nlist![8]]);
+}
+
+#[test]
+fn nlist_macro_range_elements_test() {
+    use core::ops::{RangeFull, RangeTo};
+
+    assert_type::<NList<RangeFull, Peano!(1)>>(nlist![..]);
+    assert_type::<NList<RangeTo<u8>, Peano!(2)>>(nlist![..5u8, ..8]);
+
+    assert_eq!(nlist![..5, ..8].into_array(), [..5, ..8]);
+
+    let (a, b) = (..3u8, 13);
+    assert_eq!(nlist![a, ..b].into_array(), [..3, ..13]);
+    assert_eq!(nlist![a, ..b, ..21,].into_array(), [..3, ..13, ..21]);
+    assert_eq!(nlist![a; ..nlist![..b]].into_array(), [..3, ..13]);
+}
+
+#[test]
+fn nlist_macro_repeat_clone_test() {
+    assert_type::<NList<String, Peano!(3)>>(nlist![String::new() => clone; 3]);
+
+    {
+        let list: NList<String, Peano!(2)> = nlist![String::from("foo") => clone; _];
+        assert_eq!(list, nlist!["foo".to_string(), "foo".to_string()]);
+    }
+    {
+        let list = nlist![vec![3u8, 5] => clone; 3];
+        assert_eq!(list, nlist![vec![3, 5], vec![3, 5], vec![3, 5]]);
+    }
+    {
+        let list: NList<Vec<u8>, Peano!(0)> = nlist![vec![3] => clone; _];
+        assert_eq!(list, NList::nil::<Vec<u8>>());
+    }
+}
+
+#[test]
+fn nlist_macro_from_fn_test() {
+    assert_type::<NList<usize, Peano!(4)>>(nlist![from_fn |i| i * 2; 4]);
+
+    assert_eq!(nlist![from_fn |i| i * 2; 4], nlist![0, 2, 4, 6]);
+    assert_eq!(nlist![from_fn |i: usize| i.pow(2); 3], nlist![0, 1, 4]);
+    assert_eq!(nlist![from_fn |_| "hello"; 2], nlist!["hello", "hello"]);
+
+    {
+        let list: NList<u64, Peano!(5)> = nlist![from_fn |i| 1 << i; _];
+        assert_eq!(list, nlist![1, 2, 4, 8, 16]);
+    }
+    {
+        let mut counter = 10;
+        let list: NList<u32, Peano!(3)> = nlist![from_fn |_| { counter += 1; counter }; _];
+        assert_eq!(list, nlist![11, 12, 13]);
+    }
+}
+
+#[test]
+fn nlist_macro_repeat_closure_test() {
+    // without the `from_fn` marker, a closure is repeated like any other `Copy` value
+    let list = nlist![|x: u32| x * 2; 3];
+    assert_eq!(list.map(|f| f(5)), nlist![10, 10, 10]);
+
+    let list: NList<fn(u32) -> u32, Peano!(2)> = nlist![|x| x + 1; _];
+    assert_eq!(list.map(|f| f(5)), nlist![6, 6]);
+}
+
 #[test]
 fn nlist_macro_repeat_infer_test() {
     const fn repat<T, L>(val: T) -> NList<T, L>
@@ -244,3 +326,109 @@ fn from_array_test() {
 
 
 
+
+#[test]
+fn repeat_clone_test() {
+    use std::cell::Cell;
+
+    struct CountClones<'a>(&'a Cell<usize>, u8);
+
+    impl Clone for CountClones<'_> {
+        fn clone(&self) -> Self {
+            self.0.set(self.0.get() + 1);
+            Self(self.0, self.1)
+        }
+    }
+
+    fn clones_for<L: PeanoInt>() -> (usize, NList<u8, L>) {
+        let clones = Cell::new(0);
+        let list = NList::<_, L>::repeat_clone(CountClones(&clones, 3)).map(|x| x.1);
+        (clones.get(), list)
+    }
+
+    assert_eq!(clones_for::<Peano!(0)>(), (0, nlist![]));
+    assert_eq!(clones_for::<Peano!(1)>(), (0, nlist![3]));
+    assert_eq!(clones_for::<Peano!(2)>(), (1, nlist![3, 3]));
+    assert_eq!(clones_for::<Peano!(4)>(), (3, nlist![3, 3, 3, 3]));
+
+    let list: NList<String, Peano!(2)> = NList::repeat_clone("foo".to_string());
+    assert_eq!(list, nlist!["foo".to_string(), "foo".to_string()]);
+}
+
+#[test]
+fn repeat_with_test() {
+    let mut n = 0;
+    let list: NList<u32, Peano!(4)> = NList::repeat_with(|| {
+        n += 2;
+        n
+    });
+
+    assert_eq!(list, nlist![2, 4, 6, 8]);
+    assert_eq!(n, 8);
+
+    let list: NList<u32, Peano!(0)> = NList::repeat_with(|| unreachable!());
+    assert_eq!(list, NList::nil::<u32>());
+}
+
+#[test]
+fn successors_test() {
+    fn with_calls<L: PeanoInt>() -> (usize, NList<u32, L>) {
+        let mut calls = 0;
+        let list = NList::successors(3, |x| {
+            calls += 1;
+            x * 2
+        });
+        (calls, list)
+    }
+
+    assert_eq!(with_calls::<Peano!(0)>(), (0, nlist![]));
+    assert_eq!(with_calls::<Peano!(1)>(), (0, nlist![3]));
+    assert_eq!(with_calls::<Peano!(2)>(), (1, nlist![3, 6]));
+    assert_eq!(with_calls::<Peano!(5)>(), (4, nlist![3, 6, 12, 24, 48]));
+
+    let list: NList<String, Peano!(3)> = NList::successors("a".to_string(), |s| format!("{s}b"));
+    assert_eq!(list, nlist!["a", "ab", "abb"].map(String::from));
+}
+
+#[test]
+fn try_from_fn_test() {
+    use std::cell::Cell;
+
+    {
+        let res: Result<NList<usize, Peano!(4)>, ()> = NList::try_from_fn(|i| Ok(i * 3));
+        assert_eq!(res, Ok(nlist![0, 3, 6, 9]));
+    }
+    {
+        let res: Result<NList<usize, Peano!(0)>, ()> = NList::try_from_fn(|_| unreachable!());
+        assert_eq!(res, Ok(NList::nil::<usize>()));
+    }
+    {
+        let drops = Cell::new(0);
+        let mut calls = Vec::new();
+
+        let res: Result<NList<CountDrops<'_>, Peano!(5)>, usize> = NList::try_from_fn(|i| {
+            calls.push(i);
+            if i == 3 {
+                Err(i + 100)
+            } else {
+                Ok(CountDrops(&drops, i as u8))
+            }
+        });
+
+        assert!(matches!(res, Err(103)));
+        assert_eq!(calls, [0, 1, 2, 3]);
+        assert_eq!(drops.get(), 3);
+    }
+    {
+        let drops = Cell::new(0);
+
+        let res: Result<NList<CountDrops<'_>, Peano!(3)>, ()> = 
+            NList::try_from_fn(|i| Ok(CountDrops(&drops, i as u8)));
+
+        let list = res.ok().unwrap();
+        assert_eq!(list.each_ref().map(|x| x.1), nlist![0, 1, 2]);
+        assert_eq!(drops.get(), 0);
+        drop(list);
+        assert_eq!(drops.get(), 3);
+    }
+}
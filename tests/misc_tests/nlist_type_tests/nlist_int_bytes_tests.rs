@@ -0,0 +1,120 @@
+use nlist::{NList, Peano, nlist};
+
+
+macro_rules! list_bytes_test {
+    ($($fn_name:ident $ty:ident [$($elem:expr),*])*) => {$(
+        #[test]
+        fn $fn_name() {
+            let list: NList<$ty, _> = nlist![$($elem),*];
+            let elems = list.clone().into_vec();
+
+            let concat_bytes = |f: fn($ty) -> [u8; core::mem::size_of::<$ty>()]| -> Vec<u8> {
+                elems.iter().copied().flat_map(f).collect()
+            };
+
+            let le = list.to_le_bytes();
+            let be = list.to_be_bytes();
+            let ne = list.to_ne_bytes();
+
+            assert_eq!(le.clone().into_vec(), concat_bytes(<$ty>::to_le_bytes));
+            assert_eq!(be.clone().into_vec(), concat_bytes(<$ty>::to_be_bytes));
+            assert_eq!(ne.clone().into_vec(), concat_bytes(<$ty>::to_ne_bytes));
+
+            assert_eq!(NList::<$ty, Peano!(3)>::from_le_bytes(le), list);
+            assert_eq!(NList::<$ty, Peano!(3)>::from_be_bytes(be), list);
+            assert_eq!(NList::<$ty, Peano!(3)>::from_ne_bytes(ne), list);
+        }
+    )*};
+}
+
+list_bytes_test! {
+    list_u8_bytes_test u8 [0, 0x7F, u8::MAX]
+    list_u16_bytes_test u16 [0, 0x0102, u16::MAX]
+    list_u32_bytes_test u32 [0, 0x01020304, u32::MAX]
+    list_u64_bytes_test u64 [0, 0x0102030405060708, u64::MAX]
+    list_usize_bytes_test usize [0, 0x01020304, usize::MAX]
+    list_i8_bytes_test i8 [i8::MIN, -1, i8::MAX]
+    list_i16_bytes_test i16 [i16::MIN, -0x0102, i16::MAX]
+    list_i32_bytes_test i32 [i32::MIN, -0x01020304, i32::MAX]
+    list_i64_bytes_test i64 [i64::MIN, -0x0102030405060708, i64::MAX]
+    list_isize_bytes_test isize [isize::MIN, -0x01020304, isize::MAX]
+}
+
+// 128-bit lists are tested with fewer elements,
+// since longer byte lists exceed the default recursion limit.
+#[test]
+fn list_128_bit_bytes_test() {
+    let unsigned: NList<u128, Peano!(1)> = nlist![0x0102030405060708090A0B0C0D0E0F10];
+    let signed: NList<i128, Peano!(1)> = nlist![-0x0102030405060708090A0B0C0D0E0F10];
+
+    let descending: Vec<u8> = (1..=16).rev().collect();
+    let ascending: Vec<u8> = (1..=16).collect();
+
+    assert_eq!(unsigned.to_le_bytes().into_vec(), descending);
+    assert_eq!(unsigned.to_be_bytes().into_vec(), ascending);
+    assert_eq!(NList::<u128, Peano!(1)>::from_le_bytes(unsigned.to_le_bytes()), unsigned);
+    assert_eq!(NList::<u128, Peano!(1)>::from_be_bytes(unsigned.to_be_bytes()), unsigned);
+    assert_eq!(NList::<u128, Peano!(1)>::from_ne_bytes(unsigned.to_ne_bytes()), unsigned);
+
+    assert_eq!(signed.to_be_bytes().into_vec(), signed.head().to_be_bytes());
+    assert_eq!(NList::<i128, Peano!(1)>::from_le_bytes(signed.to_le_bytes()), signed);
+    assert_eq!(NList::<i128, Peano!(1)>::from_be_bytes(signed.to_be_bytes()), signed);
+    assert_eq!(NList::<i128, Peano!(1)>::from_ne_bytes(signed.to_ne_bytes()), signed);
+}
+
+#[test]
+fn empty_list_bytes_test() {
+    let empty = NList::nil::<u32>();
+
+    assert_eq!(empty.to_le_bytes(), NList::nil::<u8>());
+    assert_eq!(NList::<u64, Peano!(0)>::from_be_bytes(NList::nil()), NList::nil::<u64>());
+}
+
+#[test]
+fn const_list_bytes_test() {
+    const BYTES: NList<u8, Peano!(6)> = nlist![0x0102u16, 0x0304, 0x0506].to_be_bytes();
+    const LIST: NList<i16, Peano!(2)> = NList::<i16, Peano!(2)>::from_le_bytes(nlist![0xFE, 0xFF, 3, 0]);
+
+    assert_eq!(BYTES, nlist![1, 2, 3, 4, 5, 6]);
+    assert_eq!(LIST, nlist![-2, 3]);
+}
+
+#[test]
+fn scalar_bytes_test() {
+    macro_rules! test_case {
+        ($ty:ident $value:expr, $from_le:ident $from_be:ident $from_ne:ident $to_le:ident $to_be:ident $to_ne:ident) => {
+            let value: $ty = $value;
+
+            let le = NList::$from_le(value);
+            let be = NList::$from_be(value);
+            let ne = NList::$from_ne(value);
+
+            assert_eq!(le.copy().into_array(), value.to_le_bytes());
+            assert_eq!(be.copy().into_array(), value.to_be_bytes());
+            assert_eq!(ne.copy().into_array(), value.to_ne_bytes());
+
+            assert_eq!(le.$to_le(), value);
+            assert_eq!(be.$to_be(), value);
+            assert_eq!(ne.$to_ne(), value);
+        };
+    }
+
+    test_case!{u8 0xAB, from_u8_le from_u8_be from_u8_ne to_u8_le to_u8_be to_u8_ne}
+    test_case!{u16 0x0102, from_u16_le from_u16_be from_u16_ne to_u16_le to_u16_be to_u16_ne}
+    test_case!{u32 0x01020304, from_u32_le from_u32_be from_u32_ne to_u32_le to_u32_be to_u32_ne}
+    test_case!{u64 0x0102030405060708, from_u64_le from_u64_be from_u64_ne to_u64_le to_u64_be to_u64_ne}
+    test_case!{u128 u128::MAX - 1, from_u128_le from_u128_be from_u128_ne to_u128_le to_u128_be to_u128_ne}
+    test_case!{usize usize::MAX - 1, from_usize_le from_usize_be from_usize_ne to_usize_le to_usize_be to_usize_ne}
+    test_case!{i8 -2, from_i8_le from_i8_be from_i8_ne to_i8_le to_i8_be to_i8_ne}
+    test_case!{i16 -0x0102, from_i16_le from_i16_be from_i16_ne to_i16_le to_i16_be to_i16_ne}
+    test_case!{i32 -0x01020304, from_i32_le from_i32_be from_i32_ne to_i32_le to_i32_be to_i32_ne}
+    test_case!{i64 i64::MIN + 1, from_i64_le from_i64_be from_i64_ne to_i64_le to_i64_be to_i64_ne}
+    test_case!{i128 i128::MIN + 1, from_i128_le from_i128_be from_i128_ne to_i128_le to_i128_be to_i128_ne}
+    test_case!{isize isize::MIN + 1, from_isize_le from_isize_be from_isize_ne to_isize_le to_isize_be to_isize_ne}
+
+    const BYTES: NList<u8, Peano!(4)> = NList::from_u32_be(0x0A0B0C0D);
+    const INT: u32 = BYTES.to_u32_le();
+
+    assert_eq!(BYTES, nlist![0x0A, 0x0B, 0x0C, 0x0D]);
+    assert_eq!(INT, 0x0D0C0B0A);
+}
@@ -1,4 +1,6 @@
-use nlist::{NList, PeanoInt, Peano, nlist, peano};
+use nlist::{BoundedNList, NList, PeanoInt, Peano, Remainder, nlist, peano};
+
+use crate::misc_tests::test_utils::CountDrops;
 
 use konst::option;
 
@@ -262,6 +264,109 @@ fn rec_find_map_test() {
     }
 }
 
+#[test]
+fn rec_find_map_rest_test() {
+    // has a destructor, so it can't be dropped in const
+    #[derive(Debug, PartialEq)]
+    struct Thing(u8);
+
+    impl Drop for Thing {
+        fn drop(&mut self) {}
+    }
+
+    const fn find_odd<L>(list: NList<Thing, L>) -> (Option<Thing>, Remainder<Thing, L>)
+    where
+        L: PeanoInt
+    {
+        nlist::rec_find_map_rest!{
+            list,
+            |elem: &mut Option<Thing>| match elem {
+                Some(Thing(x)) if *x % 2 == 1 => elem.take(),
+                _ => None,
+            },
+            |next| find_odd(next)
+        }
+    }
+
+    const fn find_div<L>(list: NList<u8, L>, div: u8) -> (Option<u8>, Remainder<u8, L>)
+    where
+        L: PeanoInt
+    {
+        nlist::rec_find_map_rest!{
+            list,
+            |elem| -> Option<u8> {
+                match elem {
+                    Some(x) if *x % div == 0 => Some(*x / div),
+                    _ => None,
+                }
+            },
+            |next: NList<u8, L::SubOneSat>| find_div(next, div)
+        }
+    }
+
+    {
+        let (found, rem) = const { find_odd(NList::nil()) };
+        assert_eq!(found, None);
+        assert_eq!(rem.len(), 0);
+    }
+    {
+        let (found, rem) = const { find_odd(nlist![Thing(2), Thing(4), Thing(6)]) };
+        assert_eq!(found, None);
+        assert_eq!(rem.into_vec(), vec![Thing(2), Thing(4), Thing(6)]);
+    }
+    {
+        let (found, rem) = const { find_odd(nlist![Thing(3), Thing(4), Thing(6)]) };
+        assert_eq!(found, Some(Thing(3)));
+        assert_eq!(
+            rem.into_option_nlist(), 
+            nlist![None, Some(Thing(4)), Some(Thing(6))],
+        );
+    }
+    {
+        let (found, rem) = const { find_odd(nlist![Thing(2), Thing(4), Thing(5), Thing(7)]) };
+        assert_eq!(found, Some(Thing(5)));
+        assert_eq!(rem.len(), 3);
+        assert_eq!(rem.into_vec(), vec![Thing(2), Thing(4), Thing(7)]);
+    }
+
+    // the mapper doesn't take the element out of the `Option`
+    {
+        let (found, rem) = const { find_div(nlist![3, 5, 10, 15], 5) };
+        assert_eq!(found, Some(1));
+        assert_eq!(rem.into_option_nlist(), nlist![Some(3), Some(5), Some(10), Some(15)]);
+    }
+}
+
+#[test]
+fn remainder_test() {
+    const fn sum_remainder<L: PeanoInt>(mut rem: Remainder<u8, L>) -> u32 {
+        let mut sum = 0;
+        while let Some(x) = rem.pop_front() {
+            sum += x as u32;
+        }
+        rem.assert_empty_drop();
+        sum
+    }
+
+    let rem: Remainder<u8, Peano!(0)> = Remainder::nil();
+    assert!(rem.is_empty());
+    assert_eq!(format!("{rem:?}"), "[]");
+
+    let rem = Remainder::cons(Some(3), Remainder::cons(None, Remainder::from_nlist(nlist![5, 8])));
+    assert_eq!(rem.len(), 3);
+    assert!(!rem.is_empty());
+    assert_eq!(rem.as_option_nlist(), &nlist![Some(3), None, Some(5), Some(8)]);
+    assert_eq!(format!("{rem:?}"), "[3, 5, 8]");
+    assert_eq!(rem.clone().into_vec(), vec![3, 5, 8]);
+
+    assert_eq!(const { sum_remainder(Remainder::from_nlist(nlist![3, 5, 8])) }, 16);
+    assert_eq!(sum_remainder(rem), 16);
+
+    let rem = Remainder::from_option_nlist(nlist![None, Some(13u8), None]);
+    assert_eq!(rem.len(), 1);
+    assert_eq!(rem.into_vec(), vec![13]);
+}
+
 #[test]
 fn flatten_test() {
     const fn inner<T, L, L2>(list: NList<NList<T, L2>, L>) -> NList<T, peano::Mul<L, L2>>
@@ -305,6 +410,50 @@ fn flatten_test() {
     test_case! {2 3 [[3 5 8] [13 21 34]]}
 }
 
+#[test]
+fn unflatten_test() {
+    const fn inner<T, L, L2>(list: NList<T, peano::Mul<L, L2>>) -> NList<NList<T, L2>, L>
+    where
+        L: PeanoInt,
+        L2: PeanoInt,
+    {
+        NList::unflatten(list)
+    }
+
+    macro_rules! test_case {
+        (
+            $L:literal $L2:literal 
+            [$([$($elem:literal)*])*]
+        ) => {
+            let flat: NList<u32, _> = nlist![
+                $($($elem,)*)*
+            ];
+
+            let expected: NList<NList<u32, Peano!($L2)>, Peano!($L)> = nlist![
+                $(nlist![ $($elem,)* ],)*
+            ];
+
+            assert_eq!(inner::<_, Peano!($L), Peano!($L2)>(flat), expected);
+            assert_eq!(inner::<_, Peano!($L), Peano!($L2)>(expected.clone().flatten()), expected);
+        }
+    }
+
+    test_case! {0 0 []}
+    test_case! {0 1 []}
+    test_case! {0 3 []}
+
+    test_case! {1 0 [[]]}
+    test_case! {1 1 [[3]]}
+    test_case! {1 3 [[3 5 8]]}
+
+    test_case! {2 0 [[] []]}
+    test_case! {2 1 [[3] [5]]}
+    test_case! {2 2 [[3 5] [8 13]]}
+    test_case! {2 3 [[3 5 8] [13 21 34]]}
+
+    test_case! {3 2 [[3 5] [8 13] [21 34]]}
+}
+
 #[test]
 fn fold_test() {
     assert_eq!(
@@ -375,6 +524,228 @@ fn fold_rec_test() {
     }
 }
 
+#[test]
+fn rec_scan_test() {
+    macro_rules! test_case {
+        ($list:ident $sum:ident ($($reffness:tt)*) => $($invocation:tt)*) => ({
+            const fn prefix_sums<L>($sum: u32, $list: $($reffness)* NList<u8, L>) -> NList<u32, L>
+            where
+                L: PeanoInt
+            {
+                $($invocation)*
+            }
+
+            assert_eq!(prefix_sums(10, $($reffness)* NList::nil()), nlist![0u32; 0]);
+            assert_eq!(prefix_sums(10, $($reffness)* nlist![3]), nlist![13]);
+            assert_eq!(prefix_sums(10, $($reffness)* nlist![3, 5, 8]), nlist![13, 18, 26]);
+        })
+    }
+
+    test_case!{list sum (&) =>
+        nlist::rec_scan!{list, sum, |sum, elem: &u8| sum + *elem as u32, |next, sum| prefix_sums(sum, next)}
+    }
+    test_case!{list sum (&mut) =>
+        nlist::rec_scan!{
+            list, 
+            sum, 
+            |sum: u32, elem: &mut u8| -> u32 { sum + *elem as u32 },
+            |next: &mut NList<u8, L::SubOneSat>, sum: u32| prefix_sums(sum, next),
+        }
+    }
+    test_case!{list sum () =>
+        nlist::rec_scan!{list, sum, |sum, elem| sum + elem as u32, |next, sum| prefix_sums(sum, next)}
+    }
+}
+
+#[test]
+fn rec_map_with_state_test() {
+    macro_rules! test_case {
+        ($list:ident $state:ident ($($reffness:tt)*) => $($invocation:tt)*) => ({
+            // maps each element to its running maximum, returning the maximum
+            const fn running_max<L>(
+                $state: u8, 
+                $list: $($reffness)* NList<u8, L>,
+            ) -> (NList<u8, L>, u8)
+            where
+                L: PeanoInt
+            {
+                $($invocation)*
+            }
+
+            assert_eq!(running_max(4, $($reffness)* NList::nil()), (nlist![], 4));
+            assert_eq!(running_max(4, $($reffness)* nlist![3]), (nlist![4], 4));
+            assert_eq!(
+                running_max(4, $($reffness)* nlist![3, 8, 5, 13, 0]), 
+                (nlist![4, 8, 8, 13, 13], 13),
+            );
+        })
+    }
+
+    test_case!{list state (&) =>
+        nlist::rec_map_with_state!{
+            list, 
+            state, 
+            |state, elem: &u8| {
+                let max = if *elem > state { *elem } else { state };
+                (max, max)
+            },
+            |next, state| running_max(state, next),
+        }
+    }
+    test_case!{list state (&mut) =>
+        nlist::rec_map_with_state!{
+            list, 
+            state, 
+            |state: u8, elem: &mut u8| -> (u8, u8) {
+                let max = if *elem > state { *elem } else { state };
+                (max, max)
+            },
+            |next: &mut NList<u8, L::SubOneSat>, state: u8| running_max(state, next),
+        }
+    }
+    test_case!{list state () =>
+        nlist::rec_map_with_state!{
+            list, 
+            state, 
+            |state, elem| {
+                let max = if elem > state { elem } else { state };
+                (max, max)
+            },
+            |next, state| running_max(state, next),
+        }
+    }
+}
+
+#[test]
+fn rec_enumerate_test() {
+    macro_rules! test_case {
+        ($list:ident $index:ident ($($reffness:tt)*) => $($invocation:tt)*) => ({
+            const fn enumerate<L>(
+                $index: usize, 
+                $list: $($reffness)* NList<u8, L>,
+            ) -> NList<(usize, u8), L>
+            where
+                L: PeanoInt
+            {
+                $($invocation)*
+            }
+
+            assert_eq!(enumerate(0, $($reffness)* NList::nil()), nlist![]);
+            assert_eq!(enumerate(0, $($reffness)* nlist![3]), nlist![(0, 3)]);
+            assert_eq!(
+                enumerate(0, $($reffness)* nlist![3, 5, 8]), 
+                nlist![(0, 3), (1, 5), (2, 8)],
+            );
+            assert_eq!(
+                enumerate(10, $($reffness)* nlist![3, 5, 8]), 
+                nlist![(10, 3), (11, 5), (12, 8)],
+            );
+        })
+    }
+
+    test_case!{list index (&) =>
+        nlist::rec_enumerate!{list, index, |i, elem: &u8| (i, *elem), |next, i| enumerate(i, next)}
+    }
+    test_case!{list index (&mut) =>
+        nlist::rec_enumerate!{
+            list, 
+            index, 
+            |i: usize, elem: &mut u8| -> (usize, u8) { (i, *elem) },
+            |next: &mut NList<u8, L::SubOneSat>, i: usize| enumerate(i, next),
+        }
+    }
+    test_case!{list index () =>
+        nlist::rec_enumerate!{list, index, |i, elem| (i, elem), |next, i| enumerate(i, next)}
+    }
+}
+
+#[test]
+fn rec_zip_map_test() {
+    macro_rules! test_case {
+        (
+            $lhs:ident ($($lreffness:tt)*) 
+            $rhs:ident ($($rreffness:tt)*) 
+            => $($invocation:tt)*
+        ) => ({
+            const fn sub<L>(
+                $lhs: $($lreffness)* NList<u8, L>, 
+                $rhs: $($rreffness)* NList<u16, L>, 
+            ) -> NList<i32, L>
+            where
+                L: PeanoInt
+            {
+                $($invocation)*
+            }
+
+            assert_eq!(sub($($lreffness)* NList::nil(), $($rreffness)* NList::nil()), nlist![0i32; 0]);
+            assert_eq!(sub($($lreffness)* nlist![3], $($rreffness)* nlist![8]), nlist![-5]);
+            assert_eq!(
+                sub($($lreffness)* nlist![3, 20, 200], $($rreffness)* nlist![8, 13, 1000]), 
+                nlist![-5, 7, -800],
+            );
+        })
+    }
+
+    test_case!{lhs (&) rhs (&) =>
+        nlist::rec_zip_map!{
+            lhs, 
+            rhs, 
+            |l: &u8, r: &u16| *l as i32 - *r as i32, 
+            |lnext, rnext| sub(lnext, rnext),
+        }
+    }
+    test_case!{lhs (&mut) rhs () =>
+        nlist::rec_zip_map!{
+            lhs, 
+            rhs, 
+            |l: &mut u8, r: u16| -> i32 { *l as i32 - r as i32 }, 
+            |lnext: &mut NList<u8, L::SubOneSat>, rnext: NList<u16, L::SubOneSat>| 
+                sub(lnext, rnext),
+        }
+    }
+    test_case!{lhs () rhs (&mut) =>
+        nlist::rec_zip_map!{
+            lhs, 
+            rhs, 
+            |l, r: &mut u16| l as i32 - *r as i32, 
+            |lnext, rnext| sub(lnext, rnext),
+        }
+    }
+}
+
+#[test]
+fn rec_filter_count_test() {
+    macro_rules! test_case {
+        ($list:ident ($($reffness:tt)*) => $($invocation:tt)*) => ({
+            const fn count_odd<L>($list: $($reffness)* NList<u8, L>) -> usize
+            where
+                L: PeanoInt
+            {
+                $($invocation)*
+            }
+
+            assert_eq!(count_odd($($reffness)* NList::nil()), 0);
+            assert_eq!(count_odd($($reffness)* nlist![3]), 1);
+            assert_eq!(count_odd($($reffness)* nlist![4]), 0);
+            assert_eq!(count_odd($($reffness)* nlist![3, 5, 8, 13, 21, 34]), 4);
+        })
+    }
+
+    test_case!{list (&) =>
+        nlist::rec_filter_count!{list, |elem: &u8| *elem % 2 == 1, |next| count_odd(next)}
+    }
+    test_case!{list (&mut) =>
+        nlist::rec_filter_count!{
+            list, 
+            |elem: &mut u8| -> bool { *elem % 2 == 1 }, 
+            |next: &mut NList<u8, L::SubOneSat>| count_odd(next),
+        }
+    }
+    test_case!{list () =>
+        nlist::rec_filter_count!{list, |elem| elem % 2 == 1, |next| count_odd(next)}
+    }
+}
+
 #[test]
 fn for_each_test() {
     macro_rules! test_case {
@@ -512,6 +883,182 @@ fn map_test() {
     }
 }
 
+#[test]
+fn try_map_test() {
+    use std::cell::Cell;
+
+    assert_eq!(NList::nil::<u8>().try_map(|_| Err::<u8, _>(())), Ok(nlist![0u8; 0]));
+    assert_eq!(nlist![3u8, 5, 8].try_map(|x| u16::from(x).checked_mul(300).ok_or(x)), Ok(nlist![900, 1500, 2400]));
+    assert_eq!(nlist![3u16, 500, 8].try_map(|x| u8::try_from(x).map_err(|_| x)), Err(500));
+
+    {
+        let drops = Cell::new(0);
+        let list = NList::<_, Peano!(5)>::from_fn(|i| CountDrops(&drops, i as u8));
+
+        let mut calls = Vec::new();
+        let res = list.try_map(|x| {
+            calls.push(x.1);
+            if x.1 == 2 { Err(x.1) } else { Ok(x) }
+        });
+
+        assert!(matches!(res, Err(2)));
+        assert_eq!(calls, [0, 1, 2]);
+        assert_eq!(drops.get(), 5);
+    }
+}
+
+#[test]
+fn try_map_option_test() {
+    assert_eq!(NList::nil::<u8>().try_map_option(|_| None::<u8>), Some(nlist![]));
+    assert_eq!(nlist![3u8, 5, 8].try_map_option(|x| x.checked_mul(30)), Some(nlist![90, 150, 240]));
+    assert_eq!(nlist![3u8, 10, 8].try_map_option(|x| x.checked_mul(30)), None);
+
+    let mut calls = Vec::new();
+    let res = nlist![3u8, 10, 8].try_map_option(|x| {
+        calls.push(x);
+        x.checked_mul(30)
+    });
+    assert_eq!(res, None);
+    assert_eq!(calls, [3, 10]);
+}
+
+#[test]
+fn rec_try_map_test() {
+    macro_rules! test_case {
+        ($list:ident ($($const:ident)?) ($($reffness:tt)*) => $($invocation:tt)*) => ({
+            $($const)? fn checked_double<L>(
+                $list: $($reffness)* NList<u8, L>,
+            ) -> Result<NList<u8, L>, u8>
+            where
+                L: PeanoInt
+            {
+                $($invocation)*
+            }
+
+            assert_eq!(checked_double($($reffness)* nlist![]), Ok(nlist![0u8; 0]));
+            assert_eq!(checked_double($($reffness)* nlist![3]), Ok(nlist![6]));
+            assert_eq!(checked_double($($reffness)* nlist![3, 5, 8]), Ok(nlist![6, 10, 16]));
+            assert_eq!(checked_double($($reffness)* nlist![200]), Err(200));
+            assert_eq!(checked_double($($reffness)* nlist![3, 128, 8, 255]), Err(128));
+            assert_eq!(checked_double($($reffness)* nlist![3, 5, 8, 255]), Err(255));
+
+            test_case!{@const ($($const)?) ($($reffness)*)}
+        });
+        (@const () $reffness:tt) => ();
+        (@const (const) ($($reffness:tt)*)) => ({
+            const CONST_OK: Result<NList<u8, Peano!(3)>, u8> = 
+                checked_double($($reffness)* nlist![3, 5, 8]);
+            assert_eq!(CONST_OK, Ok(nlist![6, 10, 16]));
+
+            const CONST_ERR: Result<NList<u8, Peano!(3)>, u8> = 
+                checked_double($($reffness)* nlist![3, 250, 8]);
+            assert_eq!(CONST_ERR, Err(250));
+        })
+    }
+
+    test_case!{list (const) (&) =>
+        nlist::rec_try_map!{
+            list, 
+            |elem: &u8| match elem.checked_mul(2) {
+                Some(x) => Ok(x),
+                None => Err(*elem),
+            },
+            |next| checked_double(next),
+        }
+    }
+    test_case!{list (const) (&mut) =>
+        nlist::rec_try_map!{
+            list, 
+            |elem: &mut u8| -> Result<u8, u8> { 
+                match elem.checked_mul(2) {
+                    Some(x) => Ok(x),
+                    None => Err(*elem),
+                }
+            },
+            |next: &mut NList<u8, L::SubOneSat>| checked_double(next),
+        }
+    }
+    // taking a list of generic length by value only works at runtime,
+    // because the unmapped elements are dropped on error
+    test_case!{list (/* non-const */) () =>
+        nlist::rec_try_map!{
+            list, 
+            |elem| match elem.checked_mul(2) {
+                Some(x) => Ok(x),
+                None => Err(elem),
+            },
+            |next| checked_double(next),
+        }
+    }
+}
+
+#[test]
+fn filter_test() {
+    let empty: BoundedNList<u8, Peano!(0)> = NList::nil().filter(|_| true);
+    assert!(empty.is_empty());
+
+    let list: BoundedNList<u8, Peano!(5)> = nlist![3, 4, 8, 13, 16].filter(|x| *x % 2 == 0);
+    assert_eq!(list.len(), 3);
+    assert_eq!(list.capacity(), 5);
+    assert_eq!(list.into_vec(), [4, 8, 16]);
+
+    assert!(nlist![3, 5, 7].filter(|x| *x % 2 == 0).is_empty());
+    assert!(nlist![3, 5, 7].filter(|_| true).is_full());
+
+    let mut calls = Vec::new();
+    _ = nlist![3, 5, 7].filter(|x| { calls.push(*x); false });
+    assert_eq!(calls, [3, 5, 7]);
+}
+
+#[test]
+fn dedup_test() {
+    assert!(NList::nil::<u8>().dedup().is_empty());
+
+    assert_eq!(nlist![3].dedup().into_vec(), [3]);
+    assert_eq!(nlist![3, 3, 3].dedup().into_vec(), [3]);
+    assert_eq!(nlist![3, 3, 5, 8, 8, 8, 3].dedup().into_vec(), [3, 5, 8, 3]);
+    assert_eq!(nlist![3, 5, 8].dedup().into_vec(), [3, 5, 8]);
+
+    // elements are compared with the last kept element, like `Vec::dedup` does
+    #[derive(Debug)]
+    struct Near(u8);
+
+    impl PartialEq for Near {
+        fn eq(&self, other: &Self) -> bool {
+            self.0.abs_diff(other.0) <= 1
+        }
+    }
+
+    let list = nlist![Near(1), Near(2), Near(3), Near(4), Near(8)].dedup();
+    assert_eq!(list.into_vec().iter().map(|x| x.0).collect::<Vec<_>>(), [1, 3, 8]);
+}
+
+#[test]
+fn take_while_test() {
+    assert!(NList::nil::<u8>().take_while(|_| true).is_empty());
+
+    assert_eq!(nlist![3, 5, 8, 13, 5].take_while(|x| *x < 10).into_vec(), [3, 5, 8]);
+    assert_eq!(nlist![3, 5, 8].take_while(|_| true).into_vec(), [3, 5, 8]);
+    assert!(nlist![3, 5, 8].take_while(|_| false).is_empty());
+
+    let mut calls = Vec::new();
+    _ = nlist![3, 5, 8, 13].take_while(|x| { calls.push(*x); *x < 5 });
+    assert_eq!(calls, [3, 5]);
+}
+
+#[test]
+fn skip_while_test() {
+    assert!(NList::nil::<u8>().skip_while(|_| true).is_empty());
+
+    assert_eq!(nlist![3, 5, 13, 8, 21].skip_while(|x| *x < 10).into_vec(), [13, 8, 21]);
+    assert!(nlist![3, 5, 8].skip_while(|_| true).is_empty());
+    assert_eq!(nlist![3, 5, 8].skip_while(|_| false).into_vec(), [3, 5, 8]);
+
+    let mut calls = Vec::new();
+    _ = nlist![3, 5, 8, 13].skip_while(|x| { calls.push(*x); *x < 5 });
+    assert_eq!(calls, [3, 5]);
+}
+
 #[test]
 fn position_test() {
     assert_eq!(NList::nil::<()>().position(|_| true), None::<usize>);
@@ -0,0 +1,67 @@
+use nlist::{NList, Peano, Zipper, nlist};
+
+
+#[test]
+fn zipper_single_element_test() {
+    let mut zipper = Zipper::from_nlist(nlist![3]);
+
+    assert_eq!(*zipper.focus(), 3);
+    assert_eq!(zipper.position(), 0);
+    assert_eq!(zipper.replace(5), 3);
+    assert_eq!(zipper.into_nlist(), nlist![5]);
+}
+
+#[test]
+fn zipper_movement_test() {
+    let zipper = Zipper::from_nlist(nlist![3, 5, 8, 13]);
+    assert_eq!((*zipper.focus(), zipper.position()), (3, 0));
+
+    let zipper = zipper.move_right();
+    assert_eq!((*zipper.focus(), zipper.position()), (5, 1));
+
+    let zipper = zipper.move_right();
+    assert_eq!((*zipper.focus(), zipper.position()), (8, 2));
+
+    let zipper = zipper.move_right();
+    assert_eq!((*zipper.focus(), zipper.position()), (13, 3));
+    assert_eq!(zipper.clone().into_nlist(), nlist![3, 5, 8, 13]);
+
+    let zipper = zipper.move_left();
+    assert_eq!((*zipper.focus(), zipper.position()), (8, 2));
+
+    let zipper = zipper.move_left().move_left();
+    assert_eq!((*zipper.focus(), zipper.position()), (3, 0));
+
+    assert_eq!(zipper.into_nlist(), nlist![3, 5, 8, 13]);
+}
+
+#[test]
+fn zipper_editing_test() {
+    let mut zipper = Zipper::from_nlist(nlist!["foo", "bar", "baz"].map(String::from));
+
+    zipper.focus_mut().push('!');
+
+    let mut zipper = zipper.move_right().move_right();
+    assert_eq!(zipper.replace("qux".to_string()), "baz");
+
+    let mut zipper = zipper.move_left();
+    *zipper.focus_mut() = zipper.focus().to_uppercase();
+
+    assert_eq!(
+        format!("{zipper:?}"),
+        r#"Zipper { before: ["foo!"], focus: "BAR", after: ["qux"] }"#,
+    );
+    assert_eq!(zipper.into_nlist(), nlist!["foo!", "BAR", "qux"]);
+}
+
+#[test]
+fn zipper_const_test() {
+    const fn increment_middle(list: NList<u8, Peano!(3)>) -> NList<u8, Peano!(3)> {
+        let mut zipper = Zipper::from_nlist(list).move_right();
+        *zipper.focus_mut() += 1;
+        zipper.move_right().move_left().into_nlist()
+    }
+
+    const LIST: NList<u8, Peano!(3)> = increment_middle(nlist![3, 5, 8]);
+    assert_eq!(LIST, nlist![3, 6, 8]);
+}
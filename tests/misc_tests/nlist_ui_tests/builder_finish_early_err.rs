@@ -0,0 +1,5 @@
+use nlist::{NList, Peano};
+
+fn main() {
+    _ = NList::<u8, Peano!(2)>::builder().push(3).finish();
+}
@@ -0,0 +1,5 @@
+use nlist::{NList, Peano};
+
+fn main() {
+    _ = NList::<u8, Peano!(1)>::builder().push(3).push(5);
+}
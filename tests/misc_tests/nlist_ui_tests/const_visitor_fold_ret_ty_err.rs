@@ -0,0 +1,7 @@
+nlist::const_visitor! {
+    struct Summer;
+
+    fold_const = |accum: u32, elem: &u32| -> u64 { (accum + *elem) as u64 };
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use nlist::{NList, Peano};
+use nlist::encoding::{Be, Decode};
+
+fn main() {
+    let _: NList<u8, Peano!(3)> = Be(0u32).encode();
+
+    let _ = <(u8, Be<u16>)>::decode(nlist::nlist![1, 2]);
+}
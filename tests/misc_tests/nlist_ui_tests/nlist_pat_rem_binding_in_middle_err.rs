@@ -0,0 +1,5 @@
+use nlist::{nlist, nlist_pat};
+
+fn main() {
+    let nlist_pat![_a, _rest @ .., _b] = nlist![3, 5, 8];
+}
@@ -0,0 +1,5 @@
+use nlist::nlist;
+
+fn main() {
+    let _ = nlist![3; ..nlist![5, 8], 13];
+}
@@ -0,0 +1,10 @@
+use nlist::{NMap, NSet, Peano, nlist};
+
+const MAP: NMap<u8, &str, Peano!(3)> = NMap::from_nlist(nlist![(1, "foo"), (2, "bar"), (1, "baz")]);
+
+const SET: NSet<&str, Peano!(3)> = NSet::from_nlist(nlist!["foo", "bar", "foo"]);
+
+fn main() {
+    _ = MAP;
+    _ = SET;
+}
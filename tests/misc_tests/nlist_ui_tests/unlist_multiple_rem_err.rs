@@ -0,0 +1,5 @@
+use nlist::{nlist, unlist};
+
+fn main() {
+    unlist!{[a, .., b, .., c] = nlist![3, 5, 8, 13]}
+}
@@ -0,0 +1,13 @@
+use nlist::{NList, Peano, nlist, unlist};
+
+fn main() {
+    unlist!{[.., a, b] = nlist![3, 5, 8, 13]}
+    assert_eq!((a, b), (8, 13));
+
+    unlist!{[rest @ .., c] = nlist![3, 5, 8, 13]}
+    let _: NList<i32, Peano!(3)> = rest;
+    assert_eq!((rest, c), (nlist![3, 5, 8], 13));
+
+    unlist!{[_ @ .., d] = nlist![21]}
+    assert_eq!(d, 21);
+}
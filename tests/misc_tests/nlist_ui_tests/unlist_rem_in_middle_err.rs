@@ -1,5 +0,0 @@
-use nlist::{nlist, unlist};
-
-fn main() {
-    unlist!{[.., _] = nlist![3, 5, 8]}
-}
\ No newline at end of file
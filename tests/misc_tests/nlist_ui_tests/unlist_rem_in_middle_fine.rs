@@ -0,0 +1,18 @@
+use nlist::{NList, Peano, PeanoInt, nlist, peano, unlist};
+
+const fn split_ends<T, L>(list: NList<T, peano::Add<Peano!(2), L>>) -> (T, NList<T, L>, T)
+where
+    L: PeanoInt
+{
+    unlist!{[first, rest @ .., last] = list}
+
+    (first, rest.coerce_len(peano::proofs::sub_identity()), last)
+}
+
+fn main() {
+    assert_eq!(split_ends(nlist![3, 5]), (3, nlist![], 5));
+    assert_eq!(split_ends(nlist![3, 5, 8, 13]), (3, nlist![5, 8], 13));
+
+    unlist!{[a, _, rest @ .., b] = nlist![3, 5, 8, 13, 21]}
+    assert_eq!((a, rest, b), (3, nlist![8, 13], 21));
+}
@@ -0,0 +1,9 @@
+use nlist::{Zipper, nlist};
+
+fn main() {
+    let zipper = Zipper::from_nlist(nlist![3, 5]);
+    _ = zipper.move_left();
+
+    let zipper = Zipper::from_nlist(nlist![3, 5]);
+    _ = zipper.move_right().move_right();
+}
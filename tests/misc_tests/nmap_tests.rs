@@ -0,0 +1,180 @@
+use nlist::{NList, NMap, NSet, Peano, nlist};
+use nlist::nmap::MapKey;
+
+use std::cmp::Ordering;
+
+
+const fn cmp_keys<K: MapKey>(l: &K, r: &K) -> Ordering {
+    K::MAP_KEY_WIT.cmp(l, r)
+}
+
+#[test]
+fn key_cmp_test() {
+    fn assert_cmp<K: MapKey + std::fmt::Debug>(keys: &[K]) {
+        for l in keys {
+            for r in keys {
+                assert_eq!(cmp_keys(l, r), l.cmp(r), "{l:?} {r:?}");
+            }
+        }
+    }
+
+    assert_cmp(&[false, true]);
+    assert_cmp(&['\0', 'a', 'b', 'z', 'ñ', char::MAX]);
+    assert_cmp(&[0u8, 1, 127, 128, 255]);
+    assert_cmp(&[0u16, 1, u16::MAX]);
+    assert_cmp(&[0u32, 1, u32::MAX]);
+    assert_cmp(&[0u64, 1, u64::MAX]);
+    assert_cmp(&[0u128, 1, u128::MAX]);
+    assert_cmp(&[0usize, 1, usize::MAX]);
+    assert_cmp(&[i8::MIN, -1, 0, 1, i8::MAX]);
+    assert_cmp(&[i16::MIN, -1, 0, 1, i16::MAX]);
+    assert_cmp(&[i32::MIN, -1, 0, 1, i32::MAX]);
+    assert_cmp(&[i64::MIN, -1, 0, 1, i64::MAX]);
+    assert_cmp(&[i128::MIN, -1, 0, 1, i128::MAX]);
+    assert_cmp(&[isize::MIN, -1, 0, 1, isize::MAX]);
+    assert_cmp(&["", "a", "aa", "ab", "b", "ba", "ñ", "z"]);
+}
+
+#[test]
+fn nmap_empty_test() {
+    const EMPTY: NMap<u8, u8, Peano!(0)> = NMap::from_nlist(NList::nil());
+
+    assert!(EMPTY.is_empty());
+    assert_eq!(EMPTY.len(), 0);
+    assert_eq!(EMPTY.get(&0), None);
+    assert!(!EMPTY.contains_key(&0));
+}
+
+#[test]
+fn nmap_get_test() {
+    const MAP: NMap<i32, &str, Peano!(6)> = NMap::from_nlist(nlist![
+        (13, "thirteen"),
+        (-5, "minus five"),
+        (8, "eight"),
+        (0, "zero"),
+        (100, "hundred"),
+        (3, "three"),
+    ]);
+
+    const EIGHT: Option<&&str> = MAP.get(&8);
+    assert_eq!(EIGHT, Some(&"eight"));
+
+    assert_eq!(MAP.len(), 6);
+    assert!(!MAP.is_empty());
+
+    for (key, value) in MAP.clone().into_nlist().into_vec() {
+        assert_eq!(MAP.get(&key), Some(&value));
+        assert_eq!(MAP.get_key_value(&key), Some(&(key, value)));
+        assert!(MAP.contains_key(&key));
+    }
+
+    for key in [i32::MIN, -6, -4, 1, 2, 4, 7, 9, 14, 99, 101, i32::MAX] {
+        assert_eq!(MAP.get(&key), None, "{key}");
+        assert!(!MAP.contains_key(&key));
+    }
+
+    assert_eq!(
+        MAP.as_nlist().each_ref().map(|(k, _)| *k),
+        nlist![-5, 0, 3, 8, 13, 100],
+    );
+}
+
+#[test]
+fn nmap_str_test() {
+    const MAP: NMap<&str, u16, Peano!(4)> = NMap::from_nlist(nlist![
+        ("ok", 200),
+        ("not found", 404),
+        ("forbidden", 403),
+        ("teapot", 418),
+    ]);
+
+    assert_eq!(MAP.get(&"teapot"), Some(&418));
+    assert_eq!(MAP.get(&"tea"), None);
+    assert_eq!(MAP.get(&""), None);
+
+    assert_eq!(format!("{MAP:?}"), r#"{"forbidden": 403, "not found": 404, "ok": 200, "teapot": 418}"#);
+}
+
+#[test]
+fn nmap_get_str_test() {
+    const MAP: NMap<&str, u16, Peano!(4)> = NMap::from_nlist(nlist![
+        ("ok", 200),
+        ("not found", 404),
+        ("forbidden", 403),
+        ("teapot", 418),
+    ]);
+
+    const TEAPOT: Option<&u16> = MAP.get_str("teapot");
+    assert_eq!(TEAPOT, Some(&418));
+
+    for (key, value) in MAP.clone().into_nlist().into_vec() {
+        let key = String::from(key);
+        assert_eq!(MAP.get_str(&key), Some(&value));
+        assert!(MAP.contains_key_str(&key));
+    }
+
+    for key in ["", "o", "oks", "not", "zzz"] {
+        let key = String::from(key);
+        assert_eq!(MAP.get_str(&key), None, "{key}");
+        assert!(!MAP.contains_key_str(&key));
+    }
+
+    const SET: NSet<&str, Peano!(3)> = NSet::from_nlist(nlist!["foo", "bar", "baz"]);
+
+    assert!(SET.contains_str(&String::from("baz")));
+    assert!(!SET.contains_str(&String::from("ba")));
+}
+
+#[test]
+fn nmap_sorting_test() {
+    const REVERSED: NMap<u8, char, Peano!(6)> = NMap::from_nlist(nlist![
+        (6, 'f'), (5, 'e'), (4, 'd'), (3, 'c'), (2, 'b'), (1, 'a'),
+    ]);
+    assert_eq!(REVERSED.into_nlist().map(|(_, v)| v), nlist!['a', 'b', 'c', 'd', 'e', 'f']);
+
+    let map = NMap::from_nlist(nlist![(3u8, 'c'), (1, 'a'), (5, 'e'), (2, 'b'), (4, 'd')]);
+    assert_eq!(map.into_nlist().map(|(k, _)| k), nlist![1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn nmap_get_mut_test() {
+    let mut map = NMap::from_nlist(nlist![('b', String::from("bar")), ('a', String::from("foo"))]);
+
+    map.get_mut(&'a').unwrap().push('!');
+    assert_eq!(map.get_mut(&'c'), None);
+
+    assert_eq!(map.into_nlist(), nlist![('a', "foo!".to_string()), ('b', "bar".to_string())]);
+}
+
+#[test]
+#[should_panic]
+fn nmap_duplicate_key_test() {
+    _ = NMap::from_nlist(nlist![(3u8, "foo"), (5, "bar"), (3, "baz")]);
+}
+
+#[test]
+fn nset_test() {
+    const SET: NSet<u64, Peano!(5)> = NSet::from_nlist(nlist![21, 3, 13, 5, 8]);
+
+    const _: () = assert!(SET.contains(&13));
+
+    assert_eq!(SET.len(), 5);
+    assert!(!SET.is_empty());
+    assert_eq!(SET.to_nlist(), nlist![3, 5, 8, 13, 21]);
+
+    for key in [0, 4, 14, 22, u64::MAX] {
+        assert!(!SET.contains(&key));
+    }
+
+    assert_eq!(format!("{SET:?}"), "{3, 5, 8, 13, 21}");
+    assert_eq!(SET.clone(), NSet::from_nlist(nlist![3, 5, 8, 13, 21]));
+    assert_ne!(SET, NSet::from_nlist(nlist![3, 5, 8, 13, 22]));
+
+    assert!(NSet::<bool, Peano!(0)>::from_nlist(NList::nil()).is_empty());
+}
+
+#[test]
+#[should_panic]
+fn nset_duplicate_key_test() {
+    _ = NSet::from_nlist(nlist!["foo", "bar", "foo"]);
+}
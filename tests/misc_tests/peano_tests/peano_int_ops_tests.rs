@@ -203,6 +203,134 @@ fn is_le_test() {
     }
 }
 
+#[test]
+fn is_eq_test() {
+    test_op! {
+        PeanoInt::IsEq<Rhs> IsEq IsEqFn, PeanoInt -> Boolean, peano => 
+        (Peano!(0), Peano!(0) => Bool<true>)
+        (Peano!(0), Peano!(1) => Bool<false>)
+        (Peano!(0), Peano!(2) => Bool<false>)
+        (Peano!(0), Peano!(3) => Bool<false>)
+
+        (Peano!(1), Peano!(0) => Bool<false>)
+        (Peano!(1), Peano!(1) => Bool<true>)
+        (Peano!(1), Peano!(2) => Bool<false>)
+        (Peano!(1), Peano!(3) => Bool<false>)
+
+        (Peano!(2), Peano!(0) => Bool<false>)
+        (Peano!(2), Peano!(1) => Bool<false>)
+        (Peano!(2), Peano!(2) => Bool<true>)
+        (Peano!(2), Peano!(3) => Bool<false>)
+
+        (Peano!(3), Peano!(0) => Bool<false>)
+        (Peano!(3), Peano!(1) => Bool<false>)
+        (Peano!(3), Peano!(2) => Bool<false>)
+        (Peano!(3), Peano!(3) => Bool<true>)
+    }
+}
+
+#[test]
+fn is_gt_test() {
+    test_op! {
+        PeanoInt::IsGt<Rhs> IsGt IsGtFn, PeanoInt -> Boolean, peano => 
+        (Peano!(0), Peano!(0) => Bool<false>)
+        (Peano!(0), Peano!(1) => Bool<false>)
+        (Peano!(0), Peano!(2) => Bool<false>)
+        (Peano!(0), Peano!(3) => Bool<false>)
+
+        (Peano!(1), Peano!(0) => Bool<true>)
+        (Peano!(1), Peano!(1) => Bool<false>)
+        (Peano!(1), Peano!(2) => Bool<false>)
+        (Peano!(1), Peano!(3) => Bool<false>)
+
+        (Peano!(2), Peano!(0) => Bool<true>)
+        (Peano!(2), Peano!(1) => Bool<true>)
+        (Peano!(2), Peano!(2) => Bool<false>)
+        (Peano!(2), Peano!(3) => Bool<false>)
+
+        (Peano!(3), Peano!(0) => Bool<true>)
+        (Peano!(3), Peano!(1) => Bool<true>)
+        (Peano!(3), Peano!(2) => Bool<true>)
+        (Peano!(3), Peano!(3) => Bool<false>)
+    }
+}
+
+#[test]
+fn is_ge_test() {
+    test_op! {
+        PeanoInt::IsGe<Rhs> IsGe IsGeFn, PeanoInt -> Boolean, peano => 
+        (Peano!(0), Peano!(0) => Bool<true>)
+        (Peano!(0), Peano!(1) => Bool<false>)
+        (Peano!(0), Peano!(2) => Bool<false>)
+        (Peano!(0), Peano!(3) => Bool<false>)
+
+        (Peano!(1), Peano!(0) => Bool<true>)
+        (Peano!(1), Peano!(1) => Bool<true>)
+        (Peano!(1), Peano!(2) => Bool<false>)
+        (Peano!(1), Peano!(3) => Bool<false>)
+
+        (Peano!(2), Peano!(0) => Bool<true>)
+        (Peano!(2), Peano!(1) => Bool<true>)
+        (Peano!(2), Peano!(2) => Bool<true>)
+        (Peano!(2), Peano!(3) => Bool<false>)
+
+        (Peano!(3), Peano!(0) => Bool<true>)
+        (Peano!(3), Peano!(1) => Bool<true>)
+        (Peano!(3), Peano!(2) => Bool<true>)
+        (Peano!(3), Peano!(3) => Bool<true>)
+    }
+}
+
+#[test]
+fn is_even_test() {
+    test_op! {
+        PeanoInt::IsEven<> IsEven IsEvenFn, PeanoInt -> Boolean, peano => 
+        (Peano!(0) => Bool<true>)
+        (Peano!(1) => Bool<false>)
+        (Peano!(2) => Bool<true>)
+        (Peano!(3) => Bool<false>)
+        (Peano!(4) => Bool<true>)
+    }
+}
+
+#[test]
+fn is_odd_test() {
+    test_op! {
+        PeanoInt::IsOdd<> IsOdd IsOddFn, PeanoInt -> Boolean, peano => 
+        (Peano!(0) => Bool<false>)
+        (Peano!(1) => Bool<true>)
+        (Peano!(2) => Bool<false>)
+        (Peano!(3) => Bool<true>)
+        (Peano!(4) => Bool<false>)
+    }
+}
+
+#[test]
+fn abs_diff_test() {
+    test_op! {
+        PeanoInt::AbsDiff<Rhs> AbsDiff AbsDiffFn, PeanoInt -> PeanoInt, peano => 
+        (Peano!(0), Peano!(0) => Peano!(0))
+        (Peano!(0), Peano!(1) => Peano!(1))
+        (Peano!(0), Peano!(2) => Peano!(2))
+        (Peano!(0), Peano!(3) => Peano!(3))
+
+        (Peano!(1), Peano!(0) => Peano!(1))
+        (Peano!(1), Peano!(1) => Peano!(0))
+        (Peano!(1), Peano!(2) => Peano!(1))
+        (Peano!(1), Peano!(3) => Peano!(2))
+
+        (Peano!(2), Peano!(0) => Peano!(2))
+        (Peano!(2), Peano!(1) => Peano!(1))
+        (Peano!(2), Peano!(2) => Peano!(0))
+        (Peano!(2), Peano!(3) => Peano!(1))
+
+        (Peano!(3), Peano!(0) => Peano!(3))
+        (Peano!(3), Peano!(1) => Peano!(2))
+        (Peano!(3), Peano!(2) => Peano!(1))
+        (Peano!(3), Peano!(3) => Peano!(0))
+    }
+}
+
 
 
 #[test]
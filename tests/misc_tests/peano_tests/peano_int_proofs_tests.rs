@@ -1,5 +1,5 @@
 use nlist::boolean::{Bool, Boolean, BoolWitG};
-use nlist::peano::{self, Peano, PeanoInt, Zero, proofs};
+use nlist::peano::{self, Peano, PeanoInt, PlusOne, Zero, proofs};
 use nlist::typewit::TypeEq;
 
 use crate::misc_tests::test_utils::assert_type;
@@ -151,3 +151,484 @@ fn compose_sub_lt_test() {
 
 
 
+
+#[test]
+fn add_plus_one_test() {
+    fn inner<A, B>() 
+    where
+        A: PeanoInt,
+        B: PeanoInt,
+    {
+        let ret = const { proofs::add_plus_one::<A, B>() };
+        assert_type::<TypeEq<peano::Add<A, PlusOne<B>>, PlusOne<peano::Add<A, B>>>>(ret);
+    }
+
+    call_with_binary!{inner}
+}
+
+#[test]
+fn associative_add_test() {
+    fn inner<A, B, C>() 
+    where
+        A: PeanoInt,
+        B: PeanoInt,
+        C: PeanoInt,
+    {
+        let ret = const { proofs::associative_add::<A, B, C>() };
+        assert_type::<TypeEq<
+            peano::Add<peano::Add<A, B>, C>, 
+            peano::Add<A, peano::Add<B, C>>,
+        >>(ret);
+    }
+
+    call_with_ternary!{inner}
+}
+
+#[test]
+fn mul_zero_test() {
+    fn inner<A>() 
+    where
+        A: PeanoInt,
+    {
+        let ret = const { proofs::mul_zero::<A>() };
+        assert_type::<TypeEq<peano::Mul<A, Zero>, Zero>>(ret);
+    }
+
+    call_with_unary!{inner}
+}
+
+#[test]
+fn mul_plus_one_test() {
+    fn inner<A, B>() 
+    where
+        A: PeanoInt,
+        B: PeanoInt,
+    {
+        let ret = const { proofs::mul_plus_one::<A, B>() };
+        assert_type::<TypeEq<peano::Mul<A, PlusOne<B>>, peano::Add<peano::Mul<A, B>, A>>>(ret);
+    }
+
+    call_with_binary!{inner}
+}
+
+#[test]
+fn distributive_mul_test() {
+    fn inner<A, B, C>() 
+    where
+        A: PeanoInt,
+        B: PeanoInt,
+        C: PeanoInt,
+    {
+        let ret = const { proofs::right_distributive_mul::<A, B, C>() };
+        assert_type::<TypeEq<
+            peano::Mul<peano::Add<A, B>, C>, 
+            peano::Add<peano::Mul<A, C>, peano::Mul<B, C>>,
+        >>(ret);
+
+        let ret = const { proofs::left_distributive_mul::<A, B, C>() };
+        assert_type::<TypeEq<
+            peano::Mul<A, peano::Add<B, C>>, 
+            peano::Add<peano::Mul<A, B>, peano::Mul<A, C>>,
+        >>(ret);
+    }
+
+    call_with_ternary!{inner}
+}
+
+#[test]
+fn associative_mul_test() {
+    fn inner<A, B, C>() 
+    where
+        A: PeanoInt,
+        B: PeanoInt,
+        C: PeanoInt,
+    {
+        let ret = const { proofs::associative_mul::<A, B, C>() };
+        assert_type::<TypeEq<
+            peano::Mul<peano::Mul<A, B>, C>, 
+            peano::Mul<A, peano::Mul<B, C>>,
+        >>(ret);
+    }
+
+    call_with_ternary!{inner}
+}
+
+#[test]
+fn add_sub_le_test() {
+    fn inner<A, B>() 
+    where
+        A: PeanoInt,
+        B: PeanoInt,
+    {
+        let ret = const { 
+            match peano::IsLe::<A, B>::BOOL_WIT {
+                BoolWitG::True(te) => Some(proofs::add_sub_le::<A, B>(te)),
+                BoolWitG::False(_) => None,
+            }
+        };
+
+        assert_eq!(A::USIZE <= B::USIZE, ret.is_some());
+
+        assert_type::<Option<TypeEq<peano::Add<A, peano::SubSat<B, A>>, B>>>(ret);
+    }
+
+    call_with_binary!{inner}
+}
+
+#[test]
+fn add_sub_cancel_test() {
+    fn inner<A, B>() 
+    where
+        A: PeanoInt,
+        B: PeanoInt,
+    {
+        let ret = const { proofs::add_sub_cancel::<A, B>() };
+        assert_type::<TypeEq<peano::SubSat<peano::Add<A, B>, B>, A>>(ret);
+    }
+
+    call_with_binary!{inner}
+}
+
+#[test]
+fn comparison_lemmas_test() {
+    fn inner<A, B>() 
+    where
+        A: PeanoInt,
+        B: PeanoInt,
+    {
+        let _: TypeEq<peano::IsLe<A, A>, Bool<true>> = const { proofs::le_refl::<A>() };
+        let _: TypeEq<peano::IsLt<A, PlusOne<A>>, Bool<true>> = 
+            const { proofs::lt_plus_one::<A>() };
+        let _: TypeEq<peano::IsLe<A, peano::Add<A, B>>, Bool<true>> = 
+            const { proofs::le_add::<A, B>() };
+        let _: TypeEq<peano::IsLe<peano::SubSat<A, B>, A>, Bool<true>> = 
+            const { proofs::sub_sat_le::<A, B>() };
+
+        let ret = const { 
+            match peano::IsLt::<A, B>::BOOL_WIT {
+                BoolWitG::True(te) => Some(proofs::lt_to_le::<A, B>(te)),
+                BoolWitG::False(_) => None,
+            }
+        };
+
+        assert_eq!(A::USIZE < B::USIZE, ret.is_some());
+
+        assert_type::<Option<TypeEq<peano::IsLe<A, B>, Bool<true>>>>(ret);
+    }
+
+    call_with_binary!{inner}
+}
+
+#[test]
+fn transitivity_test() {
+    fn inner<A, B, C>() 
+    where
+        A: PeanoInt,
+        B: PeanoInt,
+        C: PeanoInt,
+    {
+        let (a, b, c) = (A::USIZE, B::USIZE, C::USIZE);
+
+        let ret = const {
+            match (peano::IsLt::<A, B>::BOOL_WIT, peano::IsLt::<B, C>::BOOL_WIT) {
+                (BoolWitG::True(ab), BoolWitG::True(bc)) => 
+                    Some(proofs::lt_trans::<A, B, C>(ab, bc)),
+                _ => None,
+            }
+        };
+        assert_eq!(a < b && b < c, ret.is_some());
+        assert_type::<Option<TypeEq<peano::IsLt<A, C>, Bool<true>>>>(ret);
+
+        let ret = const {
+            match (peano::IsLe::<A, B>::BOOL_WIT, peano::IsLe::<B, C>::BOOL_WIT) {
+                (BoolWitG::True(ab), BoolWitG::True(bc)) => 
+                    Some(proofs::le_trans::<A, B, C>(ab, bc)),
+                _ => None,
+            }
+        };
+        assert_eq!(a <= b && b <= c, ret.is_some());
+        assert_type::<Option<TypeEq<peano::IsLe<A, C>, Bool<true>>>>(ret);
+
+        let ret = const {
+            match (peano::IsLt::<A, B>::BOOL_WIT, peano::IsLe::<B, C>::BOOL_WIT) {
+                (BoolWitG::True(ab), BoolWitG::True(bc)) => 
+                    Some(proofs::lt_le_trans::<A, B, C>(ab, bc)),
+                _ => None,
+            }
+        };
+        assert_eq!(a < b && b <= c, ret.is_some());
+        assert_type::<Option<TypeEq<peano::IsLt<A, C>, Bool<true>>>>(ret);
+
+        let ret = const {
+            match (peano::IsLe::<A, B>::BOOL_WIT, peano::IsLt::<B, C>::BOOL_WIT) {
+                (BoolWitG::True(ab), BoolWitG::True(bc)) => 
+                    Some(proofs::le_lt_trans::<A, B, C>(ab, bc)),
+                _ => None,
+            }
+        };
+        assert_eq!(a <= b && b < c, ret.is_some());
+        assert_type::<Option<TypeEq<peano::IsLt<A, C>, Bool<true>>>>(ret);
+    }
+
+    call_with_ternary!{inner}
+}
+
+#[test]
+fn add_mono_test() {
+    fn inner<A, B, C>() 
+    where
+        A: PeanoInt,
+        B: PeanoInt,
+        C: PeanoInt,
+    {
+        let ret = const { 
+            match peano::IsLe::<A, B>::BOOL_WIT {
+                BoolWitG::True(te) => Some(proofs::add_le_mono::<A, B, C>(te)),
+                BoolWitG::False(_) => None,
+            }
+        };
+        assert_eq!(A::USIZE <= B::USIZE, ret.is_some());
+        assert_type::<Option<TypeEq<
+            peano::IsLe<peano::Add<C, A>, peano::Add<C, B>>, 
+            Bool<true>,
+        >>>(ret);
+
+        let ret = const { 
+            match peano::IsLt::<A, B>::BOOL_WIT {
+                BoolWitG::True(te) => Some(proofs::add_lt_mono::<A, B, C>(te)),
+                BoolWitG::False(_) => None,
+            }
+        };
+        assert_eq!(A::USIZE < B::USIZE, ret.is_some());
+        assert_type::<Option<TypeEq<
+            peano::IsLt<peano::Add<C, A>, peano::Add<C, B>>, 
+            Bool<true>,
+        >>>(ret);
+    }
+
+    call_with_ternary!{inner}
+}
+
+#[test]
+fn min_max_test() {
+    fn inner<A, B>() 
+    where
+        A: PeanoInt,
+        B: PeanoInt,
+    {
+        let _: TypeEq<peano::Min<A, B>, peano::Min<B, A>> = 
+            const { proofs::commutative_min::<A, B>() };
+        let _: TypeEq<peano::Max<A, B>, peano::Max<B, A>> = 
+            const { proofs::commutative_max::<A, B>() };
+        let _: TypeEq<peano::IsLe<peano::Min<A, B>, A>, Bool<true>> = 
+            const { proofs::min_le::<A, B>() };
+        let _: TypeEq<peano::IsLe<A, peano::Max<A, B>>, Bool<true>> = 
+            const { proofs::le_max::<A, B>() };
+        let _: TypeEq<peano::Add<peano::Min<A, B>, peano::Max<A, B>>, peano::Add<A, B>> = 
+            const { proofs::add_min_max::<A, B>() };
+
+        let ret = const { 
+            match peano::IsLe::<A, B>::BOOL_WIT {
+                BoolWitG::True(te) => 
+                    Some((proofs::min_of_le::<A, B>(te), proofs::max_of_le::<A, B>(te))),
+                BoolWitG::False(_) => None,
+            }
+        };
+        assert_eq!(A::USIZE <= B::USIZE, ret.is_some());
+        assert_type::<Option<(TypeEq<peano::Min<A, B>, A>, TypeEq<peano::Max<A, B>, B>)>>(ret);
+    }
+
+    call_with_binary!{inner}
+}
+
+#[test]
+fn induction_test() {
+    use nlist::peano::PlusOneFn;
+    use nlist::peano::proofs::{InductionStep, InductiveEq};
+
+    type Two = Peano!(2);
+
+    // proves that `N + N == N * 2`
+    struct DoubleIsMulTwo;
+
+    impl InductiveEq for DoubleIsMulTwo {
+        type Lhs<N: PeanoInt> = peano::Add<N, N>;
+        type Rhs<N: PeanoInt> = peano::Mul<N, Two>;
+
+        const BASE: TypeEq<Zero, Zero> = TypeEq::NEW;
+
+        type Step<N: PeanoInt> = Self;
+    }
+
+    impl<N: PeanoInt> InductionStep<N, DoubleIsMulTwo> for DoubleIsMulTwo {
+        type Map = PlusTwoFn;
+
+        const LHS_STEP: TypeEq<
+            peano::Add<PlusOne<N>, PlusOne<N>>, 
+            PlusOne<PlusOne<peano::Add<N, N>>>,
+        > = proofs::add_plus_one::<N, N>().map(PlusOneFn);
+
+        const RHS_STEP: TypeEq<
+            peano::Mul<PlusOne<N>, Two>, 
+            PlusOne<PlusOne<peano::Mul<N, Two>>>,
+        > = {
+            type M<N> = peano::Mul<N, Two>;
+
+            proofs::add_plus_one::<M<N>, PlusOne<Zero>>()
+                .join(proofs::add_plus_one::<M<N>, Zero>().map(PlusOneFn))
+                .join(proofs::add_identity::<M<N>>().map(PlusOneFn).map(PlusOneFn))
+        };
+    }
+
+    nlist::typewit::type_fn! {
+        struct PlusTwoFn;
+        impl<N: PeanoInt> N => PlusOne<PlusOne<N>>
+    }
+
+    fn inner<A>() 
+    where
+        A: PeanoInt,
+    {
+        let ret = const { proofs::induction::<DoubleIsMulTwo, A>() };
+        assert_type::<TypeEq<peano::Add<A, A>, peano::Mul<A, Two>>>(ret);
+    }
+
+    call_with_unary!{inner}
+}
+
+#[test]
+fn lt_le_flipping_test() {
+    use nlist::boolean::Not;
+
+    fn inner<A, B>() 
+    where
+        A: PeanoInt,
+        B: PeanoInt,
+    {
+        let ret = const { proofs::lt_is_not_flipped_le::<A, B>() };
+        assert_type::<TypeEq<peano::IsLt<A, B>, Not<peano::IsLe<B, A>>>>(ret);
+
+        let ret = const { proofs::le_is_not_flipped_lt::<A, B>() };
+        assert_type::<TypeEq<peano::IsLe<A, B>, Not<peano::IsLt<B, A>>>>(ret);
+    }
+
+    call_with_binary!{inner}
+}
+
+#[test]
+fn is_eq_test() {
+    use nlist::typewit::TypeNe;
+
+    fn inner<A, B>() 
+    where
+        A: PeanoInt,
+        B: PeanoInt,
+    {
+        let _: TypeEq<peano::IsEq<A, A>, Bool<true>> = const { proofs::is_eq_refl::<A>() };
+
+        let ret = const {
+            match peano::IsEq::<A, B>::BOOL_WIT {
+                BoolWitG::True(te) => Ok(proofs::is_eq_to_eq::<A, B>(te)),
+                BoolWitG::False(te) => Err(proofs::is_ne_to_ne::<A, B>(te)),
+            }
+        };
+        assert_eq!(A::USIZE == B::USIZE, ret.is_ok());
+        assert_type::<Result<TypeEq<A, B>, TypeNe<A, B>>>(ret);
+
+        if let Ok(te) = ret {
+            let _: TypeEq<peano::IsEq<A, B>, Bool<true>> = proofs::eq_to_is_eq(te);
+        }
+    }
+
+    call_with_binary!{inner}
+}
+
+#[test]
+fn gt_ge_flipping_test() {
+    fn inner<A, B>() 
+    where
+        A: PeanoInt,
+        B: PeanoInt,
+    {
+        let ret = const { proofs::gt_is_flipped_lt::<A, B>() };
+        assert_type::<TypeEq<peano::IsGt<A, B>, peano::IsLt<B, A>>>(ret);
+
+        let ret = const { proofs::ge_is_flipped_le::<A, B>() };
+        assert_type::<TypeEq<peano::IsGe<A, B>, peano::IsLe<B, A>>>(ret);
+    }
+
+    call_with_binary!{inner}
+}
+
+#[test]
+fn parity_test() {
+    use nlist::boolean::Not;
+    use nlist::peano::proofs::EvenWit;
+
+    fn inner<A>() 
+    where
+        A: PeanoInt,
+    {
+        let ret = const { proofs::odd_is_not_even::<A>() };
+        assert_type::<TypeEq<peano::IsOdd<A>, Not<peano::IsEven<A>>>>(ret);
+
+        let ret = const {
+            match peano::IsEven::<A>::BOOL_WIT {
+                BoolWitG::True(te) => Some(proofs::even_wit::<A>(te)),
+                BoolWitG::False(_) => None,
+            }
+        };
+        assert_eq!(A::USIZE % 2 == 0, ret.is_some());
+        match ret {
+            Some(EvenWit::Zero(_)) => assert_eq!(A::USIZE, 0),
+            Some(EvenWit::PlusTwo { .. }) => assert!(A::USIZE >= 2),
+            None => {}
+        }
+
+        let ret = const {
+            match peano::IsOdd::<A>::BOOL_WIT {
+                BoolWitG::True(te) => Some(proofs::odd_wit::<A>(te)),
+                BoolWitG::False(_) => None,
+            }
+        };
+        assert_eq!(A::USIZE % 2 == 1, ret.is_some());
+        assert_type::<Option<(
+            TypeEq<A, PlusOne<peano::SubOneSat<A>>>,
+            TypeEq<peano::IsEven<peano::SubOneSat<A>>, Bool<true>>,
+        )>>(ret);
+    }
+
+    call_with_unary!{inner}
+}
+
+#[test]
+fn abs_diff_test() {
+    fn inner<A, B>() 
+    where
+        A: PeanoInt,
+        B: PeanoInt,
+    {
+        let _: TypeEq<peano::AbsDiff<A, B>, peano::AbsDiff<B, A>> = 
+            const { proofs::commutative_abs_diff::<A, B>() };
+
+        let ret = const { 
+            match peano::IsLe::<A, B>::BOOL_WIT {
+                BoolWitG::True(te) => Some(proofs::abs_diff_of_le::<A, B>(te)),
+                BoolWitG::False(_) => None,
+            }
+        };
+        assert_eq!(A::USIZE <= B::USIZE, ret.is_some());
+        assert_type::<Option<TypeEq<peano::AbsDiff<A, B>, peano::SubSat<B, A>>>>(ret);
+
+        let ret = const { 
+            match peano::eq::<peano::AbsDiff<A, B>, Zero>() {
+                nlist::typewit::TypeCmp::Eq(te) => Some(proofs::abs_diff_zero_to_eq::<A, B>(te)),
+                nlist::typewit::TypeCmp::Ne(_) => None,
+            }
+        };
+        assert_eq!(A::USIZE == B::USIZE, ret.is_some());
+        assert_type::<Option<TypeEq<A, B>>>(ret);
+    }
+
+    call_with_binary!{inner}
+}
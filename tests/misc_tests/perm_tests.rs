@@ -0,0 +1,81 @@
+use nlist::{Fin, NList, Peano, PeanoInt, Perm, nlist};
+
+
+fn perm<L: PeanoInt>(indices: NList<usize, L>) -> Perm<L> {
+    Perm::from_usizes(indices).unwrap()
+}
+
+#[test]
+fn new_test() {
+    assert!(Perm::<Peano!(0)>::from_usizes(NList::nil()).is_some());
+    assert!(Perm::<Peano!(1)>::from_usizes(nlist![0]).is_some());
+    assert!(Perm::<Peano!(1)>::from_usizes(nlist![1]).is_none());
+
+    assert!(Perm::<Peano!(3)>::from_usizes(nlist![2, 0, 1]).is_some());
+    assert!(Perm::<Peano!(3)>::from_usizes(nlist![2, 0, 2]).is_none());
+    assert!(Perm::<Peano!(3)>::from_usizes(nlist![0, 0, 0]).is_none());
+    assert!(Perm::<Peano!(3)>::from_usizes(nlist![0, 1, 3]).is_none());
+
+    let indices = nlist![1, 0].map(|i| Fin::<Peano!(2)>::new(i).unwrap());
+    let swap = Perm::new(indices.clone()).unwrap();
+    assert_eq!(swap.as_nlist(), &indices);
+    assert_eq!(swap.into_nlist(), indices);
+}
+
+#[test]
+fn identity_test() {
+    const ID: Perm<Peano!(4)> = Perm::identity();
+
+    assert_eq!(ID, perm(nlist![0, 1, 2, 3]));
+    assert_eq!(ID.apply(nlist![3, 5, 8, 13]), nlist![3, 5, 8, 13]);
+    assert_eq!(ID.inverse(), ID);
+
+    let other = perm(nlist![3, 1, 0, 2]);
+    assert_eq!(ID.compose(&other), other);
+    assert_eq!(other.compose(&ID), other);
+}
+
+#[test]
+fn apply_test() {
+    let rotate = perm(nlist![1, 2, 3, 0]);
+
+    assert_eq!(rotate.apply(nlist![3, 5, 8, 13]), nlist![5, 8, 13, 3]);
+
+    let strings = nlist!["foo", "bar", "baz", "qux"].map(String::from);
+    assert_eq!(rotate.apply(strings), nlist!["bar", "baz", "qux", "foo"]);
+}
+
+#[test]
+fn compose_inverse_test() {
+    let a = perm(nlist![2, 0, 3, 1, 4]);
+    let b = perm(nlist![4, 3, 2, 1, 0]);
+    let c = perm(nlist![1, 0, 4, 2, 3]);
+
+    let list = nlist![3, 5, 8, 13, 21];
+
+    assert_eq!(a.compose(&b).apply(list.clone()), a.apply(b.apply(list.clone())));
+    assert_eq!(a.compose(&b).compose(&c), a.compose(&b.compose(&c)));
+
+    for p in [&a, &b, &c] {
+        assert_eq!(p.inverse().apply(p.apply(list.clone())), list);
+        assert_eq!(p.apply(p.inverse().apply(list.clone())), list);
+        assert_eq!(p.compose(&p.inverse()), Perm::identity());
+        assert_eq!(p.inverse().inverse(), *p);
+    }
+}
+
+#[test]
+fn gather_test() {
+    let list = nlist![3, 5, 8];
+    fn fins<M: PeanoInt>(list: NList<usize, M>) -> NList<Fin<Peano!(3)>, M> {
+        list.map(|i| Fin::new(i).unwrap())
+    }
+
+    assert_eq!(list.gather(NList::nil()), NList::nil::<i32>());
+    assert_eq!(list.gather(fins(nlist![2])), nlist![8]);
+    assert_eq!(list.gather(fins(nlist![2, 2, 0, 1, 0])), nlist![8, 8, 3, 5, 3]);
+
+    let strings = nlist!["foo", "bar"].map(String::from);
+    let two = Fin::<Peano!(2)>::all();
+    assert_eq!(strings.gather(nlist![*two.index::<Peano!(1)>(); 3]), nlist!["bar"; 3]);
+}
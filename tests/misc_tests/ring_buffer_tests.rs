@@ -0,0 +1,165 @@
+use nlist::{NList, Peano, PeanoInt, RingBuffer, nlist};
+
+use std::cell::Cell;
+
+use crate::misc_tests::test_utils::CountDrops;
+
+
+fn ring_of<Cap: PeanoInt>(elems: &[u32]) -> RingBuffer<u32, Cap> {
+    let mut ring = RingBuffer::new();
+    for &elem in elems {
+        ring.push_overwrite(elem);
+    }
+    ring
+}
+
+#[test]
+fn new_test() {
+    const EMPTY: RingBuffer<u8, Peano!(3)> = RingBuffer::new();
+
+    assert_eq!(EMPTY.len(), 0);
+    assert_eq!(EMPTY.capacity(), 3);
+    assert!(EMPTY.is_empty());
+    assert!(!EMPTY.is_full());
+    assert_eq!(EMPTY.get(0), None);
+    assert_eq!(EMPTY.iter().next(), None);
+    assert_eq!(EMPTY.into_nlist(), None);
+}
+
+#[test]
+fn zero_capacity_test() {
+    let mut ring = RingBuffer::<u8, Peano!(0)>::default();
+
+    assert!(ring.is_empty());
+    assert!(ring.is_full());
+    assert_eq!(ring.push_overwrite(3), Some(3));
+    assert_eq!(ring.pop_front(), None);
+    assert_eq!(ring.as_nlist_rotated(), NList::nil());
+    assert_eq!(ring.into_nlist(), Some(NList::nil()));
+}
+
+#[test]
+fn push_pop_test() {
+    let mut ring = RingBuffer::<u32, Peano!(3)>::new();
+
+    assert_eq!(ring.push_overwrite(3), None);
+    assert_eq!(ring.push_overwrite(5), None);
+    assert_eq!(ring.len(), 2);
+    assert_eq!(ring.pop_front(), Some(3));
+
+    assert_eq!(ring.push_overwrite(8), None);
+    assert_eq!(ring.push_overwrite(13), None);
+    assert!(ring.is_full());
+    assert_eq!(ring.iter().collect::<Vec<_>>(), [&5, &8, &13]);
+
+    assert_eq!(ring.push_overwrite(21), Some(5));
+    assert_eq!(ring.push_overwrite(34), Some(8));
+    assert_eq!(ring.len(), 3);
+    assert_eq!(ring.iter().collect::<Vec<_>>(), [&13, &21, &34]);
+
+    assert_eq!(ring.pop_front(), Some(13));
+    assert_eq!(ring.pop_front(), Some(21));
+    assert_eq!(ring.pop_front(), Some(34));
+    assert_eq!(ring.pop_front(), None);
+    assert!(ring.is_empty());
+}
+
+#[test]
+fn iter_test() {
+    let ring = ring_of::<Peano!(4)>(&[3, 5, 8, 13, 21, 34]);
+
+    assert_eq!(ring.iter().len(), 4);
+    assert_eq!(ring.iter().copied().collect::<Vec<_>>(), [8, 13, 21, 34]);
+    assert_eq!(ring.iter().rev().copied().collect::<Vec<_>>(), [34, 21, 13, 8]);
+
+    assert_eq!(ring.get(0), Some(&8));
+    assert_eq!(ring.get(3), Some(&34));
+    assert_eq!(ring.get(4), None);
+}
+
+#[test]
+fn rotated_test() {
+    let ring = ring_of::<Peano!(4)>(&[3, 5]);
+    assert_eq!(ring.as_nlist_rotated(), nlist![Some(&3), Some(&5), None, None]);
+    assert_eq!(ring.into_nlist_rotated(), nlist![Some(3), Some(5), None, None]);
+
+    let ring = ring_of::<Peano!(4)>(&[3, 5, 8, 13, 21, 34]);
+    assert_eq!(ring.as_nlist_rotated(), nlist![Some(&8), Some(&13), Some(&21), Some(&34)]);
+    assert_eq!(ring.into_nlist_rotated(), nlist![Some(8), Some(13), Some(21), Some(34)]);
+
+    let mut ring = ring_of::<Peano!(4)>(&[3, 5, 8, 13, 21]);
+    ring.pop_front();
+    assert_eq!(ring.as_nlist_rotated(), nlist![Some(&8), Some(&13), Some(&21), None]);
+}
+
+#[test]
+fn into_nlist_test() {
+    assert_eq!(ring_of::<Peano!(3)>(&[3, 5]).into_nlist(), None);
+    assert_eq!(ring_of::<Peano!(3)>(&[3, 5, 8]).into_nlist(), Some(nlist![3, 5, 8]));
+    assert_eq!(ring_of::<Peano!(3)>(&[3, 5, 8, 13]).into_nlist(), Some(nlist![5, 8, 13]));
+}
+
+#[test]
+fn const_test() {
+    const fn window<Cap: PeanoInt>(readings: &[u32]) -> NList<Option<u32>, Cap> {
+        let mut ring = RingBuffer::new();
+        let mut i = 0;
+        while i < readings.len() {
+            _ = ring.push_overwrite(readings[i]);
+            i += 1;
+        }
+        ring.into_nlist_rotated()
+    }
+
+    const WINDOW: NList<Option<u32>, Peano!(3)> = window(&[3, 5, 8, 13]);
+    assert_eq!(WINDOW, nlist![Some(5), Some(8), Some(13)]);
+
+    const fn sum_all<Cap: PeanoInt>(mut ring: RingBuffer<u32, Cap>) -> u32 {
+        let mut sum = 0;
+        while let Some(x) = ring.pop_front() {
+            sum += x;
+        }
+        ring.assert_empty_drop();
+        sum
+    }
+
+    const SUM: u32 = {
+        let mut ring = RingBuffer::<u32, Peano!(2)>::new();
+        _ = ring.push_overwrite(3);
+        _ = ring.push_overwrite(5);
+        _ = ring.push_overwrite(8);
+        sum_all(ring)
+    };
+    assert_eq!(SUM, 13);
+}
+
+#[test]
+#[should_panic]
+fn assert_empty_drop_test() {
+    ring_of::<Peano!(2)>(&[3]).assert_empty_drop();
+}
+
+#[test]
+fn drop_test() {
+    let drops = Cell::new(0);
+
+    let mut ring = RingBuffer::<_, Peano!(3)>::new();
+    for _ in 0..5 {
+        drop(ring.push_overwrite(CountDrops(&drops, 0)));
+    }
+    assert_eq!(drops.get(), 2);
+
+    drop(ring);
+    assert_eq!(drops.get(), 5);
+}
+
+#[test]
+fn traits_test() {
+    let ring = ring_of::<Peano!(3)>(&[3, 5, 8, 13]);
+
+    assert_eq!(format!("{ring:?}"), "[5, 8, 13]");
+    assert_eq!(ring.clone(), ring);
+    assert_eq!(ring, ring_of::<Peano!(4)>(&[5, 8, 13]));
+    assert_ne!(ring, ring_of::<Peano!(3)>(&[5, 8]));
+    assert_ne!(ring, ring_of::<Peano!(3)>(&[5, 8, 14]));
+}
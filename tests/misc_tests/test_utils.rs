@@ -1,3 +1,16 @@
+use std::cell::Cell;
+
+
+// increments the counter when dropped, the `u8` distinguishes the values in a list
+pub struct CountDrops<'a>(pub &'a Cell<usize>, pub u8);
+
+impl Drop for CountDrops<'_> {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+
 macro_rules! assertm {
     ($val:expr, $($tt:tt)*) => {
         match $val {
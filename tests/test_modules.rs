@@ -1,9 +1,18 @@
 mod misc_tests {
+    mod bit_nlist_tests;
     mod boolean_tests;
+    mod bounded_nlist_tests;
+    mod const_visitor_tests;
     mod destructuring_macro_tests;
+    mod encoding_tests;
+    mod fin_tests;
+    mod future_tests;
     mod nlist_type_tests;
+    mod nmap_tests;
     mod peano_tests;
+    mod perm_tests;
     mod receiver_tests;
+    mod ring_buffer_tests;
     mod test_utils;
     mod ui_tests;
 }
\ No newline at end of file